@@ -39,7 +39,9 @@ exports.handler = async function (yargs: CommandArguments) {
       cloneAccountAddress
     );
 
+    const pools = await cloneClient.getPools();
     const oracles = await cloneClient.getOracles();
+    const userAccount = await cloneClient.getUserAccount();
     const collateral = cloneClient.clone.collateral;
 
     const collateralTokenAccountInfo = await getOrCreateAssociatedTokenAccount(
@@ -52,6 +54,8 @@ exports.handler = async function (yargs: CommandArguments) {
     const amount = new BN(`${toScale(yargs.amount, Number(collateral.scale))}`);
 
     let ix = cloneClient.withdrawCollateralFromBorrowInstruction(
+      pools,
+      userAccount,
       yargs.borrowIndex,
       collateralTokenAccountInfo.address,
       amount
@@ -4,6 +4,7 @@ pub static CLONE_TOKEN_SCALE: u32 = 8;
 pub static PERCENT_SCALE: u32 = 2;
 pub static RATIO_SCALE: u32 = 2;
 pub static BPS_SCALE: u32 = 4;
+pub static INDEX_SCALE: u32 = 12;
 
 #[macro_export]
 macro_rules! to_clone_decimal {
@@ -57,6 +58,12 @@ macro_rules! to_bps_decimal {
     }};
 }
 
+pub fn rescale_away_from_zero(decimal: Decimal, scale: u32) -> Decimal {
+    let mut rounded_decimal = decimal.round_dp_with_strategy(scale, RoundingStrategy::AwayFromZero);
+    rounded_decimal.rescale(scale);
+    rounded_decimal
+}
+
 pub fn rescale_toward_zero(decimal: Decimal, scale: u32) -> Decimal {
     let mut rounded_decimal = decimal.round_dp_with_strategy(scale, RoundingStrategy::ToZero);
     rounded_decimal.rescale(scale);
@@ -157,6 +157,90 @@ pub enum CloneError {
     /// 38. Auth already exists
     #[msg("Failed to load switchboard")]
     FailedToLoadSwitchboard,
+
+    /// 39. Volume cap exceeded
+    #[msg("Swap volume cap exceeded")]
+    VolumeCapExceeded,
+
+    /// 40. Account migration required
+    #[msg("Account must be migrated to the current layout")]
+    AccountMigrationRequired,
+
+    /// 41. Account already migrated
+    #[msg("Account is already in the current layout")]
+    AccountAlreadyMigrated,
+
+    /// 42. Account capacity reached
+    #[msg("Account holds its maximum number of entries")]
+    AccountCapacityReached,
+
+    /// 43. Invalid referrer account
+    #[msg("Invalid referrer account")]
+    InvalidReferrerAccount,
+
+    /// 44. Flash loan repayment not found
+    #[msg("Flash loan repayment not found")]
+    FlashLoanRepayNotFound,
+
+    /// 45. Flash loan borrow not found
+    #[msg("Flash loan borrow not found")]
+    FlashLoanBorrowNotFound,
+
+    /// 46. Flash loan CPI not allowed
+    #[msg("Flash loans cannot be invoked via CPI")]
+    FlashLoanCpiNotAllowed,
+
+    /// 47. Swap expired
+    #[msg("Swap expired")]
+    SwapExpired,
+
+    /// 48. Oracle age exceeded
+    #[msg("Oracle price older than allowed")]
+    OracleAgeExceeded,
+
+    /// 49. Invalid collateral index
+    #[msg("Collateral index is not registered")]
+    InvalidCollateralIndex,
+
+    /// 50. Deposit cap exceeded
+    #[msg("Collateral deposit cap exceeded")]
+    DepositCapExceeded,
+
+    /// 51. Invalid borrow collateral
+    #[msg("Onasset cannot be borrowed against itself")]
+    InvalidBorrowCollateral,
+
+    /// 52. Debt ceiling exceeded
+    #[msg("Pool debt ceiling exceeded")]
+    DebtCeilingExceeded,
+
+    /// 53. Liquidity cap exceeded
+    #[msg("Pool liquidity cap exceeded")]
+    LiquidityCapExceeded,
+
+    /// 54. Flash liquidation unavailable
+    #[msg("Flash liquidation requires an active pool and the base collateral")]
+    FlashLiquidationUnavailable,
+
+    /// 55. Flash liquidation unprofitable
+    #[msg("Collateral reward does not cover the onasset bought from the pool")]
+    FlashLiquidationUnprofitable,
+
+    /// 56. Liquidation grace period active
+    #[msg("Only auth keepers may liquidate during the grace period")]
+    LiquidationGracePeriodActive,
+
+    /// 57. Settlement unavailable
+    #[msg("Settlement requires the collateral's settlement reserve")]
+    SettlementUnavailable,
+
+    /// 58. Redemption period ended
+    #[msg("Redemption period has ended")]
+    RedemptionPeriodEnded,
+
+    /// 59. Redemption period active
+    #[msg("Pool can't be removed before its redemption deadline")]
+    RedemptionPeriodActive,
 }
 
 impl From<CloneError> for ProgramError {
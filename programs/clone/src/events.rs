@@ -11,6 +11,7 @@ pub struct SwapEvent {
     pub output: u64,
     pub trading_fee: u64,
     pub treasury_fee: u64,
+    pub referral_fee: u64,
 }
 
 // LIQUIDITY CHANGE EVENT
@@ -49,11 +50,25 @@ pub struct BorrowUpdate {
     pub borrowed_delta: i64,
 }
 
+// BAD DEBT WRITE-OFF EVENT
+#[event]
+pub struct BadDebtWriteOff {
+    pub event_id: u64,
+    pub user_address: Pubkey,
+    pub pool_index: u8,
+    pub is_borrow: bool,
+    pub insurance_fund_paid: u64,
+    pub onasset_written_off: u64,
+    pub collateral_written_off: u64,
+    pub is_socialized: bool,
+}
+
 // COMET COLLATERAL CHANGE EVENT
 #[event]
 pub struct CometCollateralUpdate {
     pub event_id: u64,
     pub user_address: Pubkey,
+    pub collateral_index: u8,
     pub collateral_supplied: u64,
     pub collateral_delta: i64,
 }
@@ -0,0 +1,124 @@
+use crate::{error::*, return_error_if_false, states::*};
+use crate::{CLONE_PROGRAM_SEED, COLLATERALS_SEED, POOLS_SEED};
+use anchor_lang::prelude::*;
+use anchor_spl::token::*;
+use depository_token::Settings as DepositorySettings;
+
+#[derive(Accounts)]
+#[instruction(
+    oracle_info_index: u8,
+    collateralization_ratio: u8,
+    deposit_cap: u64,
+    min_overcollateral_ratio: u16,
+    max_liquidation_overcollateral_ratio: u16,
+)]
+pub struct AddCollateral<'info> {
+    #[account(mut, address = clone.admin)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [CLONE_PROGRAM_SEED.as_ref()],
+        bump = clone.bump,
+        has_one = admin
+    )]
+    pub clone: Box<Account<'info, Clone>>,
+    #[account(
+        mut,
+        seeds = [COLLATERALS_SEED.as_ref()],
+        bump,
+    )]
+    pub collaterals: Box<Account<'info, Collaterals>>,
+    #[account(
+        seeds = [POOLS_SEED.as_ref()],
+        bump,
+    )]
+    pub pools: Box<Account<'info, Pools>>,
+    pub collateral_mint: Box<Account<'info, Mint>>,
+    #[account(
+        token::mint = collateral_mint,
+        token::authority = clone,
+    )]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = depository_settings.depository_token_mint == collateral_mint.key() @ CloneError::InvalidValueRange,
+    )]
+    pub depository_settings: Option<Box<Account<'info, DepositorySettings>>>,
+    #[account(
+        constraint = matches!(depository_settings.as_ref(), Some(settings) if settings.depositing_token_mint == depositing_token_mint.key()) @ CloneError::InvalidValueRange,
+    )]
+    pub depositing_token_mint: Option<Box<Account<'info, Mint>>>,
+}
+
+pub fn execute(
+    ctx: Context<AddCollateral>,
+    oracle_info_index: u8,
+    collateralization_ratio: u8,
+    deposit_cap: u64,
+    min_overcollateral_ratio: u16,
+    max_liquidation_overcollateral_ratio: u16,
+) -> Result<()> {
+    return_error_if_false!(
+        collateralization_ratio <= 100,
+        CloneError::InvalidValueRange
+    );
+    let collateral_mint = ctx.accounts.collateral_mint.key();
+
+    // Onassets are priced by their pool's oracle, depository tokens by the oracle
+    // of the token they redeem for.
+    let collateral_type = if let Some(pool) = ctx
+        .accounts
+        .pools
+        .pools
+        .iter()
+        .find(|pool| pool.asset_info.onasset_mint == collateral_mint)
+    {
+        return_error_if_false!(
+            pool.asset_info.oracle_info_index == oracle_info_index,
+            CloneError::InvalidValueRange
+        );
+        CollateralType::Onasset
+    } else if let (Some(settings), Some(depositing_token_mint)) = (
+        &ctx.accounts.depository_settings,
+        &ctx.accounts.depositing_token_mint,
+    ) {
+        CollateralType::DepositoryToken {
+            ratio: settings.ratio,
+            depositing_scale: depositing_token_mint.decimals,
+        }
+    } else {
+        CollateralType::Token
+    };
+
+    let collateral = Collateral {
+        oracle_info_index,
+        mint: collateral_mint,
+        vault: ctx.accounts.collateral_vault.key(),
+        collateralization_ratio,
+        scale: ctx.accounts.collateral_mint.decimals,
+        deposit_cap,
+        collateral_type,
+        min_overcollateral_ratio,
+        max_liquidation_overcollateral_ratio,
+        total_deposits: 0,
+    };
+    return_error_if_false!(
+        collateral.is_valid_overcollateral_ratios(),
+        CloneError::InvalidOvercollateralizationRatios
+    );
+
+    let collaterals = &mut ctx.accounts.collaterals.collaterals;
+    // Index 0 is reserved for the base collateral, registered collaterals follow it.
+    return_error_if_false!(
+        collaterals.len() < NUM_COLLATERALS,
+        CloneError::AccountCapacityReached
+    );
+    return_error_if_false!(
+        collateral_mint != ctx.accounts.clone.collateral.mint
+            && collaterals
+                .iter()
+                .all(|collateral| collateral.mint != collateral_mint),
+        CloneError::InvalidValueRange
+    );
+    collaterals.push(collateral);
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use crate::error::*;
 use crate::events::*;
 use crate::states::*;
-use crate::{return_error_if_false, CLONE_PROGRAM_SEED, USER_SEED};
+use crate::{return_error_if_false, CLONE_PROGRAM_SEED, COLLATERALS_SEED, USER_SEED};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, *};
 use std::convert::TryInto;
@@ -23,7 +23,16 @@ pub struct AddCollateralToBorrow<'info> {
     pub clone: Box<Account<'info, Clone>>,
     #[account(
         mut,
-        address = clone.collateral.vault
+        seeds = [COLLATERALS_SEED.as_ref()],
+        bump,
+    )]
+    pub collaterals: Box<Account<'info, Collaterals>>,
+    #[account(
+        mut,
+        address = collaterals.get_collateral(
+            &clone.collateral,
+            user_account.borrows[borrow_index as usize].collateral_index
+        )?.vault
     )]
     pub vault: Box<Account<'info, TokenAccount>>,
     #[account(
@@ -39,6 +48,9 @@ pub fn execute(ctx: Context<AddCollateralToBorrow>, borrow_index: u8, amount: u6
     return_error_if_false!(amount > 0, CloneError::InvalidTokenAmount);
 
     let borrows = &mut ctx.accounts.user_account.borrows;
+    ctx.accounts
+        .collaterals
+        .add_deposit(borrows[borrow_index as usize].collateral_index, amount)?;
 
     // add collateral amount to mint data
     //borrows[borrow_index as usize].collateral_amount += amount;
@@ -64,10 +76,7 @@ pub fn execute(ctx: Context<AddCollateralToBorrow>, borrow_index: u8, amount: u6
     emit!(BorrowUpdate {
         event_id: ctx.accounts.clone.event_counter,
         user_address: ctx.accounts.user.key(),
-        pool_index: borrows[borrow_index as usize]
-            .pool_index
-            .try_into()
-            .map_err(|_| CloneError::IntTypeConversionError)?,
+        pool_index: borrows[borrow_index as usize].pool_index,
         is_liquidation: false,
         collateral_supplied: borrows[borrow_index as usize].collateral_amount,
         collateral_delta: amount
@@ -3,12 +3,12 @@ use std::convert::TryInto;
 use crate::error::*;
 use crate::events::*;
 use crate::states::*;
-use crate::{return_error_if_false, CLONE_PROGRAM_SEED, USER_SEED};
+use crate::{return_error_if_false, CLONE_PROGRAM_SEED, COLLATERALS_SEED, USER_SEED};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, *};
 
 #[derive(Accounts)]
-#[instruction(amount: u64)]
+#[instruction(collateral_index: u8, amount: u64)]
 pub struct AddCollateralToComet<'info> {
     pub user: Signer<'info>,
     #[account(
@@ -25,21 +25,35 @@ pub struct AddCollateralToComet<'info> {
     pub clone: Box<Account<'info, Clone>>,
     #[account(
         mut,
-        address = clone.collateral.vault,
+        seeds = [COLLATERALS_SEED.as_ref()],
+        bump,
+    )]
+    pub collaterals: Box<Account<'info, Collaterals>>,
+    #[account(
+        mut,
+        address = collaterals.get_collateral(&clone.collateral, collateral_index)?.vault,
    )]
     pub vault: Box<Account<'info, TokenAccount>>,
     #[account(
         mut,
-        associated_token::mint = clone.collateral.mint,
+        associated_token::mint = vault.mint,
         associated_token::authority = user
     )]
     pub user_collateral_token_account: Account<'info, TokenAccount>,
     pub token_program: Program<'info, Token>,
 }
 
-pub fn execute(ctx: Context<AddCollateralToComet>, amount: u64) -> Result<()> {
+pub fn execute(
+    ctx: Context<AddCollateralToComet>,
+    collateral_index: u8,
+    amount: u64,
+) -> Result<()> {
     return_error_if_false!(amount > 0, CloneError::InvalidTokenAmount);
 
+    ctx.accounts
+        .collaterals
+        .add_deposit(collateral_index, amount)?;
+
     let comet = &mut ctx.accounts.user_account.comet;
 
     // send collateral from user to vault
@@ -55,15 +69,13 @@ pub fn execute(ctx: Context<AddCollateralToComet>, amount: u64) -> Result<()> {
     let cpi_program = ctx.accounts.token_program.to_account_info();
     token::transfer(CpiContext::new(cpi_program, cpi_accounts), amount)?;
 
-    comet.collateral_amount = comet
-        .collateral_amount
-        .checked_add(amount)
-        .ok_or(error!(CloneError::CheckedMathError))?;
+    comet.add_collateral(collateral_index, amount)?;
 
     emit!(CometCollateralUpdate {
         event_id: ctx.accounts.clone.event_counter,
         user_address: ctx.accounts.user.key(),
-        collateral_supplied: comet.collateral_amount,
+        collateral_index,
+        collateral_supplied: comet.get_collateral_amount(collateral_index),
         collateral_delta: amount
             .try_into()
             .map_err(|_| CloneError::IntTypeConversionError)?,
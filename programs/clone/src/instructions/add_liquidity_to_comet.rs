@@ -4,7 +4,7 @@ use crate::events::*;
 use crate::math::*;
 use crate::states::*;
 use crate::{return_error_if_false, to_clone_decimal};
-use crate::{CLONE_PROGRAM_SEED, ORACLES_SEED, POOLS_SEED, USER_SEED};
+use crate::{CLONE_PROGRAM_SEED, COLLATERALS_SEED, ORACLES_SEED, POOLS_SEED, USER_SEED};
 use anchor_lang::prelude::*;
 use rust_decimal::prelude::*;
 use std::convert::TryInto;
@@ -37,6 +37,11 @@ pub struct AddLiquidityToComet<'info> {
         bump,
     )]
     pub oracles: Box<Account<'info, Oracles>>,
+    #[account(
+        seeds = [COLLATERALS_SEED.as_ref()],
+        bump,
+    )]
+    pub collaterals: Box<Account<'info, Collaterals>>,
 }
 
 pub fn execute(
@@ -53,8 +58,10 @@ pub fn execute(
         CloneError::StatusPreventsAction
     );
     let oracles = &ctx.accounts.oracles;
+    let collaterals = &ctx.accounts.collaterals;
     let comet = &mut ctx.accounts.user_account.comet;
     let pool = &pools.pools[pool_index as usize];
+    pool.check_liquidity_cap(collateral_amount)?;
     let committed_collateral_value =
         collateral.to_collateral_decimal(pool.committed_collateral_liquidity)?;
     let collateral_liquidity_value = collateral.to_collateral_decimal(collateral_amount)?;
@@ -72,10 +79,7 @@ pub fn execute(
             .to_collateral_decimal(pool.collateral_ild)?
             .checked_mul(proportion_value)
             .ok_or(error!(CloneError::CheckedMathError))?,
-        collateral
-            .scale
-            .try_into()
-            .map_err(|_| CloneError::IntTypeConversionError)?,
+        collateral.scale.into(),
     );
     let collateral_ild_delta: i64 = collateral_ild
         .mantissa()
@@ -136,7 +140,7 @@ pub fn execute(
         .checked_add(collateral_ild_delta)
         .ok_or(error!(CloneError::CheckedMathError))?;
 
-    let health_score = calculate_health_score(comet, pools, oracles, collateral)?;
+    let health_score = calculate_health_score(comet, pools, oracles, collateral, collaterals)?;
 
     return_error_if_false!(health_score.is_healthy(), CloneError::HealthScoreTooLow);
 
@@ -28,6 +28,8 @@ pub struct AddPool<'info> {
         mut,
         seeds = [POOLS_SEED.as_ref()],
         bump,
+        constraint = pools.to_account_info().data_len() >= POOLS_SPACE @ CloneError::AccountMigrationRequired,
+        constraint = pools.pools.len() < NUM_POOLS @ CloneError::AccountCapacityReached,
     )]
     pub pools: Box<Account<'info, Pools>>,
     #[account(
@@ -50,6 +52,7 @@ pub struct AddPool<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn execute(
     ctx: Context<AddPool>,
     min_overcollateral_ratio: u16,
@@ -67,7 +70,6 @@ pub fn execute(
         position_health_score_coefficient,
         min_overcollateral_ratio,
         max_liquidation_overcollateral_ratio,
-        ..AssetInfo::default()
     };
     return_error_if_false!(
         asset_info.is_valid_overcollateral_ratios(),
@@ -80,13 +82,19 @@ pub fn execute(
             .underlying_asset_token_account
             .to_account_info()
             .key(),
-        treasury_trading_fee_bps: treasury_trading_fee_bps.into(),
-        liquidity_trading_fee_bps: liquidity_trading_fee_bps.into(),
+        treasury_trading_fee_bps,
+        liquidity_trading_fee_bps,
         asset_info,
         status: Status::Active,
         committed_collateral_liquidity: 0,
         collateral_ild: 0,
         onasset_ild: 0,
+        swap_volume: SwapVolume::default(),
+        stability_fee: StabilityFee::default(),
+        borrowed_onasset: 0,
+        debt_ceiling: 0,
+        max_committed_collateral_liquidity: 0,
+        settlement: Settlement::default(),
     });
 
     Ok(())
@@ -1,10 +1,12 @@
 use crate::error::*;
 use crate::events::*;
+use crate::instructions::accrue_and_mint_stability_fee;
 use crate::math::*;
 use crate::return_error_if_false;
 use crate::states::*;
 use crate::{
-    to_clone_decimal, to_ratio_decimal, CLONE_PROGRAM_SEED, ORACLES_SEED, POOLS_SEED, USER_SEED,
+    to_clone_decimal, to_ratio_decimal, CLONE_PROGRAM_SEED, COLLATERALS_SEED, ORACLES_SEED,
+    POOLS_SEED, USER_SEED,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, *};
@@ -27,6 +29,7 @@ pub struct BorrowMore<'info> {
     )]
     pub clone: Box<Account<'info, Clone>>,
     #[account(
+        mut,
         seeds = [POOLS_SEED.as_ref()],
         bump
     )]
@@ -36,6 +39,11 @@ pub struct BorrowMore<'info> {
         bump,
     )]
     pub oracles: Box<Account<'info, Oracles>>,
+    #[account(
+        seeds = [COLLATERALS_SEED.as_ref()],
+        bump,
+    )]
+    pub collaterals: Box<Account<'info, Collaterals>>,
     #[account(
         mut,
         associated_token::mint = onasset_mint,
@@ -47,6 +55,12 @@ pub struct BorrowMore<'info> {
         address = pools.pools[user_account.borrows[borrow_index as usize].pool_index as usize].asset_info.onasset_mint,
     )]
     pub onasset_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = onasset_mint,
+        associated_token::authority = clone.treasury_address
+    )]
+    pub treasury_onasset_token_account: Box<Account<'info, TokenAccount>>,
     pub token_program: Program<'info, Token>,
 }
 
@@ -58,12 +72,25 @@ pub fn execute(ctx: Context<BorrowMore>, borrow_index: u8, amount: u64) -> Resul
         bytemuck::bytes_of(&ctx.accounts.clone.bump),
     ][..]];
 
-    let collateral = &ctx.accounts.clone.collateral;
-    let pools = &ctx.accounts.pools;
+    let pools = &mut ctx.accounts.pools;
     let oracles = &ctx.accounts.oracles;
     let borrows = &mut ctx.accounts.user_account.borrows;
+    let collateral = ctx.accounts.collaterals.get_collateral(
+        &ctx.accounts.clone.collateral,
+        borrows[borrow_index as usize].collateral_index,
+    )?;
 
     let pool_index = borrows[borrow_index as usize].pool_index;
+    accrue_and_mint_stability_fee(
+        &mut pools.pools[pool_index as usize],
+        &mut borrows[borrow_index as usize],
+        ctx.accounts.onasset_mint.to_account_info(),
+        ctx.accounts.treasury_onasset_token_account.to_account_info(),
+        ctx.accounts.clone.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        seeds,
+    )?;
+    pools.pools[pool_index as usize].add_borrowed_onasset(amount)?;
     let pool = &pools.pools[pool_index as usize];
     return_error_if_false!(
         pool.status == Status::Active,
@@ -73,8 +100,8 @@ pub fn execute(ctx: Context<BorrowMore>, borrow_index: u8, amount: u64) -> Resul
     let pool_oracle = &oracles.oracles[pool.asset_info.oracle_info_index as usize];
     let collateral_oracle = &oracles.oracles[collateral.oracle_info_index as usize];
     let borrow_position = borrows[borrow_index as usize];
-    let min_overcollateral_ratio = to_ratio_decimal!(pool.asset_info.min_overcollateral_ratio);
-    let collateralization_ratio = to_ratio_decimal!(collateral.collateralization_ratio);
+    let (min_overcollateral_ratio, _) = collateral.get_overcollateral_ratios(&pool.asset_info);
+    let min_overcollateral_ratio = to_ratio_decimal!(min_overcollateral_ratio);
 
     borrows[borrow_index as usize].borrowed_onasset = borrows[borrow_index as usize]
         .borrowed_onasset
@@ -84,10 +111,10 @@ pub fn execute(ctx: Context<BorrowMore>, borrow_index: u8, amount: u64) -> Resul
     // ensure position sufficiently over collateralized and oracle prices are up to date
     check_mint_collateral_sufficient(
         pool_oracle,
+        collateral,
         collateral_oracle,
         to_clone_decimal!(borrows[borrow_index as usize].borrowed_onasset),
         min_overcollateral_ratio,
-        collateralization_ratio,
         collateral.to_collateral_decimal(borrow_position.collateral_amount)?,
     )?;
 
@@ -110,9 +137,7 @@ pub fn execute(ctx: Context<BorrowMore>, borrow_index: u8, amount: u64) -> Resul
     emit!(BorrowUpdate {
         event_id: ctx.accounts.clone.event_counter,
         user_address: ctx.accounts.user.key(),
-        pool_index: pool_index
-            .try_into()
-            .map_err(|_| CloneError::IntTypeConversionError)?,
+        pool_index,
         is_liquidation: false,
         collateral_supplied: borrows[borrow_index as usize].collateral_amount,
         collateral_delta: 0,
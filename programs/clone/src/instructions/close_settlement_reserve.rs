@@ -0,0 +1,102 @@
+use crate::{error::*, return_error_if_false, states::*};
+use crate::{CLONE_PROGRAM_SEED, COLLATERALS_SEED, POOLS_SEED, SETTLEMENT_RESERVE_SEED};
+use anchor_lang::prelude::*;
+use anchor_spl::token::*;
+
+#[derive(Accounts)]
+#[instruction(pool_index: u8)]
+pub struct CloseSettlementReserve<'info> {
+    #[account(mut, address = clone.admin)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [CLONE_PROGRAM_SEED.as_ref()],
+        bump = clone.bump,
+        has_one = admin
+    )]
+    pub clone: Box<Account<'info, Clone>>,
+    #[account(
+        mut,
+        seeds = [POOLS_SEED.as_ref()],
+        bump,
+        constraint = (pool_index as usize) < pools.pools.len() @ CloneError::PoolNotFound,
+    )]
+    pub pools: Box<Account<'info, Pools>>,
+    #[account(
+        seeds = [COLLATERALS_SEED.as_ref()],
+        bump,
+    )]
+    pub collaterals: Box<Account<'info, Collaterals>>,
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            SETTLEMENT_RESERVE_SEED.as_ref(),
+            pools.pools[pool_index as usize].asset_info.onasset_mint.as_ref(),
+            vault.mint.as_ref()
+        ],
+        bump,
+    )]
+    pub settlement_reserve: Box<Account<'info, SettlementReserve>>,
+    #[account(
+        mut,
+        address = collaterals.get_collateral(
+            &clone.collateral,
+            settlement_reserve.collateral_index
+        )?.vault,
+    )]
+    pub vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = vault.mint,
+        associated_token::authority = clone.treasury_address,
+    )]
+    pub treasury_collateral_token_account: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+// Once the redemption period of a deprecated pool is over, the unredeemed collateral of each
+// registered settlement reserve goes to the treasury as `remove_pool` does for the pool's own.
+pub fn execute(ctx: Context<CloseSettlementReserve>, pool_index: u8) -> Result<()> {
+    let seeds = &[&[
+        CLONE_PROGRAM_SEED.as_ref(),
+        bytemuck::bytes_of(&ctx.accounts.clone.bump),
+    ][..]];
+    let pool = &mut ctx.accounts.pools.pools[pool_index as usize];
+    return_error_if_false!(
+        pool.status == Status::Deprecation,
+        CloneError::StatusPreventsAction
+    );
+    return_error_if_false!(
+        pool.settlement.redemption_deadline != 0
+            && !pool.settlement.is_redeemable(Clock::get()?.unix_timestamp),
+        CloneError::RedemptionPeriodActive
+    );
+    pool.settlement.registered_reserve_onasset = pool
+        .settlement
+        .registered_reserve_onasset
+        .saturating_sub(ctx.accounts.settlement_reserve.onasset_amount);
+
+    let collateral_amount = ctx.accounts.settlement_reserve.collateral_amount;
+    if collateral_amount > 0 {
+        // transfer unredeemed collateral to treasury
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.vault.to_account_info().clone(),
+            to: ctx
+                .accounts
+                .treasury_collateral_token_account
+                .to_account_info()
+                .clone(),
+            authority: ctx.accounts.clone.to_account_info().clone(),
+        };
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info().clone(),
+                cpi_accounts,
+                seeds,
+            ),
+            collateral_amount,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use crate::error::*;
+use crate::math::*;
+use crate::return_error_if_false;
+use crate::states::*;
+use crate::{CLONE_PROGRAM_SEED, ORACLES_SEED, POOLS_SEED};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(pool_index: u8, redemption_deadline: i64)]
+pub struct DeprecatePool<'info> {
+    #[account(address = clone.admin)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [CLONE_PROGRAM_SEED.as_ref()],
+        bump = clone.bump,
+    )]
+    pub clone: Box<Account<'info, Clone>>,
+    #[account(
+        mut,
+        seeds = [POOLS_SEED.as_ref()],
+        bump,
+        constraint = (pool_index as usize) < pools.pools.len() @ CloneError::PoolNotFound,
+    )]
+    pub pools: Box<Account<'info, Pools>>,
+    #[account(
+        seeds = [ORACLES_SEED.as_ref()],
+        bump,
+    )]
+    pub oracles: Box<Account<'info, Oracles>>,
+}
+
+// Deprecated pools settle as in extraction but holders can only redeem until the deadline,
+// after which `remove_pool` sweeps what is left to the treasury. Pools already settled keep
+// their snapshot price.
+pub fn execute(
+    ctx: Context<DeprecatePool>,
+    pool_index: u8,
+    redemption_deadline: i64,
+) -> Result<()> {
+    let collateral = &ctx.accounts.clone.collateral;
+    let oracles = &ctx.accounts.oracles;
+    let pool = &mut ctx.accounts.pools.pools[pool_index as usize];
+    return_error_if_false!(
+        pool.settlement.redemption_deadline == 0,
+        CloneError::StatusPreventsAction
+    );
+    return_error_if_false!(
+        redemption_deadline > Clock::get()?.unix_timestamp,
+        CloneError::InvalidValueRange
+    );
+
+    if pool.settlement.pool_price == 0 {
+        let pool_oracle = &oracles.oracles[pool.asset_info.oracle_info_index as usize];
+        let collateral_oracle = &oracles.oracles[collateral.oracle_info_index as usize];
+        snapshot_settlement_price(pool, pool_oracle, collateral_oracle)?;
+    }
+    pool.settlement.redemption_deadline = redemption_deadline;
+    pool.status = Status::Deprecation;
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+use crate::error::*;
+use crate::instruction::{FlashBorrow as FlashBorrowArgs, FlashRepay as FlashRepayArgs};
+use crate::states::*;
+use crate::{return_error_if_false, CLONE_PROGRAM_SEED, POOLS_SEED};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked, ID as INSTRUCTIONS_SYSVAR_ID,
+};
+use anchor_lang::Discriminator;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum FlashLoanAsset {
+    Onasset { pool_index: u8 },
+    Collateral,
+}
+
+#[derive(Accounts)]
+#[instruction(asset: FlashLoanAsset, amount: u64)]
+pub struct FlashBorrow<'info> {
+    pub user: Signer<'info>,
+    #[account(
+        seeds = [CLONE_PROGRAM_SEED.as_ref()],
+        bump = clone.bump,
+    )]
+    pub clone: Box<Account<'info, Clone>>,
+    #[account(
+        seeds = [POOLS_SEED.as_ref()],
+        bump,
+    )]
+    pub pools: Box<Account<'info, Pools>>,
+    #[account(mut)]
+    pub onasset_mint: Option<Box<Account<'info, Mint>>>,
+    #[account(
+        mut,
+        address = clone.collateral.vault
+    )]
+    pub collateral_vault: Option<Box<Account<'info, TokenAccount>>>,
+    #[account(mut)]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: Checked by address constraint.
+    #[account(address = INSTRUCTIONS_SYSVAR_ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+// Flash loans must be issued by a top-level instruction and followed by a matching
+// repayment before any other flash loan is opened.
+fn check_repayment_follows(
+    instructions_sysvar: &AccountInfo,
+    asset: FlashLoanAsset,
+    amount: u64,
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)? as usize;
+    let current_instruction = load_instruction_at_checked(current_index, instructions_sysvar)?;
+    return_error_if_false!(
+        current_instruction.program_id == crate::ID,
+        CloneError::FlashLoanCpiNotAllowed
+    );
+
+    let mut index = current_index + 1;
+    while let Ok(instruction) = load_instruction_at_checked(index, instructions_sysvar) {
+        if instruction.program_id == crate::ID && instruction.data.len() >= 8 {
+            let discriminator = &instruction.data[..8];
+            return_error_if_false!(
+                discriminator != FlashBorrowArgs::discriminator(),
+                CloneError::FlashLoanRepayNotFound
+            );
+            if discriminator == FlashRepayArgs::discriminator() {
+                let args = FlashRepayArgs::deserialize(&mut &instruction.data[8..])
+                    .map_err(|_| error!(CloneError::InvalidConversion))?;
+                return_error_if_false!(
+                    args.asset == asset && args.amount == amount,
+                    CloneError::FlashLoanRepayNotFound
+                );
+                return Ok(());
+            }
+        }
+        index += 1;
+    }
+
+    Err(error!(CloneError::FlashLoanRepayNotFound))
+}
+
+pub fn execute(ctx: Context<FlashBorrow>, asset: FlashLoanAsset, amount: u64) -> Result<()> {
+    return_error_if_false!(amount > 0, CloneError::InvalidTokenAmount);
+    check_repayment_follows(&ctx.accounts.instructions_sysvar, asset, amount)?;
+
+    let seeds = &[&[
+        CLONE_PROGRAM_SEED.as_ref(),
+        bytemuck::bytes_of(&ctx.accounts.clone.bump),
+    ][..]];
+
+    match asset {
+        FlashLoanAsset::Onasset { pool_index } => {
+            return_error_if_false!(
+                (pool_index as usize) < ctx.accounts.pools.pools.len(),
+                CloneError::PoolNotFound
+            );
+            let pool = &ctx.accounts.pools.pools[pool_index as usize];
+            return_error_if_false!(
+                pool.status == Status::Active,
+                CloneError::StatusPreventsAction
+            );
+            let onasset_mint = ctx
+                .accounts
+                .onasset_mint
+                .as_ref()
+                .ok_or(error!(CloneError::ExpectedAccountNotFound))?;
+            return_error_if_false!(
+                onasset_mint.key() == pool.asset_info.onasset_mint
+                    && ctx.accounts.user_token_account.mint == onasset_mint.key(),
+                CloneError::InvalidInputCollateralAccount
+            );
+
+            // mint onasset to user
+            token::mint_to(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    MintTo {
+                        mint: onasset_mint.to_account_info().clone(),
+                        to: ctx.accounts.user_token_account.to_account_info().clone(),
+                        authority: ctx.accounts.clone.to_account_info().clone(),
+                    },
+                    seeds,
+                ),
+                amount,
+            )?;
+        }
+        FlashLoanAsset::Collateral => {
+            let collateral_vault = ctx
+                .accounts
+                .collateral_vault
+                .as_ref()
+                .ok_or(error!(CloneError::ExpectedAccountNotFound))?;
+            return_error_if_false!(
+                ctx.accounts.user_token_account.mint == ctx.accounts.clone.collateral.mint,
+                CloneError::InvalidInputCollateralAccount
+            );
+
+            // transfer collateral from vault to user
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: collateral_vault.to_account_info().clone(),
+                        to: ctx.accounts.user_token_account.to_account_info().clone(),
+                        authority: ctx.accounts.clone.to_account_info().clone(),
+                    },
+                    seeds,
+                ),
+                amount,
+            )?;
+        }
+    }
+
+    Ok(())
+}
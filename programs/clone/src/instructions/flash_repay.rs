@@ -0,0 +1,169 @@
+use crate::error::*;
+use crate::instruction::{FlashBorrow as FlashBorrowArgs, FlashRepay as FlashRepayArgs};
+use crate::instructions::FlashLoanAsset;
+use crate::states::*;
+use crate::{return_error_if_false, to_bps_decimal, CLONE_PROGRAM_SEED, POOLS_SEED};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked, ID as INSTRUCTIONS_SYSVAR_ID,
+};
+use anchor_lang::Discriminator;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+use rust_decimal::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(asset: FlashLoanAsset, amount: u64)]
+pub struct FlashRepay<'info> {
+    pub user: Signer<'info>,
+    #[account(
+        seeds = [CLONE_PROGRAM_SEED.as_ref()],
+        bump = clone.bump,
+    )]
+    pub clone: Box<Account<'info, Clone>>,
+    #[account(
+        seeds = [POOLS_SEED.as_ref()],
+        bump,
+    )]
+    pub pools: Box<Account<'info, Pools>>,
+    #[account(mut)]
+    pub onasset_mint: Option<Box<Account<'info, Mint>>>,
+    #[account(
+        mut,
+        address = clone.collateral.vault
+    )]
+    pub collateral_vault: Option<Box<Account<'info, TokenAccount>>>,
+    #[account(mut)]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == clone.treasury_address @ CloneError::Unauthorized,
+        constraint = treasury_token_account.mint == user_token_account.mint @ CloneError::InvalidInputCollateralAccount,
+    )]
+    pub treasury_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: Checked by address constraint.
+    #[account(address = INSTRUCTIONS_SYSVAR_ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+// The repayment must close the most recent flash loan opened in this transaction.
+fn check_borrow_precedes(
+    instructions_sysvar: &AccountInfo,
+    asset: FlashLoanAsset,
+    amount: u64,
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)? as usize;
+
+    for index in (0..current_index).rev() {
+        let instruction = load_instruction_at_checked(index, instructions_sysvar)?;
+        if instruction.program_id == crate::ID && instruction.data.len() >= 8 {
+            let discriminator = &instruction.data[..8];
+            return_error_if_false!(
+                discriminator != FlashRepayArgs::discriminator(),
+                CloneError::FlashLoanBorrowNotFound
+            );
+            if discriminator == FlashBorrowArgs::discriminator() {
+                let args = FlashBorrowArgs::deserialize(&mut &instruction.data[8..])
+                    .map_err(|_| error!(CloneError::InvalidConversion))?;
+                return_error_if_false!(
+                    args.asset == asset && args.amount == amount,
+                    CloneError::FlashLoanBorrowNotFound
+                );
+                return Ok(());
+            }
+        }
+    }
+
+    Err(error!(CloneError::FlashLoanBorrowNotFound))
+}
+
+pub fn execute(ctx: Context<FlashRepay>, asset: FlashLoanAsset, amount: u64) -> Result<()> {
+    return_error_if_false!(amount > 0, CloneError::InvalidTokenAmount);
+    check_borrow_precedes(&ctx.accounts.instructions_sysvar, asset, amount)?;
+
+    // Fees are rounded up in favor of the protocol.
+    let fee: u64 = Decimal::from(amount)
+        .checked_mul(to_bps_decimal!(ctx.accounts.clone.flash_loan_fee_bps))
+        .ok_or(error!(CloneError::CheckedMathError))?
+        .ceil()
+        .to_u64()
+        .ok_or(error!(CloneError::IntTypeConversionError))?;
+
+    match asset {
+        FlashLoanAsset::Onasset { pool_index } => {
+            return_error_if_false!(
+                (pool_index as usize) < ctx.accounts.pools.pools.len(),
+                CloneError::PoolNotFound
+            );
+            let pool = &ctx.accounts.pools.pools[pool_index as usize];
+            let onasset_mint = ctx
+                .accounts
+                .onasset_mint
+                .as_ref()
+                .ok_or(error!(CloneError::ExpectedAccountNotFound))?;
+            return_error_if_false!(
+                onasset_mint.key() == pool.asset_info.onasset_mint
+                    && ctx.accounts.user_token_account.mint == onasset_mint.key(),
+                CloneError::InvalidInputCollateralAccount
+            );
+
+            // burn the flash minted onasset from user
+            token::burn(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Burn {
+                        mint: onasset_mint.to_account_info().clone(),
+                        from: ctx.accounts.user_token_account.to_account_info().clone(),
+                        authority: ctx.accounts.user.to_account_info().clone(),
+                    },
+                ),
+                amount,
+            )?;
+        }
+        FlashLoanAsset::Collateral => {
+            let collateral_vault = ctx
+                .accounts
+                .collateral_vault
+                .as_ref()
+                .ok_or(error!(CloneError::ExpectedAccountNotFound))?;
+            return_error_if_false!(
+                ctx.accounts.user_token_account.mint == ctx.accounts.clone.collateral.mint,
+                CloneError::InvalidInputCollateralAccount
+            );
+
+            // return borrowed collateral to vault
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.user_token_account.to_account_info().clone(),
+                        to: collateral_vault.to_account_info().clone(),
+                        authority: ctx.accounts.user.to_account_info().clone(),
+                    },
+                ),
+                amount,
+            )?;
+        }
+    }
+
+    // send fee to treasury
+    if fee > 0 {
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user_token_account.to_account_info().clone(),
+                    to: ctx
+                        .accounts
+                        .treasury_token_account
+                        .to_account_info()
+                        .clone(),
+                    authority: ctx.accounts.user.to_account_info().clone(),
+                },
+            ),
+            fee,
+        )?;
+    }
+
+    Ok(())
+}
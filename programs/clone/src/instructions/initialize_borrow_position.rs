@@ -4,14 +4,15 @@ use crate::math::*;
 use crate::return_error_if_false;
 use crate::states::*;
 use crate::{
-    to_clone_decimal, to_ratio_decimal, CLONE_PROGRAM_SEED, ORACLES_SEED, POOLS_SEED, USER_SEED,
+    to_clone_decimal, to_ratio_decimal, CLONE_PROGRAM_SEED, COLLATERALS_SEED, ORACLES_SEED,
+    POOLS_SEED, USER_SEED,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
 use std::convert::TryInto;
 
 #[derive(Accounts)]
-#[instruction(pool_index: u8, onasset_amount: u64, collateral_amount: u64)]
+#[instruction(pool_index: u8, onasset_amount: u64, collateral_amount: u64, collateral_index: u8)]
 pub struct InitializeBorrowPosition<'info> {
     pub user: Signer<'info>,
     #[account(
@@ -40,7 +41,13 @@ pub struct InitializeBorrowPosition<'info> {
     pub oracles: Box<Account<'info, Oracles>>,
     #[account(
         mut,
-        address = clone.collateral.vault
+        seeds = [COLLATERALS_SEED.as_ref()],
+        bump,
+    )]
+    pub collaterals: Box<Account<'info, Collaterals>>,
+    #[account(
+        mut,
+        address = collaterals.get_collateral(&clone.collateral, collateral_index)?.vault
     )]
     pub vault: Box<Account<'info, TokenAccount>>,
     #[account(
@@ -68,6 +75,7 @@ pub fn execute(
     pool_index: u8,
     onasset_amount: u64,
     collateral_amount: u64,
+    collateral_index: u8,
 ) -> Result<()> {
     return_error_if_false!(
         onasset_amount > 0 && collateral_amount > 0,
@@ -78,7 +86,13 @@ pub fn execute(
         CLONE_PROGRAM_SEED.as_ref(),
         bytemuck::bytes_of(&ctx.accounts.clone.bump),
     ][..]];
-    let collateral = &ctx.accounts.clone.collateral;
+    ctx.accounts
+        .collaterals
+        .add_deposit(collateral_index, collateral_amount)?;
+    let collateral = ctx
+        .accounts
+        .collaterals
+        .get_collateral(&ctx.accounts.clone.collateral, collateral_index)?;
     let pools = &mut ctx.accounts.pools;
     let oracles = &ctx.accounts.oracles;
 
@@ -87,21 +101,25 @@ pub fn execute(
         pool.status == Status::Active,
         CloneError::StatusPreventsAction
     );
+    return_error_if_false!(
+        collateral.mint != pool.asset_info.onasset_mint,
+        CloneError::InvalidBorrowCollateral
+    );
 
     let pool_oracle = &oracles.oracles[pool.asset_info.oracle_info_index as usize];
     let collateral_oracle = &oracles.oracles[collateral.oracle_info_index as usize];
-    let min_overcollateral_ratio = to_ratio_decimal!(pool.asset_info.min_overcollateral_ratio);
-    let collateralization_ratio = to_ratio_decimal!(collateral.collateralization_ratio);
+    let (min_overcollateral_ratio, _) = collateral.get_overcollateral_ratios(&pool.asset_info);
+    let min_overcollateral_ratio = to_ratio_decimal!(min_overcollateral_ratio);
 
     let collateral_amount_value = collateral.to_collateral_decimal(collateral_amount)?;
     let onasset_amount_value = to_clone_decimal!(onasset_amount);
     // ensure position sufficiently over collateralized and oracle prices are up to date
     check_mint_collateral_sufficient(
         pool_oracle,
+        collateral,
         collateral_oracle,
         onasset_amount_value,
         min_overcollateral_ratio,
-        collateralization_ratio,
         collateral_amount_value,
     )?;
 
@@ -138,14 +156,25 @@ pub fn execute(
         onasset_amount,
     )?;
 
+    let pool = &mut pools.pools[pool_index as usize];
+    pool.add_borrowed_onasset(onasset_amount)?;
+    // start the position at the pool's current stability fee index
+    pool.stability_fee.accrue(Clock::get()?.unix_timestamp)?;
+    let stability_fee_index = pool.stability_fee.cumulative_index;
+
     // set mint position data
     let user_account = &mut ctx.accounts.user_account;
+    return_error_if_false!(
+        user_account.borrows.len() < NUM_BORROW_POSITIONS,
+        CloneError::AccountCapacityReached
+    );
     user_account.borrows.push(Borrow {
         collateral_amount,
-        pool_index: pool_index
-            .try_into()
-            .map_err(|_| CloneError::IntTypeConversionError)?,
+        pool_index,
         borrowed_onasset: onasset_amount,
+        collateral_index,
+        stability_fee_index,
+        liquidation_start_slot: 0,
     });
 
     emit!(BorrowUpdate {
@@ -19,7 +19,7 @@ pub struct InitializeClone<'info> {
     #[account(
         init,
         seeds = [CLONE_PROGRAM_SEED.as_ref()],
-        space = 8 + 472,
+        space = CLONE_SPACE,
         bump,
         payer = admin
     )]
@@ -62,6 +62,7 @@ pub fn execute(
         scale: ctx.accounts.collateral_mint.decimals,
         oracle_info_index: collateral_oracle_index,
         collateralization_ratio,
+        ..Collateral::default()
     };
     ctx.accounts.clone.comet_collateral_ild_liquidator_fee_bps =
         comet_collateral_ild_liquidator_fee_bps;
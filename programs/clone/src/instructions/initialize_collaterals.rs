@@ -0,0 +1,30 @@
+use crate::states::*;
+use crate::CLONE_PROGRAM_SEED;
+use anchor_lang::prelude::*;
+
+pub const COLLATERALS_SEED: &str = "collaterals";
+
+#[derive(Accounts)]
+pub struct InitializeCollaterals<'info> {
+    #[account(mut, address = clone.admin)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [CLONE_PROGRAM_SEED.as_ref()],
+        bump,
+        has_one = admin,
+    )]
+    pub clone: Box<Account<'info, Clone>>,
+    #[account(
+        init,
+        space = COLLATERALS_SPACE,
+        seeds = [COLLATERALS_SEED.as_ref()],
+        bump,
+        payer = admin,
+    )]
+    pub collaterals: Account<'info, Collaterals>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn execute(_ctx: Context<InitializeCollaterals>) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use crate::CLONE_PROGRAM_SEED;
+use crate::{error::*, return_error_if_false, states::*};
+use anchor_lang::prelude::*;
+
+pub const REFERRER_SEED: &str = "referrer";
+
+#[derive(Accounts)]
+#[instruction(authority: Pubkey, fee_share_bps: u16)]
+pub struct InitializeReferrer<'info> {
+    #[account(mut, address = clone.admin)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [CLONE_PROGRAM_SEED.as_ref()],
+        bump = clone.bump,
+        has_one = admin,
+    )]
+    pub clone: Box<Account<'info, Clone>>,
+    #[account(
+        init,
+        space = 8 + 42,
+        seeds = [REFERRER_SEED.as_ref(), authority.as_ref()],
+        bump,
+        payer = admin,
+    )]
+    pub referrer: Account<'info, Referrer>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn execute(
+    ctx: Context<InitializeReferrer>,
+    authority: Pubkey,
+    fee_share_bps: u16,
+) -> Result<()> {
+    return_error_if_false!(fee_share_bps <= 10000, CloneError::InvalidValueRange);
+
+    ctx.accounts.referrer.authority = authority;
+    ctx.accounts.referrer.fee_share_bps = fee_share_bps;
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use crate::states::*;
+use crate::{error::*, return_error_if_false};
+use crate::{CLONE_PROGRAM_SEED, COLLATERALS_SEED, POOLS_SEED};
+use anchor_lang::prelude::*;
+
+pub const SETTLEMENT_RESERVE_SEED: &str = "settlement-reserve";
+
+#[derive(Accounts)]
+#[instruction(pool_index: u8, collateral_index: u8)]
+pub struct InitializeSettlementReserve<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CLONE_PROGRAM_SEED.as_ref()],
+        bump = clone.bump,
+    )]
+    pub clone: Box<Account<'info, Clone>>,
+    #[account(
+        seeds = [POOLS_SEED.as_ref()],
+        bump,
+        constraint = (pool_index as usize) < pools.pools.len() @ CloneError::PoolNotFound,
+        constraint = pools.pools[pool_index as usize].is_settling() @ CloneError::StatusPreventsAction
+    )]
+    pub pools: Box<Account<'info, Pools>>,
+    #[account(
+        seeds = [COLLATERALS_SEED.as_ref()],
+        bump,
+    )]
+    pub collaterals: Box<Account<'info, Collaterals>>,
+    #[account(
+        init,
+        space = 8 + 49,
+        seeds = [
+            SETTLEMENT_RESERVE_SEED.as_ref(),
+            pools.pools[pool_index as usize].asset_info.onasset_mint.as_ref(),
+            collaterals.get_collateral(&clone.collateral, collateral_index)?.mint.as_ref()
+        ],
+        bump,
+        payer = payer,
+    )]
+    pub settlement_reserve: Account<'info, SettlementReserve>,
+    pub system_program: Program<'info, System>,
+}
+
+// Permissionless, borrows backed by a registered collateral settle into its reserve.
+pub fn execute(
+    ctx: Context<InitializeSettlementReserve>,
+    pool_index: u8,
+    collateral_index: u8,
+) -> Result<()> {
+    return_error_if_false!(collateral_index > 0, CloneError::InvalidCollateralIndex);
+
+    ctx.accounts.settlement_reserve.onasset_mint = ctx.accounts.pools.pools[pool_index as usize]
+        .asset_info
+        .onasset_mint;
+    ctx.accounts.settlement_reserve.collateral_index = collateral_index;
+
+    Ok(())
+}
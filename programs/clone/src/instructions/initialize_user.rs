@@ -10,7 +10,7 @@ pub struct InitializeUser<'info> {
     pub payer: Signer<'info>,
     #[account(
         init,
-        space = 10240,
+        space = USER_SPACE,
         seeds = [USER_SEED.as_ref(), authority.as_ref()],
         bump,
         payer = payer,
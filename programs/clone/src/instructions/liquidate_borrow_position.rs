@@ -1,12 +1,14 @@
-use crate::decimal::rescale_toward_zero;
+use crate::decimal::{rescale_toward_zero, CLONE_TOKEN_SCALE};
 use crate::error::*;
 use crate::events::*;
+use crate::instructions::accrue_and_mint_stability_fee;
 use crate::math::*;
 use crate::return_error_if_false;
 use crate::states::*;
 use crate::to_ratio_decimal;
 use crate::{
-    to_bps_decimal, to_clone_decimal, CLONE_PROGRAM_SEED, ORACLES_SEED, POOLS_SEED, USER_SEED,
+    to_bps_decimal, to_clone_decimal, CLONE_PROGRAM_SEED, COLLATERALS_SEED, ORACLES_SEED,
+    POOLS_SEED, USER_SEED,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, *};
@@ -35,6 +37,12 @@ pub struct LiquidateBorrowPosition<'info> {
         bump,
     )]
     pub oracles: Box<Account<'info, Oracles>>,
+    #[account(
+        mut,
+        seeds = [COLLATERALS_SEED.as_ref()],
+        bump,
+    )]
+    pub collaterals: Box<Account<'info, Collaterals>>,
     #[account(
         mut,
         seeds = [USER_SEED.as_ref(), user.as_ref()],
@@ -48,7 +56,10 @@ pub struct LiquidateBorrowPosition<'info> {
     pub onasset_mint: Box<Account<'info, Mint>>,
     #[account(
         mut,
-        address = clone.collateral.vault,
+        address = collaterals.get_collateral(
+            &clone.collateral,
+            user_account.borrows[borrow_index as usize].collateral_index
+        )?.vault,
    )]
     pub vault: Box<Account<'info, TokenAccount>>,
     #[account(
@@ -57,12 +68,24 @@ pub struct LiquidateBorrowPosition<'info> {
         associated_token::authority = liquidator
    )]
     pub liquidator_collateral_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = vault.mint,
+        associated_token::authority = clone.treasury_address
+    )]
+    pub treasury_collateral_token_account: Box<Account<'info, TokenAccount>>,
     #[account(
         mut,
         associated_token::mint = onasset_mint,
         associated_token::authority = liquidator
     )]
     pub liquidator_onasset_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = onasset_mint,
+        associated_token::authority = clone.treasury_address
+    )]
+    pub treasury_onasset_token_account: Box<Account<'info, TokenAccount>>,
     pub token_program: Program<'info, Token>,
 }
 
@@ -71,54 +94,59 @@ pub fn execute(
     user: Pubkey,
     borrow_index: u8,
     amount: u64,
+    flash: bool,
 ) -> Result<()> {
     return_error_if_false!(amount > 0, CloneError::InvalidTokenAmount);
 
-    if !ctx.accounts.clone.non_auth_liquidations_enabled {
-        return_error_if_false!(
-            ctx.accounts
-                .clone
-                .auth
-                .contains(ctx.accounts.liquidator.key),
-            CloneError::Unauthorized
-        );
-    }
-
     let seeds = &[&[
         CLONE_PROGRAM_SEED.as_ref(),
         bytemuck::bytes_of(&ctx.accounts.clone.bump),
     ][..]];
 
-    let collateral = &ctx.accounts.clone.collateral;
     let pools = &mut ctx.accounts.pools;
     let oracles = &ctx.accounts.oracles;
 
     let borrows = &mut ctx.accounts.user_account.borrows;
+    let pool_index = borrows[borrow_index as usize].pool_index as usize;
+    accrue_and_mint_stability_fee(
+        &mut pools.pools[pool_index],
+        &mut borrows[borrow_index as usize],
+        ctx.accounts.onasset_mint.to_account_info(),
+        ctx.accounts.treasury_onasset_token_account.to_account_info(),
+        ctx.accounts.clone.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        seeds,
+    )?;
     let borrow_position = borrows[borrow_index as usize];
-    let pool_index = borrow_position.pool_index as usize;
+    let collateral = ctx.accounts.collaterals.get_collateral(
+        &ctx.accounts.clone.collateral,
+        borrow_position.collateral_index,
+    )?;
     let pool = &pools.pools[pool_index];
     return_error_if_false!(
-        pool.status != Status::Frozen,
+        pool.status != Status::Frozen && !pool.is_settling(),
         CloneError::StatusPreventsAction
     );
 
     let pool_oracle = &oracles.oracles[pool.asset_info.oracle_info_index as usize];
     let collateral_oracle = &oracles.oracles[collateral.oracle_info_index as usize];
 
-    let min_overcollateral_ratio = to_ratio_decimal!(pool.asset_info.min_overcollateral_ratio);
+    let (min_overcollateral_ratio, max_liquidation_overcollateral_ratio) =
+        collateral.get_overcollateral_ratios(&pool.asset_info);
+    let min_overcollateral_ratio = to_ratio_decimal!(min_overcollateral_ratio);
     let collateralization_ratio = to_ratio_decimal!(collateral.collateralization_ratio);
 
-    let burn_amount = amount.min(borrow_position.borrowed_onasset);
+    let borrowed_onasset = to_clone_decimal!(borrow_position.borrowed_onasset);
     let collateral_position_amount =
         collateral.to_collateral_decimal(borrow_position.collateral_amount)?;
 
     // This call checks that the oracles are updated
     let is_undercollateralized = check_mint_collateral_sufficient(
         pool_oracle,
+        collateral,
         collateral_oracle,
-        to_clone_decimal!(borrow_position.borrowed_onasset),
+        borrowed_onasset,
         min_overcollateral_ratio,
-        collateralization_ratio,
         collateral_position_amount,
     )
     .is_err();
@@ -128,46 +156,172 @@ pub fn execute(
         is_undercollateralized || is_in_liquidation_mode,
         CloneError::BorrowPositionUnableToLiquidate
     );
-
-    let borrow_liquidation_fee_rate = to_bps_decimal!(ctx.accounts.clone.borrow_liquidator_fee_bps);
     let pool_price = pool_oracle
         .get_price()?
-        .checked_div(collateral_oracle.get_price()?)
+        .checked_div(collateral.get_price(collateral_oracle)?)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+    let borrowed_value = pool_price
+        .checked_mul(borrowed_onasset)
         .ok_or(error!(CloneError::CheckedMathError))?;
 
-    let collateral_reward = rescale_toward_zero(
-        (Decimal::one()
-            .checked_add(borrow_liquidation_fee_rate)
-            .ok_or(error!(CloneError::CheckedMathError))?)
-        .checked_mul(to_clone_decimal!(burn_amount))
-        .ok_or(error!(CloneError::CheckedMathError))?
+    // Deeply undercollateralized positions skip ahead in the auction and the grace period.
+    let health_shortfall = if borrowed_value > Decimal::ZERO {
+        calculate_borrow_health_shortfall(
+            collateral_position_amount
+                .checked_mul(collateralization_ratio)
+                .ok_or(error!(CloneError::CheckedMathError))?
+                .checked_div(borrowed_value)
+                .ok_or(error!(CloneError::CheckedMathError))?,
+            min_overcollateral_ratio,
+        )?
+    } else {
+        Decimal::ZERO
+    };
+    let slot = Clock::get()?.slot;
+    borrows[borrow_index as usize].update_liquidation_auction(true, slot);
+    ctx.accounts.clone.check_liquidator(
+        ctx.accounts.liquidator.key,
+        borrows[borrow_index as usize].liquidation_start_slot,
+        slot,
+        health_shortfall,
+    )?;
+    let auction_progress = calculate_liquidation_auction_progress(
+        borrows[borrow_index as usize].liquidation_start_slot,
+        slot,
+        ctx.accounts.clone.liquidation_auction_slots,
+    );
+
+    // At most the close factor of the debt can be repaid per liquidation, a zero close
+    // factor, a dust position or a pool in liquidation mode allows repaying it in full.
+    let close_factor_amount: u64 = rescale_toward_zero(
+        borrowed_onasset
+            .checked_mul(to_bps_decimal!(
+                ctx.accounts.clone.borrow_liquidation_close_factor_bps
+            ))
+            .ok_or(error!(CloneError::CheckedMathError))?,
+        CLONE_TOKEN_SCALE,
+    )
+    .mantissa()
+    .try_into()
+    .map_err(|_| CloneError::IntTypeConversionError)?;
+    let max_burn_amount = if close_factor_amount == 0 || is_in_liquidation_mode {
+        borrow_position.borrowed_onasset
+    } else {
+        close_factor_amount
+    };
+    let burn_amount = amount.min(max_burn_amount);
+
+    let borrow_liquidation_fee_rate = calculate_liquidation_fee_rate(
+        ctx.accounts.clone.borrow_liquidator_fee_bps,
+        ctx.accounts.clone.borrow_liquidator_max_fee_bps,
+        auction_progress.max(health_shortfall),
+    )?;
+
+    let collateral_scale: u32 = collateral.scale.into();
+    let repaid_value = to_clone_decimal!(burn_amount)
         .checked_mul(pool_price)
-        .ok_or(error!(CloneError::CheckedMathError))?,
-        collateral
-            .scale
-            .try_into()
-            .map_err(|_| CloneError::IntTypeConversionError)?,
+        .ok_or(error!(CloneError::CheckedMathError))?;
+    let bonus_value = repaid_value
+        .checked_mul(borrow_liquidation_fee_rate)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+    let full_collateral_reward = rescale_toward_zero(
+        repaid_value
+            .checked_add(bonus_value)
+            .ok_or(error!(CloneError::CheckedMathError))?,
+        collateral_scale,
+    );
+    let collateral_reward = full_collateral_reward.min(collateral_position_amount);
+    // The protocol takes its cut out of the bonus, never out of the repaid value.
+    let protocol_fee = rescale_toward_zero(
+        bonus_value
+            .checked_mul(to_bps_decimal!(
+                ctx.accounts.clone.borrow_liquidation_protocol_fee_bps
+            ))
+            .ok_or(error!(CloneError::CheckedMathError))?,
+        collateral_scale,
     )
-    .min(collateral_position_amount);
+    .min(collateral_reward);
+    // The insurance fund lives in the base collateral vault, so it only tops up rewards
+    // for positions backed by the base collateral.
+    let insurance_fund_paid: u64 = if borrow_position.collateral_index == 0 {
+        TryInto::<u64>::try_into(
+            full_collateral_reward
+                .checked_sub(collateral_reward)
+                .ok_or(error!(CloneError::CheckedMathError))?
+                .mantissa(),
+        )
+        .map_err(|_| CloneError::IntTypeConversionError)?
+        .min(ctx.accounts.clone.insurance_fund)
+    } else {
+        0
+    };
+    let mut liquidator_reward = collateral_reward
+        .checked_sub(protocol_fee)
+        .ok_or(error!(CloneError::CheckedMathError))?
+        .checked_add(collateral.to_collateral_decimal(insurance_fund_paid)?)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+
+    // Flash liquidations buy the burned onasset from the pool with the liquidator's reward,
+    // which needs the pool to be denominated in the position's collateral.
+    if flash {
+        return_error_if_false!(
+            borrow_position.collateral_index == 0,
+            CloneError::FlashLiquidationUnavailable
+        );
+        let (collateral_spent, treasury_fees) = swap_collateral_for_liquidated_onasset(
+            &mut pools.pools[pool_index],
+            pool_oracle,
+            collateral_oracle,
+            collateral,
+            to_clone_decimal!(burn_amount),
+        )?;
+        return_error_if_false!(
+            collateral_spent <= liquidator_reward,
+            CloneError::FlashLiquidationUnprofitable
+        );
+        liquidator_reward = liquidator_reward
+            .checked_sub(collateral_spent)
+            .ok_or(error!(CloneError::CheckedMathError))?;
+
+        // Mint the swap's treasury fees
+        if treasury_fees > 0 {
+            let cpi_accounts = MintTo {
+                mint: ctx.accounts.onasset_mint.to_account_info().clone(),
+                to: ctx
+                    .accounts
+                    .treasury_onasset_token_account
+                    .to_account_info()
+                    .clone(),
+                authority: ctx.accounts.clone.to_account_info().clone(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            token::mint_to(
+                CpiContext::new_with_signer(cpi_program, cpi_accounts, seeds),
+                treasury_fees,
+            )?;
+        }
+    }
 
     // Burn the onAsset from the liquidator
-    let cpi_accounts = Burn {
-        mint: ctx.accounts.onasset_mint.to_account_info().clone(),
-        from: ctx
-            .accounts
-            .liquidator_onasset_token_account
-            .to_account_info()
-            .clone(),
-        authority: ctx.accounts.liquidator.to_account_info().clone(),
-    };
-    let burn_liquidator_onasset_context = CpiContext::new(
-        ctx.accounts.token_program.to_account_info().clone(),
-        cpi_accounts,
-    );
+    if !flash {
+        let cpi_accounts = Burn {
+            mint: ctx.accounts.onasset_mint.to_account_info().clone(),
+            from: ctx
+                .accounts
+                .liquidator_onasset_token_account
+                .to_account_info()
+                .clone(),
+            authority: ctx.accounts.liquidator.to_account_info().clone(),
+        };
+        let burn_liquidator_onasset_context = CpiContext::new(
+            ctx.accounts.token_program.to_account_info().clone(),
+            cpi_accounts,
+        );
 
-    token::burn(burn_liquidator_onasset_context, burn_amount)?;
+        token::burn(burn_liquidator_onasset_context, burn_amount)?;
+    }
 
-    // Send the user the collateral reward
+    // Send the liquidator the collateral reward
     let cpi_accounts = Transfer {
         from: ctx.accounts.vault.to_account_info().clone(),
         to: ctx
@@ -184,13 +338,38 @@ pub fn execute(
     );
     token::transfer(
         send_collateral_context,
-        collateral_reward
+        liquidator_reward
             .mantissa()
             .try_into()
             .map_err(|_| CloneError::IntTypeConversionError)?,
     )?;
 
+    // Send the protocol its cut of the liquidation bonus
+    if protocol_fee > Decimal::ZERO {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.vault.to_account_info().clone(),
+            to: ctx
+                .accounts
+                .treasury_collateral_token_account
+                .to_account_info()
+                .clone(),
+            authority: ctx.accounts.clone.to_account_info().clone(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info().clone(),
+                cpi_accounts,
+                seeds,
+            ),
+            protocol_fee
+                .mantissa()
+                .try_into()
+                .map_err(|_| CloneError::IntTypeConversionError)?,
+        )?;
+    }
+
     // Update data
+    pools.pools[pool_index].remove_borrowed_onasset(burn_amount);
     borrows[borrow_index as usize].borrowed_onasset = borrows[borrow_index as usize]
         .borrowed_onasset
         .checked_sub(burn_amount)
@@ -205,9 +384,46 @@ pub fn execute(
         )
         .ok_or(error!(CloneError::CheckedMathError))?;
 
-    // Remove position if empty
+    // Debt left once the position's collateral is exhausted is written off and socialised
+    // across the pool's LPs as onasset ILD.
+    let onasset_written_off = if borrows[borrow_index as usize].collateral_amount == 0 {
+        borrows[borrow_index as usize].borrowed_onasset
+    } else {
+        0
+    };
+    if insurance_fund_paid > 0 || onasset_written_off > 0 {
+        let is_socialized = pools.pools[pool_index].socialize_ild(
+            onasset_written_off
+                .try_into()
+                .map_err(|_| CloneError::IntTypeConversionError)?,
+            0,
+        )?;
+        pools.pools[pool_index].remove_borrowed_onasset(onasset_written_off);
+        borrows[borrow_index as usize].borrowed_onasset = borrows[borrow_index as usize]
+            .borrowed_onasset
+            .checked_sub(onasset_written_off)
+            .ok_or(error!(CloneError::CheckedMathError))?;
+        emit!(BadDebtWriteOff {
+            event_id: ctx.accounts.clone.event_counter,
+            user_address: user,
+            pool_index: pool_index
+                .try_into()
+                .map_err(|_| CloneError::IntTypeConversionError)?,
+            is_borrow: true,
+            insurance_fund_paid,
+            onasset_written_off,
+            collateral_written_off: 0,
+            is_socialized,
+        });
+    }
+    let collateral_supplied = borrows[borrow_index as usize].collateral_amount;
+    let borrowed_amount = borrows[borrow_index as usize].borrowed_onasset;
+
+    // Remove position if empty, a repaid position only has collateral left to withdraw.
     if borrows[borrow_index as usize].is_empty() {
         borrows.remove(borrow_index as usize);
+    } else if borrows[borrow_index as usize].borrowed_onasset == 0 {
+        borrows[borrow_index as usize].update_liquidation_auction(false, slot);
     } else {
         let borrowed_onasset = to_clone_decimal!(borrows[borrow_index as usize].borrowed_onasset);
         let collateral_amount = Decimal::new(
@@ -215,13 +431,10 @@ pub fn execute(
                 .collateral_amount
                 .try_into()
                 .map_err(|_| CloneError::IntTypeConversionError)?,
-            collateral
-                .scale
-                .try_into()
-                .map_err(|_| CloneError::IntTypeConversionError)?,
+            collateral.scale.into(),
         );
         let max_liquidation_overcollateral_ratio =
-            to_ratio_decimal!(pool.asset_info.max_liquidation_overcollateral_ratio);
+            to_ratio_decimal!(max_liquidation_overcollateral_ratio);
         let c_ratio = collateral_amount
             .checked_mul(collateralization_ratio)
             .ok_or(error!(CloneError::CheckedMathError))?
@@ -235,7 +448,18 @@ pub fn execute(
             c_ratio <= max_liquidation_overcollateral_ratio,
             CloneError::InvalidMintCollateralRatio
         );
+        borrows[borrow_index as usize].update_liquidation_auction(
+            c_ratio < min_overcollateral_ratio || is_in_liquidation_mode,
+            slot,
+        );
     }
+    ctx.accounts.collaterals.remove_deposit(
+        borrow_position.collateral_index,
+        collateral_reward
+            .mantissa()
+            .try_into()
+            .map_err(|_| CloneError::IntTypeConversionError)?,
+    )?;
 
     emit!(BorrowUpdate {
         event_id: ctx.accounts.clone.event_counter,
@@ -244,16 +468,22 @@ pub fn execute(
             .try_into()
             .map_err(|_| CloneError::IntTypeConversionError)?,
         is_liquidation: true,
-        collateral_supplied: borrows[borrow_index as usize].collateral_amount,
+        collateral_supplied,
         collateral_delta: -(collateral_reward
             .mantissa()
             .try_into()
             .map_err(|_| CloneError::IntTypeConversionError)?),
-        borrowed_amount: borrows[borrow_index as usize].borrowed_onasset,
+        borrowed_amount,
         borrowed_delta: -(burn_amount
             .try_into()
             .map_err(|_| CloneError::IntTypeConversionError)?)
     });
+    ctx.accounts.clone.insurance_fund = ctx
+        .accounts
+        .clone
+        .insurance_fund
+        .checked_sub(insurance_fund_paid)
+        .ok_or(error!(CloneError::CheckedMathError))?;
     ctx.accounts.clone.event_counter = ctx
         .accounts
         .clone
@@ -1,11 +1,15 @@
-use crate::decimal::rescale_toward_zero;
+use crate::decimal::{rescale_away_from_zero, rescale_toward_zero};
 use crate::error::*;
 use crate::events::*;
-use crate::instructions::withdraw_liquidity;
+use crate::instructions::{
+    seize_comet_collateral_deposit, transfer_seized_comet_deposit, withdraw_liquidity,
+    write_off_comet_bad_debt,
+};
 use crate::math::*;
 use crate::states::*;
 use crate::{
-    return_error_if_false, to_bps_decimal, CLONE_PROGRAM_SEED, ORACLES_SEED, POOLS_SEED, USER_SEED,
+    return_error_if_false, CLONE_PROGRAM_SEED, COLLATERALS_SEED, ORACLES_SEED, POOLS_SEED,
+    USER_SEED,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, *};
@@ -39,6 +43,12 @@ pub struct LiquidateCometCollateralIld<'info> {
         bump,
     )]
     pub oracles: Box<Account<'info, Oracles>>,
+    #[account(
+        mut,
+        seeds = [COLLATERALS_SEED.as_ref()],
+        bump,
+    )]
+    pub collaterals: Box<Account<'info, Collaterals>>,
     #[account(
         mut,
         address = clone.collateral.mint
@@ -55,6 +65,10 @@ pub struct LiquidateCometCollateralIld<'info> {
         address = clone.collateral.vault,
    )]
     pub vault: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub deposit_vault: Option<Box<Account<'info, TokenAccount>>>,
+    #[account(mut)]
+    pub liquidator_deposit_token_account: Option<Box<Account<'info, TokenAccount>>>,
     pub token_program: Program<'info, Token>,
 }
 
@@ -63,16 +77,6 @@ pub fn execute(
     user: Pubkey,
     comet_position_index: u8,
 ) -> Result<()> {
-    if !ctx.accounts.clone.non_auth_liquidations_enabled {
-        return_error_if_false!(
-            ctx.accounts
-                .clone
-                .auth
-                .contains(ctx.accounts.liquidator.key),
-            CloneError::Unauthorized
-        );
-    }
-
     let seeds = &[&[
         CLONE_PROGRAM_SEED.as_ref(),
         bytemuck::bytes_of(&ctx.accounts.clone.bump),
@@ -80,6 +84,7 @@ pub fn execute(
     let collateral = &ctx.accounts.clone.collateral;
     let pools = &mut ctx.accounts.pools;
     let oracles = &ctx.accounts.oracles;
+    let collaterals = &mut ctx.accounts.collaterals;
     let comet = &mut ctx.accounts.user_account.comet;
 
     let comet_position = comet.positions[comet_position_index as usize];
@@ -90,45 +95,109 @@ pub fn execute(
         pool.status == Status::Active || pool.status == Status::Liquidation,
         CloneError::StatusPreventsAction
     );
-    let collateral_scale = collateral
-        .scale
-        .try_into()
-        .map_err(|_| CloneError::IntTypeConversionError)?;
+    let collateral_scale = collateral.scale.into();
 
     let is_in_liquidation_mode = pool.status == Status::Liquidation;
-    let starting_health_score = calculate_health_score(comet, pools, oracles, collateral)?;
+    let starting_health_score =
+        calculate_health_score(comet, pools, oracles, collateral, collaterals)?;
 
     return_error_if_false!(
         !starting_health_score.is_healthy() || is_in_liquidation_mode,
         CloneError::NotSubjectToLiquidation
     );
+    let slot = Clock::get()?.slot;
+    comet.update_liquidation_auction(true, slot);
+    // Deeply unhealthy comets skip ahead in the auction and the grace period.
+    let health_shortfall = calculate_comet_health_shortfall(&starting_health_score)?;
+    ctx.accounts.clone.check_liquidator(
+        ctx.accounts.liquidator.key,
+        comet.liquidation_start_slot,
+        slot,
+        health_shortfall,
+    )?;
+    let auction_progress = calculate_liquidation_auction_progress(
+        comet.liquidation_start_slot,
+        slot,
+        ctx.accounts.clone.liquidation_auction_slots,
+    )
+    .max(health_shortfall);
 
+    let target_health_score = ctx.accounts.clone.comet_liquidation_target_health_score;
+    let mut insurance_fund_paid = 0;
     if ild_share.collateral_ild_share > Decimal::ZERO {
         // calculate reward for liquidator
-        let liquidator_fee =
-            to_bps_decimal!(ctx.accounts.clone.comet_collateral_ild_liquidator_fee_bps);
-        let collateral_reward: u64 = rescale_toward_zero(
+        let liquidator_fee = calculate_liquidation_fee_rate(
+            ctx.accounts.clone.comet_collateral_ild_liquidator_fee_bps,
+            ctx.accounts
+                .clone
+                .comet_collateral_ild_liquidator_max_fee_bps,
+            auction_progress,
+        )?;
+        // Pay only the ILD needed to restore the target health score when paying it helps.
+        let mut ild_payment = ild_share.collateral_ild_share;
+        if !is_in_liquidation_mode {
+            if let Some(needed_ild_payment) = calculate_liquidation_ild_payment(
+                calculate_health_score_deficit(&starting_health_score, target_health_score)?,
+                Decimal::ONE,
+                pool,
+                collateral,
+                liquidator_fee,
+                target_health_score,
+            )? {
+                ild_payment =
+                    ild_payment.min(rescale_away_from_zero(needed_ild_payment, collateral_scale));
+            }
+        }
+        let full_collateral_reward: u64 = rescale_toward_zero(
             liquidator_fee
-                .checked_mul(ild_share.collateral_ild_share)
+                .checked_mul(ild_payment)
                 .ok_or(error!(CloneError::CheckedMathError))?,
             collateral_scale,
         )
         .mantissa()
         .try_into()
         .map_err(|_| CloneError::IntTypeConversionError)?;
-        // Remove equivalent reward from user's collateral
-        let ild_share: u64 = ild_share
-            .collateral_ild_share
+        let full_ild_share: u64 = ild_payment
             .mantissa()
             .try_into()
             .map_err(|_| CloneError::IntTypeConversionError)?;
-        let collateral_reduction = collateral_reward
-            .checked_add(ild_share)
+        // The registered deposit in `deposit_vault` and then the insurance fund cover what the
+        // comet's collateral can't, paying the liquidator first. Whatever remains unpaid is
+        // written off once the comet is exhausted.
+        let full_collateral_reduction = full_collateral_reward
+            .checked_add(full_ild_share)
             .ok_or(error!(CloneError::CheckedMathError))?;
-        return_error_if_false!(
-            collateral_reduction <= comet.collateral_amount,
-            CloneError::InvalidTokenAmount
-        );
+        let collateral_reduction = full_collateral_reduction.min(comet.collateral_amount);
+        let collateral_shortfall = full_collateral_reduction
+            .checked_sub(collateral_reduction)
+            .ok_or(error!(CloneError::CheckedMathError))?;
+        let (deposit_collateral_index, deposit_seized, deposit_paid) =
+            match &ctx.accounts.deposit_vault {
+                Some(deposit_vault) if collateral_shortfall > 0 => seize_comet_collateral_deposit(
+                    comet,
+                    oracles,
+                    collateral,
+                    collaterals,
+                    &deposit_vault.key(),
+                    collateral_shortfall,
+                )?,
+                _ => (0, 0, 0),
+            };
+        insurance_fund_paid = collateral_shortfall
+            .checked_sub(deposit_paid)
+            .ok_or(error!(CloneError::CheckedMathError))?
+            .min(ctx.accounts.clone.insurance_fund);
+        let collateral_paid = collateral_reduction
+            .checked_add(deposit_paid)
+            .ok_or(error!(CloneError::CheckedMathError))?
+            .checked_add(insurance_fund_paid)
+            .ok_or(error!(CloneError::CheckedMathError))?;
+        let collateral_reward = full_collateral_reward.min(collateral_paid);
+        let ild_share = collateral_paid
+            .checked_sub(collateral_reward)
+            .ok_or(error!(CloneError::CheckedMathError))?;
+
+        // Remove equivalent reward from user's collateral
         comet.collateral_amount = comet
             .collateral_amount
             .checked_sub(collateral_reduction)
@@ -143,25 +212,64 @@ pub fn execute(
             )
             .ok_or(error!(CloneError::CheckedMathError))?;
 
-        // Transfer collateral to liquidator
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.vault.to_account_info().clone(),
-            to: ctx
-                .accounts
-                .liquidator_collateral_token_account
-                .to_account_info()
-                .clone(),
-            authority: ctx.accounts.clone.to_account_info().clone(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        token::transfer(
-            CpiContext::new_with_signer(cpi_program, cpi_accounts, seeds),
-            collateral_reward,
-        )?;
+        // The seized deposit is part of the liquidator's reward, any of it covering the ILD
+        // is bought by the liquidator with the base collateral the pool is owed.
+        if collateral_reward >= deposit_paid {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.vault.to_account_info().clone(),
+                to: ctx
+                    .accounts
+                    .liquidator_collateral_token_account
+                    .to_account_info()
+                    .clone(),
+                authority: ctx.accounts.clone.to_account_info().clone(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            token::transfer(
+                CpiContext::new_with_signer(cpi_program, cpi_accounts, seeds),
+                collateral_reward - deposit_paid,
+            )?;
+        } else {
+            let cpi_accounts = Transfer {
+                from: ctx
+                    .accounts
+                    .liquidator_collateral_token_account
+                    .to_account_info()
+                    .clone(),
+                to: ctx.accounts.vault.to_account_info().clone(),
+                authority: ctx.accounts.liquidator.to_account_info().clone(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            token::transfer(
+                CpiContext::new(cpi_program, cpi_accounts),
+                deposit_paid - collateral_reward,
+            )?;
+        }
+        if deposit_seized > 0 {
+            transfer_seized_comet_deposit(
+                &ctx.accounts.deposit_vault,
+                &ctx.accounts.liquidator_deposit_token_account,
+                ctx.accounts.liquidator.key,
+                ctx.accounts.clone.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                seeds,
+                deposit_seized,
+            )?;
+            emit!(CometCollateralUpdate {
+                event_id: ctx.accounts.clone.event_counter,
+                user_address: user.key(),
+                collateral_index: deposit_collateral_index,
+                collateral_supplied: comet.get_collateral_amount(deposit_collateral_index),
+                collateral_delta: -(deposit_seized
+                    .try_into()
+                    .map_err(|_| CloneError::IntTypeConversionError)?),
+            });
+        }
 
         emit!(CometCollateralUpdate {
             event_id: ctx.accounts.clone.event_counter,
             user_address: user.key(),
+            collateral_index: 0,
             collateral_supplied: comet.collateral_amount,
             collateral_delta: -(collateral_reduction
                 .try_into()
@@ -169,19 +277,52 @@ pub fn execute(
         });
     }
 
-    // Withdraw liquidity position
-    if comet_position.committed_collateral_liquidity > 0 {
+    // Withdraw only the liquidity needed to restore the target health score, pools in
+    // liquidation mode are unwound entirely.
+    let liquidity_to_withdraw = if is_in_liquidation_mode {
+        comet_position.committed_collateral_liquidity
+    } else {
+        let health_score = calculate_health_score(comet, pools, oracles, collateral, collaterals)?;
+        calculate_liquidation_liquidity_withdrawal(
+            calculate_health_score_deficit(&health_score, target_health_score)?,
+            &pools.pools[pool_index],
+            &comet.positions[comet_position_index as usize],
+            collateral,
+        )?
+    };
+    if liquidity_to_withdraw > 0 {
         withdraw_liquidity(
             pools,
             oracles,
             comet,
             collateral,
             comet_position_index,
-            comet_position.committed_collateral_liquidity,
+            liquidity_to_withdraw,
             user,
             ctx.accounts.clone.event_counter,
         )?;
     };
+    write_off_comet_bad_debt(
+        pools,
+        comet,
+        collateral,
+        comet_position_index,
+        user,
+        insurance_fund_paid,
+        ctx.accounts.clone.event_counter,
+    )?;
+    let ending_health_score =
+        calculate_health_score(comet, pools, oracles, collateral, collaterals)?;
+    comet.update_liquidation_auction(
+        !ending_health_score.is_healthy() || is_in_liquidation_mode,
+        slot,
+    );
+    ctx.accounts.clone.insurance_fund = ctx
+        .accounts
+        .clone
+        .insurance_fund
+        .checked_sub(insurance_fund_paid)
+        .ok_or(error!(CloneError::CheckedMathError))?;
     ctx.accounts.clone.event_counter = ctx
         .accounts
         .clone
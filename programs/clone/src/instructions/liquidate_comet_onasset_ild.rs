@@ -1,10 +1,11 @@
-use crate::decimal::rescale_toward_zero;
+use crate::decimal::{rescale_away_from_zero, rescale_toward_zero, CLONE_TOKEN_SCALE};
 use crate::error::*;
+use crate::events::*;
 use crate::instructions::withdraw_liquidity;
 use crate::math::*;
 use crate::states::*;
 use crate::{
-    return_error_if_false, to_bps_decimal, to_clone_decimal, CLONE_PROGRAM_SEED, ORACLES_SEED,
+    return_error_if_false, to_clone_decimal, CLONE_PROGRAM_SEED, COLLATERALS_SEED, ORACLES_SEED,
     POOLS_SEED, USER_SEED,
 };
 use anchor_lang::prelude::*;
@@ -39,6 +40,12 @@ pub struct LiquidateCometOnassetIld<'info> {
         bump,
     )]
     pub oracles: Box<Account<'info, Oracles>>,
+    #[account(
+        mut,
+        seeds = [COLLATERALS_SEED.as_ref()],
+        bump,
+    )]
+    pub collaterals: Box<Account<'info, Collaterals>>,
     #[account(
         mut,
         address = pools.pools[user_account.comet.positions[comet_position_index as usize].pool_index as usize].asset_info.onasset_mint,
@@ -56,32 +63,198 @@ pub struct LiquidateCometOnassetIld<'info> {
         associated_token::mint = vault.mint,
     )]
     pub liquidator_collateral_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = onasset_mint,
+        associated_token::authority = clone.treasury_address
+    )]
+    pub treasury_onasset_token_account: Box<Account<'info, TokenAccount>>,
     #[account(
             mut,
             address = clone.collateral.vault,
        )]
     pub vault: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub deposit_vault: Option<Box<Account<'info, TokenAccount>>>,
+    #[account(mut)]
+    pub liquidator_deposit_token_account: Option<Box<Account<'info, TokenAccount>>>,
     pub token_program: Program<'info, Token>,
 }
 
+// Seizes the comet's registered collateral held in `deposit_vault` worth up to `value` of the
+// base collateral, valued as in the health score. Returns the deposit's collateral index, the
+// amount seized and the base collateral value it covers.
+pub fn seize_comet_collateral_deposit(
+    comet: &mut Comet,
+    oracles: &Oracles,
+    collateral: &Collateral,
+    collaterals: &mut Collaterals,
+    deposit_vault: &Pubkey,
+    value: u64,
+) -> Result<(u8, u64, u64)> {
+    let deposit = *comet
+        .collateral_deposits
+        .iter()
+        .find(|deposit| {
+            matches!(
+                collaterals.get_collateral(collateral, deposit.collateral_index),
+                Ok(deposit_collateral) if deposit_collateral.vault == *deposit_vault
+            )
+        })
+        .ok_or(error!(CloneError::InvalidInputCollateralAccount))?;
+    let deposit_collateral = collaterals.get_collateral(collateral, deposit.collateral_index)?;
+    let deposit_price = oracles.oracles[deposit_collateral.oracle_info_index as usize]
+        .get_price()?
+        .checked_div(oracles.oracles[collateral.oracle_info_index as usize].get_price()?)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+
+    let seized_amount: u64 = rescale_away_from_zero(
+        collateral
+            .to_collateral_decimal(value)?
+            .checked_div(deposit_price)
+            .ok_or(error!(CloneError::CheckedMathError))?,
+        deposit_collateral.scale.into(),
+    )
+    .mantissa()
+    .try_into()
+    .map_err(|_| CloneError::IntTypeConversionError)?;
+    let seized_amount = seized_amount.min(deposit.amount);
+    let seized_value: u64 = rescale_toward_zero(
+        deposit_collateral
+            .to_collateral_decimal(seized_amount)?
+            .checked_mul(deposit_price)
+            .ok_or(error!(CloneError::CheckedMathError))?,
+        collateral.scale.into(),
+    )
+    .mantissa()
+    .try_into()
+    .map_err(|_| CloneError::IntTypeConversionError)?;
+    comet.remove_collateral(deposit.collateral_index, seized_amount)?;
+    collaterals.remove_deposit(deposit.collateral_index, seized_amount)?;
+
+    Ok((
+        deposit.collateral_index,
+        seized_amount,
+        seized_value.min(value),
+    ))
+}
+
+// Sends a deposit seized by `seize_comet_collateral_deposit` to the liquidator.
+pub fn transfer_seized_comet_deposit<'info>(
+    deposit_vault: &Option<Box<Account<'info, TokenAccount>>>,
+    liquidator_deposit_token_account: &Option<Box<Account<'info, TokenAccount>>>,
+    liquidator: &Pubkey,
+    clone: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<()> {
+    let (deposit_vault, liquidator_deposit_token_account) =
+        match (deposit_vault, liquidator_deposit_token_account) {
+            (Some(deposit_vault), Some(liquidator_deposit_token_account)) => {
+                (deposit_vault, liquidator_deposit_token_account)
+            }
+            _ => return Err(error!(CloneError::InvalidInputCollateralAccount)),
+        };
+    return_error_if_false!(
+        liquidator_deposit_token_account.mint == deposit_vault.mint
+            && liquidator_deposit_token_account.owner == *liquidator,
+        CloneError::InvalidInputCollateralAccount
+    );
+    let cpi_accounts = Transfer {
+        from: deposit_vault.to_account_info(),
+        to: liquidator_deposit_token_account.to_account_info(),
+        authority: clone,
+    };
+    token::transfer(
+        CpiContext::new_with_signer(token_program, cpi_accounts, seeds),
+        amount,
+    )
+}
+
+// Once the comet's collateral is exhausted, the ILD left on the liquidated position is
+// socialised across the pool's remaining LPs.
+pub fn write_off_comet_bad_debt(
+    pools: &mut Pools,
+    comet: &mut Comet,
+    collateral: &Collateral,
+    comet_position_index: u8,
+    user: Pubkey,
+    insurance_fund_paid: u64,
+    event_counter: u64,
+) -> Result<()> {
+    let comet_position = comet.positions[comet_position_index as usize];
+    let is_exhausted = comet.collateral_amount == 0
+        && comet
+            .collateral_deposits
+            .iter()
+            .all(|deposit| deposit.amount == 0)
+        && comet_position.committed_collateral_liquidity == 0;
+    let (onasset_written_off, collateral_written_off): (i64, i64) = if is_exhausted {
+        let ild_share = calculate_ild_share(&comet_position, pools, collateral)?;
+        (
+            ild_share
+                .onasset_ild_share
+                .max(Decimal::ZERO)
+                .mantissa()
+                .try_into()
+                .map_err(|_| CloneError::IntTypeConversionError)?,
+            ild_share
+                .collateral_ild_share
+                .max(Decimal::ZERO)
+                .mantissa()
+                .try_into()
+                .map_err(|_| CloneError::IntTypeConversionError)?,
+        )
+    } else {
+        (0, 0)
+    };
+    if insurance_fund_paid == 0 && onasset_written_off == 0 && collateral_written_off == 0 {
+        return Ok(());
+    }
+
+    let pool_index = comet_position.pool_index as usize;
+    let is_socialized =
+        pools.pools[pool_index].socialize_ild(onasset_written_off, collateral_written_off)?;
+    let position = &mut comet.positions[comet_position_index as usize];
+    position.onasset_ild_rebate = position
+        .onasset_ild_rebate
+        .checked_add(onasset_written_off)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+    position.collateral_ild_rebate = position
+        .collateral_ild_rebate
+        .checked_add(collateral_written_off)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+
+    emit!(BadDebtWriteOff {
+        event_id: event_counter,
+        user_address: user,
+        pool_index: pool_index
+            .try_into()
+            .map_err(|_| CloneError::IntTypeConversionError)?,
+        is_borrow: false,
+        insurance_fund_paid,
+        onasset_written_off: onasset_written_off
+            .try_into()
+            .map_err(|_| CloneError::IntTypeConversionError)?,
+        collateral_written_off: collateral_written_off
+            .try_into()
+            .map_err(|_| CloneError::IntTypeConversionError)?,
+        is_socialized,
+    });
+
+    Ok(())
+}
+
 pub fn execute(
     ctx: Context<LiquidateCometOnassetIld>,
     user: Pubkey,
     comet_position_index: u8,
     amount: u64,
+    flash: bool,
 ) -> Result<()> {
     return_error_if_false!(amount > 0, CloneError::InvalidTokenAmount);
 
-    if !ctx.accounts.clone.non_auth_liquidations_enabled {
-        return_error_if_false!(
-            ctx.accounts
-                .clone
-                .auth
-                .contains(ctx.accounts.liquidator.key),
-            CloneError::Unauthorized
-        );
-    }
-
     let seeds = &[&[
         CLONE_PROGRAM_SEED.as_ref(),
         bytemuck::bytes_of(&ctx.accounts.clone.bump),
@@ -89,6 +262,7 @@ pub fn execute(
     let collateral = &ctx.accounts.clone.collateral;
     let pools = &mut ctx.accounts.pools;
     let oracles = &ctx.accounts.oracles;
+    let collaterals = &mut ctx.accounts.collaterals;
     let comet = &mut ctx.accounts.user_account.comet;
     let comet_position = comet.positions[comet_position_index as usize];
     let authorized_amount = to_clone_decimal!(amount);
@@ -105,25 +279,57 @@ pub fn execute(
     let onasset_price = pool_oracle.get_price()?;
     let collateral_oracle = &oracles.oracles[collateral.oracle_info_index as usize];
     let collateral_price = collateral_oracle.get_price()?;
-    let collateral_scale = collateral
-        .scale
-        .try_into()
-        .map_err(|_| CloneError::IntTypeConversionError)?;
+    let collateral_scale = collateral.scale.into();
     let pool_price = onasset_price
         .checked_div(collateral_price)
         .ok_or(error!(CloneError::CheckedMathError))?;
 
     let is_in_liquidation_mode = pool.status == Status::Liquidation;
-    let starting_health_score = calculate_health_score(comet, pools, oracles, collateral)?;
+    let starting_health_score =
+        calculate_health_score(comet, pools, oracles, collateral, collaterals)?;
 
     return_error_if_false!(
         !starting_health_score.is_healthy() || is_in_liquidation_mode,
         CloneError::NotSubjectToLiquidation
     );
+    let slot = Clock::get()?.slot;
+    comet.update_liquidation_auction(true, slot);
+    // Deeply unhealthy comets skip ahead in the auction and the grace period.
+    let health_shortfall = calculate_comet_health_shortfall(&starting_health_score)?;
+    ctx.accounts.clone.check_liquidator(
+        ctx.accounts.liquidator.key,
+        comet.liquidation_start_slot,
+        slot,
+        health_shortfall,
+    )?;
+    let auction_progress = calculate_liquidation_auction_progress(
+        comet.liquidation_start_slot,
+        slot,
+        ctx.accounts.clone.liquidation_auction_slots,
+    )
+    .max(health_shortfall);
 
-    let burn_amount = ild_share.onasset_ild_share.min(authorized_amount);
+    let liquidator_fee = calculate_liquidation_fee_rate(
+        ctx.accounts.clone.comet_onasset_ild_liquidator_fee_bps,
+        ctx.accounts.clone.comet_onasset_ild_liquidator_max_fee_bps,
+        auction_progress,
+    )?;
 
-    let liquidator_fee = to_bps_decimal!(ctx.accounts.clone.comet_onasset_ild_liquidator_fee_bps);
+    // Pay only the ILD needed to restore the target health score when paying it helps.
+    let target_health_score = ctx.accounts.clone.comet_liquidation_target_health_score;
+    let mut burn_amount = ild_share.onasset_ild_share.min(authorized_amount);
+    if !is_in_liquidation_mode {
+        if let Some(ild_payment) = calculate_liquidation_ild_payment(
+            calculate_health_score_deficit(&starting_health_score, target_health_score)?,
+            pool_price,
+            pool,
+            collateral,
+            liquidator_fee,
+            target_health_score,
+        )? {
+            burn_amount = burn_amount.min(rescale_away_from_zero(ild_payment, CLONE_TOKEN_SCALE));
+        }
+    }
 
     // calculate reward for liquidator
     let collateral_reward = rescale_toward_zero(
@@ -137,6 +343,51 @@ pub fn execute(
         collateral_scale,
     );
 
+    // Rewards beyond the comet's collateral are paid from the registered deposit in
+    // `deposit_vault` and then drawn from the insurance fund, whatever neither covers reduces
+    // the burn and is written off once the comet is exhausted.
+    let full_collateral_reward: u64 = collateral_reward
+        .mantissa()
+        .try_into()
+        .map_err(|_| CloneError::IntTypeConversionError)?;
+    let comet_collateral_paid = full_collateral_reward.min(comet.collateral_amount);
+    let collateral_shortfall = full_collateral_reward
+        .checked_sub(comet_collateral_paid)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+    let (deposit_collateral_index, deposit_seized, deposit_paid) = match &ctx.accounts.deposit_vault
+    {
+        Some(deposit_vault) if collateral_shortfall > 0 => seize_comet_collateral_deposit(
+            comet,
+            oracles,
+            collateral,
+            collaterals,
+            &deposit_vault.key(),
+            collateral_shortfall,
+        )?,
+        _ => (0, 0, 0),
+    };
+    let insurance_fund_paid = collateral_shortfall
+        .checked_sub(deposit_paid)
+        .ok_or(error!(CloneError::CheckedMathError))?
+        .min(ctx.accounts.clone.insurance_fund);
+    let collateral_reward = comet_collateral_paid
+        .checked_add(deposit_paid)
+        .ok_or(error!(CloneError::CheckedMathError))?
+        .checked_add(insurance_fund_paid)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+    let burn_amount = if collateral_reward < full_collateral_reward {
+        rescale_toward_zero(
+            burn_amount
+                .checked_mul(Decimal::from(collateral_reward))
+                .ok_or(error!(CloneError::CheckedMathError))?
+                .checked_div(Decimal::from(full_collateral_reward))
+                .ok_or(error!(CloneError::CheckedMathError))?,
+            CLONE_TOKEN_SCALE,
+        )
+    } else {
+        burn_amount
+    };
+
     if ild_share.onasset_ild_share > Decimal::ZERO {
         let ild_rebate_increase: i64 = burn_amount
             .mantissa()
@@ -147,22 +398,64 @@ pub fn execute(
             .onasset_ild_rebate
             .checked_add(ild_rebate_increase)
             .ok_or(error!(CloneError::CheckedMathError))?;
-        let cpi_accounts = Burn {
-            mint: ctx.accounts.onasset_mint.to_account_info().clone(),
-            from: ctx
-                .accounts
-                .liquidator_onasset_token_account
-                .to_account_info()
-                .clone(),
-            authority: ctx.accounts.liquidator.to_account_info().clone(),
-        };
 
-        token::burn(
-            CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
-            ild_rebate_increase
+        // Flash liquidations buy the onasset from the pool with the liquidator's base
+        // collateral reward instead of burning it from the liquidator.
+        let mut liquidator_reward = comet_collateral_paid
+            .checked_add(insurance_fund_paid)
+            .ok_or(error!(CloneError::CheckedMathError))?;
+        if flash {
+            let (collateral_spent, treasury_fees) = swap_collateral_for_liquidated_onasset(
+                &mut pools.pools[pool_index],
+                pool_oracle,
+                collateral_oracle,
+                collateral,
+                burn_amount,
+            )?;
+            let collateral_spent: u64 = collateral_spent
+                .mantissa()
                 .try_into()
-                .map_err(|_| CloneError::IntTypeConversionError)?,
-        )?;
+                .map_err(|_| CloneError::IntTypeConversionError)?;
+            liquidator_reward = liquidator_reward
+                .checked_sub(collateral_spent)
+                .ok_or(error!(CloneError::FlashLiquidationUnprofitable))?;
+            if treasury_fees > 0 {
+                let cpi_accounts = MintTo {
+                    mint: ctx.accounts.onasset_mint.to_account_info().clone(),
+                    to: ctx
+                        .accounts
+                        .treasury_onasset_token_account
+                        .to_account_info()
+                        .clone(),
+                    authority: ctx.accounts.clone.to_account_info().clone(),
+                };
+                token::mint_to(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        cpi_accounts,
+                        seeds,
+                    ),
+                    treasury_fees,
+                )?;
+            }
+        } else {
+            let cpi_accounts = Burn {
+                mint: ctx.accounts.onasset_mint.to_account_info().clone(),
+                from: ctx
+                    .accounts
+                    .liquidator_onasset_token_account
+                    .to_account_info()
+                    .clone(),
+                authority: ctx.accounts.liquidator.to_account_info().clone(),
+            };
+
+            token::burn(
+                CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
+                ild_rebate_increase
+                    .try_into()
+                    .map_err(|_| CloneError::IntTypeConversionError)?,
+            )?;
+        }
 
         // Transfer collateral to liquidator
         let cpi_accounts = Transfer {
@@ -177,37 +470,83 @@ pub fn execute(
         let cpi_program = ctx.accounts.token_program.to_account_info();
         token::transfer(
             CpiContext::new_with_signer(cpi_program, cpi_accounts, seeds),
-            collateral_reward
-                .mantissa()
-                .try_into()
-                .map_err(|_| CloneError::IntTypeConversionError)?,
+            liquidator_reward,
         )?;
 
         // Remove equivalent reward from user's collateral
         comet.collateral_amount = comet
             .collateral_amount
-            .checked_sub(
-                collateral_reward
-                    .mantissa()
-                    .try_into()
-                    .map_err(|_| CloneError::IntTypeConversionError)?,
-            )
+            .checked_sub(comet_collateral_paid)
             .ok_or(error!(CloneError::CheckedMathError))?;
     }
 
-    // Withdraw liquidity position
-    if comet_position.committed_collateral_liquidity > 0 {
+    if deposit_seized > 0 {
+        transfer_seized_comet_deposit(
+            &ctx.accounts.deposit_vault,
+            &ctx.accounts.liquidator_deposit_token_account,
+            ctx.accounts.liquidator.key,
+            ctx.accounts.clone.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            seeds,
+            deposit_seized,
+        )?;
+        emit!(CometCollateralUpdate {
+            event_id: ctx.accounts.clone.event_counter,
+            user_address: user,
+            collateral_index: deposit_collateral_index,
+            collateral_supplied: comet.get_collateral_amount(deposit_collateral_index),
+            collateral_delta: -(deposit_seized
+                .try_into()
+                .map_err(|_| CloneError::IntTypeConversionError)?),
+        });
+    }
+
+    // Withdraw only the liquidity needed to restore the target health score, pools in
+    // liquidation mode are unwound entirely.
+    let liquidity_to_withdraw = if is_in_liquidation_mode {
+        comet_position.committed_collateral_liquidity
+    } else {
+        let health_score = calculate_health_score(comet, pools, oracles, collateral, collaterals)?;
+        calculate_liquidation_liquidity_withdrawal(
+            calculate_health_score_deficit(&health_score, target_health_score)?,
+            &pools.pools[pool_index],
+            &comet.positions[comet_position_index as usize],
+            collateral,
+        )?
+    };
+    if liquidity_to_withdraw > 0 {
         withdraw_liquidity(
             pools,
             oracles,
             comet,
             collateral,
             comet_position_index,
-            comet_position.committed_collateral_liquidity,
+            liquidity_to_withdraw,
             user,
             ctx.accounts.clone.event_counter,
         )?;
     }
+    write_off_comet_bad_debt(
+        pools,
+        comet,
+        collateral,
+        comet_position_index,
+        user,
+        insurance_fund_paid,
+        ctx.accounts.clone.event_counter,
+    )?;
+    let ending_health_score =
+        calculate_health_score(comet, pools, oracles, collateral, collaterals)?;
+    comet.update_liquidation_auction(
+        !ending_health_score.is_healthy() || is_in_liquidation_mode,
+        slot,
+    );
+    ctx.accounts.clone.insurance_fund = ctx
+        .accounts
+        .clone
+        .insurance_fund
+        .checked_sub(insurance_fund_paid)
+        .ok_or(error!(CloneError::CheckedMathError))?;
     ctx.accounts.clone.event_counter = ctx
         .accounts
         .clone
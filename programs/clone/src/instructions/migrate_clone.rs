@@ -0,0 +1,40 @@
+use crate::error::CloneError;
+use crate::migration::*;
+use crate::states::*;
+use crate::{return_error_if_false, CLONE_PROGRAM_SEED};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct MigrateClone<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: Read in its legacy layout, the admin is checked against it.
+    #[account(
+        mut,
+        seeds = [CLONE_PROGRAM_SEED.as_ref()],
+        bump,
+    )]
+    pub clone: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn execute(ctx: Context<MigrateClone>) -> Result<()> {
+    let clone_account = ctx.accounts.clone.to_account_info();
+    return_error_if_false!(
+        clone_account.data_len() < CLONE_SPACE,
+        CloneError::AccountAlreadyMigrated
+    );
+    let legacy_clone = read_legacy_account::<Clone, LegacyClone>(&clone_account)?;
+    return_error_if_false!(
+        legacy_clone.admin == ctx.accounts.admin.key(),
+        CloneError::Unauthorized
+    );
+
+    write_migrated_account(
+        &clone_account,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        CLONE_SPACE,
+        &Clone::from(legacy_clone),
+    )
+}
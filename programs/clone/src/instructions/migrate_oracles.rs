@@ -0,0 +1,49 @@
+use crate::error::CloneError;
+use crate::migration::*;
+use crate::states::*;
+use crate::{return_error_if_false, CLONE_PROGRAM_SEED, ORACLES_SEED};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct MigrateOracles<'info> {
+    #[account(mut, address = clone.admin)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [CLONE_PROGRAM_SEED.as_ref()],
+        bump = clone.bump,
+        has_one = admin,
+    )]
+    pub clone: Box<Account<'info, Clone>>,
+    /// CHECK: Read in its legacy layout.
+    #[account(
+        mut,
+        seeds = [ORACLES_SEED.as_ref()],
+        bump,
+    )]
+    pub oracles: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// As with pools, new deployments run this once to grow Oracles to `ORACLES_SPACE`.
+pub fn execute(ctx: Context<MigrateOracles>) -> Result<()> {
+    let oracles_account = ctx.accounts.oracles.to_account_info();
+    return_error_if_false!(
+        oracles_account.data_len() < ORACLES_SPACE,
+        CloneError::AccountAlreadyMigrated
+    );
+    let legacy_oracles = read_legacy_account::<Oracles, LegacyOracles>(&oracles_account)?;
+
+    write_migrated_account(
+        &oracles_account,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ORACLES_SPACE,
+        &Oracles {
+            oracles: legacy_oracles
+                .oracles
+                .into_iter()
+                .map(OracleInfo::from)
+                .collect(),
+        },
+    )
+}
@@ -0,0 +1,46 @@
+use crate::error::CloneError;
+use crate::migration::*;
+use crate::states::*;
+use crate::{return_error_if_false, CLONE_PROGRAM_SEED, POOLS_SEED};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct MigratePools<'info> {
+    #[account(mut, address = clone.admin)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [CLONE_PROGRAM_SEED.as_ref()],
+        bump = clone.bump,
+        has_one = admin,
+    )]
+    pub clone: Box<Account<'info, Clone>>,
+    /// CHECK: Read in its legacy layout.
+    #[account(
+        mut,
+        seeds = [POOLS_SEED.as_ref()],
+        bump,
+    )]
+    pub pools: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// Pools is created at the CPI allocation limit, new deployments also run this once to
+// grow it to `POOLS_SPACE` before adding pools.
+pub fn execute(ctx: Context<MigratePools>) -> Result<()> {
+    let pools_account = ctx.accounts.pools.to_account_info();
+    return_error_if_false!(
+        pools_account.data_len() < POOLS_SPACE,
+        CloneError::AccountAlreadyMigrated
+    );
+    let legacy_pools = read_legacy_account::<Pools, LegacyPools>(&pools_account)?;
+
+    write_migrated_account(
+        &pools_account,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        POOLS_SPACE,
+        &Pools {
+            pools: legacy_pools.pools.into_iter().map(Pool::from).collect(),
+        },
+    )
+}
@@ -0,0 +1,38 @@
+use crate::error::CloneError;
+use crate::migration::*;
+use crate::states::*;
+use crate::{return_error_if_false, USER_SEED};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(authority: Pubkey)]
+pub struct MigrateUser<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: Read in its legacy layout.
+    #[account(
+        mut,
+        seeds = [USER_SEED.as_ref(), authority.as_ref()],
+        bump,
+    )]
+    pub user_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// Permissionless, the user's positions are carried over unchanged.
+pub fn execute(ctx: Context<MigrateUser>, _authority: Pubkey) -> Result<()> {
+    let user_account = ctx.accounts.user_account.to_account_info();
+    return_error_if_false!(
+        user_account.data_len() == LEGACY_ACCOUNT_SPACE,
+        CloneError::AccountAlreadyMigrated
+    );
+    let legacy_user = read_legacy_account::<User, LegacyUser>(&user_account)?;
+
+    write_migrated_account(
+        &user_account,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        USER_SPACE,
+        &User::from(legacy_user),
+    )
+}
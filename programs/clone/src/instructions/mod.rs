@@ -1,60 +1,100 @@
+pub mod add_collateral;
 pub mod add_collateral_to_borrow;
 pub mod add_collateral_to_comet;
 pub mod add_liquidity_to_comet;
 pub mod add_pool;
 pub mod borrow_more;
+pub mod close_settlement_reserve;
 pub mod close_user_account;
 pub mod collect_lp_rewards;
 pub mod create_token_metadata;
+pub mod deprecate_pool;
+pub mod flash_borrow;
+pub mod flash_repay;
 pub mod initialize_borrow_position;
 pub mod initialize_clone;
+pub mod initialize_collaterals;
 pub mod initialize_oracles;
 pub mod initialize_pools;
+pub mod initialize_referrer;
+pub mod initialize_settlement_reserve;
 pub mod initialize_user;
 pub mod liquidate_borrow_position;
 pub mod liquidate_comet_collateral_ild;
 pub mod liquidate_comet_onasset_ild;
+pub mod migrate_clone;
+pub mod migrate_oracles;
+pub mod migrate_pools;
+pub mod migrate_user;
 pub mod pay_borrow_debt;
 pub mod pay_impermanent_loss_debt;
+pub mod redeem_settled_onasset;
+pub mod redeem_settlement_reserve;
 pub mod remove_comet_position;
 pub mod remove_pool;
+pub mod settle_borrow_position;
+pub mod settle_comet_position;
+pub mod settle_pool;
 pub mod swap;
 pub mod unwrap_onasset;
 pub mod update_clone_parameters;
+pub mod update_collateral_parameters;
+pub mod update_liquidation_auctions;
 pub mod update_oracles;
 pub mod update_pool_parameters;
 pub mod update_prices;
+pub mod update_referrer;
 pub mod withdraw_collateral_from_borrow;
 pub mod withdraw_collateral_from_comet;
 pub mod withdraw_liquidity_from_comet;
 pub mod wrap_asset;
 
+pub use add_collateral::*;
 pub use add_collateral_to_borrow::*;
 pub use add_collateral_to_comet::*;
 pub use add_liquidity_to_comet::*;
 pub use add_pool::*;
 pub use borrow_more::*;
+pub use close_settlement_reserve::*;
 pub use close_user_account::*;
 pub use collect_lp_rewards::*;
 pub use create_token_metadata::*;
+pub use deprecate_pool::*;
+pub use flash_borrow::*;
+pub use flash_repay::*;
 pub use initialize_borrow_position::*;
 pub use initialize_clone::*;
+pub use initialize_collaterals::*;
 pub use initialize_oracles::*;
 pub use initialize_pools::*;
+pub use initialize_referrer::*;
+pub use initialize_settlement_reserve::*;
 pub use initialize_user::*;
 pub use liquidate_borrow_position::*;
 pub use liquidate_comet_collateral_ild::*;
 pub use liquidate_comet_onasset_ild::*;
+pub use migrate_clone::*;
+pub use migrate_oracles::*;
+pub use migrate_pools::*;
+pub use migrate_user::*;
 pub use pay_borrow_debt::*;
 pub use pay_impermanent_loss_debt::*;
+pub use redeem_settled_onasset::*;
+pub use redeem_settlement_reserve::*;
 pub use remove_comet_position::*;
 pub use remove_pool::*;
+pub use settle_borrow_position::*;
+pub use settle_comet_position::*;
+pub use settle_pool::*;
 pub use swap::*;
 pub use unwrap_onasset::*;
 pub use update_clone_parameters::*;
+pub use update_collateral_parameters::*;
+pub use update_liquidation_auctions::*;
 pub use update_oracles::*;
 pub use update_pool_parameters::*;
 pub use update_prices::*;
+pub use update_referrer::*;
 pub use withdraw_collateral_from_borrow::*;
 pub use withdraw_collateral_from_comet::*;
 pub use withdraw_liquidity_from_comet::*;
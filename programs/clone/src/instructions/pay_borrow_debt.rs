@@ -1,5 +1,6 @@
 use crate::error::*;
 use crate::events::*;
+use crate::math::*;
 use crate::states::*;
 use crate::{return_error_if_false, CLONE_PROGRAM_SEED, POOLS_SEED, USER_SEED};
 use anchor_lang::prelude::*;
@@ -24,6 +25,7 @@ pub struct PayBorrowDebt<'info> {
     )]
     pub clone: Box<Account<'info, Clone>>,
     #[account(
+        mut,
         seeds = [POOLS_SEED.as_ref()],
         bump,
         constraint = pools.pools[user_account.borrows[borrow_index as usize].pool_index as usize].status != Status::Frozen @ CloneError::StatusPreventsAction
@@ -41,9 +43,40 @@ pub struct PayBorrowDebt<'info> {
         address = pools.pools[user_account.borrows[borrow_index as usize].pool_index as usize].asset_info.onasset_mint,
     )]
     pub onasset_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = onasset_mint,
+        associated_token::authority = clone.treasury_address
+    )]
+    pub treasury_onasset_token_account: Box<Account<'info, TokenAccount>>,
     pub token_program: Program<'info, Token>,
 }
 
+// Accrues the borrow's stability fee and mints it to the treasury.
+pub fn accrue_and_mint_stability_fee<'info>(
+    pool: &mut Pool,
+    borrow: &mut Borrow,
+    onasset_mint: AccountInfo<'info>,
+    treasury_onasset_token_account: AccountInfo<'info>,
+    clone: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let stability_fee = accrue_borrow_stability_fee(pool, borrow)?;
+    if stability_fee > 0 {
+        let cpi_accounts = MintTo {
+            mint: onasset_mint,
+            to: treasury_onasset_token_account,
+            authority: clone,
+        };
+        token::mint_to(
+            CpiContext::new_with_signer(token_program, cpi_accounts, seeds),
+            stability_fee,
+        )?;
+    }
+    Ok(())
+}
+
 pub fn execute(
     ctx: Context<PayBorrowDebt>,
     user: Pubkey,
@@ -51,9 +84,25 @@ pub fn execute(
     amount: u64,
 ) -> Result<()> {
     return_error_if_false!(amount > 0, CloneError::InvalidTokenAmount);
+    let seeds = &[&[
+        CLONE_PROGRAM_SEED.as_ref(),
+        bytemuck::bytes_of(&ctx.accounts.clone.bump),
+    ][..]];
+    let pools = &mut ctx.accounts.pools;
     let borrows = &mut ctx.accounts.user_account.borrows;
+    let pool_index = borrows[borrow_index as usize].pool_index;
+    accrue_and_mint_stability_fee(
+        &mut pools.pools[pool_index as usize],
+        &mut borrows[borrow_index as usize],
+        ctx.accounts.onasset_mint.to_account_info(),
+        ctx.accounts.treasury_onasset_token_account.to_account_info(),
+        ctx.accounts.clone.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        seeds,
+    )?;
     let borrow_position = borrows[borrow_index as usize];
     let amount_value = amount.min(borrow_position.borrowed_onasset);
+    pools.pools[pool_index as usize].remove_borrowed_onasset(amount_value);
 
     // burn user onasset to pay back mint position
     let cpi_accounts = Burn {
@@ -77,10 +126,7 @@ pub fn execute(
     emit!(BorrowUpdate {
         event_id: ctx.accounts.clone.event_counter,
         user_address: user,
-        pool_index: borrows[borrow_index as usize]
-            .pool_index
-            .try_into()
-            .map_err(|_| CloneError::IntTypeConversionError)?,
+        pool_index: borrows[borrow_index as usize].pool_index,
         is_liquidation: false,
         collateral_supplied: borrows[borrow_index as usize].collateral_amount,
         collateral_delta: 0,
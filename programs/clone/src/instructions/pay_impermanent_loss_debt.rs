@@ -79,7 +79,7 @@ pub fn execute(
     let comet = &mut ctx.accounts.user_account.comet;
 
     let comet_position = comet.positions[comet_position_index as usize];
-    let ild_share = calculate_ild_share(&comet_position, &pools, &ctx.accounts.clone.collateral)?;
+    let ild_share = calculate_ild_share(&comet_position, pools, &ctx.accounts.clone.collateral)?;
 
     match payment_type {
         PaymentType::Onasset => {
@@ -182,16 +182,13 @@ pub fn execute(
 
             comet.collateral_amount = comet
                 .collateral_amount
-                .checked_sub(
-                    from_wallet_amount
-                        .try_into()
-                        .map_err(|_| CloneError::IntTypeConversionError)?,
-                )
+                .checked_sub(from_wallet_amount)
                 .ok_or(error!(CloneError::CheckedMathError))?;
 
             emit!(CometCollateralUpdate {
                 event_id: ctx.accounts.clone.event_counter,
                 user_address: user.key(),
+                collateral_index: 0,
                 collateral_supplied: comet.collateral_amount,
                 collateral_delta: -(from_wallet_amount
                     .try_into()
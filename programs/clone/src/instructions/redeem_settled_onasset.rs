@@ -0,0 +1,161 @@
+use crate::decimal::{rescale_toward_zero, CLONE_TOKEN_SCALE};
+use crate::error::*;
+use crate::states::*;
+use crate::{return_error_if_false, to_clone_decimal, CLONE_PROGRAM_SEED, POOLS_SEED};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, *};
+use rust_decimal::prelude::*;
+use std::convert::TryInto;
+
+#[derive(Accounts)]
+#[instruction(pool_index: u8, amount: u64)]
+pub struct RedeemSettledOnasset<'info> {
+    pub user: Signer<'info>,
+    #[account(
+        seeds = [CLONE_PROGRAM_SEED.as_ref()],
+        bump = clone.bump,
+    )]
+    pub clone: Box<Account<'info, Clone>>,
+    #[account(
+        mut,
+        seeds = [POOLS_SEED.as_ref()],
+        bump,
+        constraint = (pool_index as usize) < pools.pools.len() @ CloneError::PoolNotFound,
+        constraint = pools.pools[pool_index as usize].is_settling() @ CloneError::StatusPreventsAction
+    )]
+    pub pools: Box<Account<'info, Pools>>,
+    #[account(
+        mut,
+        address = pools.pools[pool_index as usize].asset_info.onasset_mint,
+    )]
+    pub onasset_mint: Box<Account<'info, Mint>>,
+    #[account(
+        address = underlying_asset_token_account.mint,
+    )]
+    pub underlying_asset_mint: Box<Account<'info, Mint>>,
+    #[account(
+        address = pools.pools[pool_index as usize].underlying_asset_token_account,
+    )]
+    pub underlying_asset_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = onasset_mint,
+        associated_token::authority = user
+    )]
+    pub user_onasset_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        address = clone.collateral.vault,
+    )]
+    pub vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = vault.mint,
+        associated_token::authority = user
+    )]
+    pub user_collateral_token_account: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+// Onasset is redeemed at the settlement price unless the reserve falls short of backing the
+// supply it owes, in which case every holder takes the same haircut. Wrapped onasset is backed
+// by the underlying through `unwrap_onasset` and onasset backed by registered collateral by
+// `redeem_settlement_reserve`, so neither counts. Redemptions open once every position in the
+// pool is settled and the reserve is complete.
+pub fn execute(ctx: Context<RedeemSettledOnasset>, pool_index: u8, amount: u64) -> Result<()> {
+    return_error_if_false!(amount > 0, CloneError::InvalidTokenAmount);
+    let seeds = &[&[
+        CLONE_PROGRAM_SEED.as_ref(),
+        bytemuck::bytes_of(&ctx.accounts.clone.bump),
+    ][..]];
+    let collateral = &ctx.accounts.clone.collateral;
+    let pool = &mut ctx.accounts.pools.pools[pool_index as usize];
+    return_error_if_false!(
+        pool.settlement.is_redeemable(Clock::get()?.unix_timestamp),
+        CloneError::RedemptionPeriodEnded
+    );
+    return_error_if_false!(
+        pool.borrowed_onasset == 0 && pool.committed_collateral_liquidity == 0,
+        CloneError::RequireAllPositionsClosed
+    );
+
+    let wrapped_supply = rescale_toward_zero(
+        Decimal::new(
+            ctx.accounts
+                .underlying_asset_token_account
+                .amount
+                .try_into()
+                .map_err(|_| CloneError::IntTypeConversionError)?,
+            ctx.accounts.underlying_asset_mint.decimals.into(),
+        ),
+        CLONE_TOKEN_SCALE,
+    );
+    let redeemable_supply = to_clone_decimal!(ctx.accounts.onasset_mint.supply)
+        .checked_sub(wrapped_supply)
+        .ok_or(error!(CloneError::CheckedMathError))?
+        .checked_sub(to_clone_decimal!(
+            pool.settlement.registered_reserve_onasset
+        ))
+        .ok_or(error!(CloneError::CheckedMathError))?;
+    return_error_if_false!(
+        redeemable_supply > Decimal::ZERO,
+        CloneError::InvalidTokenAmount
+    );
+    let reserve = collateral.to_collateral_decimal(pool.settlement.collateral_reserve)?;
+    let redemption_price = pool.settlement.get_pool_price().min(
+        reserve
+            .checked_div(redeemable_supply)
+            .ok_or(error!(CloneError::CheckedMathError))?,
+    );
+    let redeemed_collateral: u64 = rescale_toward_zero(
+        to_clone_decimal!(amount)
+            .checked_mul(redemption_price)
+            .ok_or(error!(CloneError::CheckedMathError))?,
+        collateral.scale.into(),
+    )
+    .mantissa()
+    .try_into()
+    .map_err(|_| CloneError::IntTypeConversionError)?;
+    return_error_if_false!(redeemed_collateral > 0, CloneError::InvalidTokenAmount);
+    pool.settlement.collateral_reserve = pool
+        .settlement
+        .collateral_reserve
+        .checked_sub(redeemed_collateral)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+
+    // burn onasset from user
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.onasset_mint.to_account_info().clone(),
+        from: ctx
+            .accounts
+            .user_onasset_token_account
+            .to_account_info()
+            .clone(),
+        authority: ctx.accounts.user.to_account_info().clone(),
+    };
+    token::burn(
+        CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
+        amount,
+    )?;
+
+    // send the redeemed collateral to the user
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.vault.to_account_info().clone(),
+        to: ctx
+            .accounts
+            .user_collateral_token_account
+            .to_account_info()
+            .clone(),
+        authority: ctx.accounts.clone.to_account_info().clone(),
+    };
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            seeds,
+        ),
+        redeemed_collateral,
+    )?;
+
+    Ok(())
+}
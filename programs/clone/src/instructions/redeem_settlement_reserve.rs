@@ -0,0 +1,144 @@
+use crate::error::*;
+use crate::states::*;
+use crate::{
+    return_error_if_false, CLONE_PROGRAM_SEED, COLLATERALS_SEED, POOLS_SEED,
+    SETTLEMENT_RESERVE_SEED,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, *};
+use std::convert::TryInto;
+
+#[derive(Accounts)]
+#[instruction(pool_index: u8, amount: u64)]
+pub struct RedeemSettlementReserve<'info> {
+    pub user: Signer<'info>,
+    #[account(
+        seeds = [CLONE_PROGRAM_SEED.as_ref()],
+        bump = clone.bump,
+    )]
+    pub clone: Box<Account<'info, Clone>>,
+    #[account(
+        mut,
+        seeds = [POOLS_SEED.as_ref()],
+        bump,
+        constraint = (pool_index as usize) < pools.pools.len() @ CloneError::PoolNotFound,
+        constraint = pools.pools[pool_index as usize].is_settling() @ CloneError::StatusPreventsAction
+    )]
+    pub pools: Box<Account<'info, Pools>>,
+    #[account(
+        seeds = [COLLATERALS_SEED.as_ref()],
+        bump,
+    )]
+    pub collaterals: Box<Account<'info, Collaterals>>,
+    #[account(
+        mut,
+        seeds = [
+            SETTLEMENT_RESERVE_SEED.as_ref(),
+            onasset_mint.key().as_ref(),
+            vault.mint.as_ref()
+        ],
+        bump,
+    )]
+    pub settlement_reserve: Box<Account<'info, SettlementReserve>>,
+    #[account(
+        mut,
+        address = pools.pools[pool_index as usize].asset_info.onasset_mint,
+    )]
+    pub onasset_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = onasset_mint,
+        associated_token::authority = user
+    )]
+    pub user_onasset_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        address = collaterals.get_collateral(
+            &clone.collateral,
+            settlement_reserve.collateral_index
+        )?.vault,
+    )]
+    pub vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = vault.mint,
+        associated_token::authority = user
+    )]
+    pub user_collateral_token_account: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+// Redeems onasset for its share of a registered collateral's settlement reserve, which never
+// takes a haircut from the pool's own reserve.
+pub fn execute(ctx: Context<RedeemSettlementReserve>, pool_index: u8, amount: u64) -> Result<()> {
+    let seeds = &[&[
+        CLONE_PROGRAM_SEED.as_ref(),
+        bytemuck::bytes_of(&ctx.accounts.clone.bump),
+    ][..]];
+    let pool = &mut ctx.accounts.pools.pools[pool_index as usize];
+    let settlement_reserve = &mut ctx.accounts.settlement_reserve;
+    return_error_if_false!(
+        amount > 0 && amount <= settlement_reserve.onasset_amount,
+        CloneError::InvalidTokenAmount
+    );
+    return_error_if_false!(
+        pool.settlement.is_redeemable(Clock::get()?.unix_timestamp),
+        CloneError::RedemptionPeriodEnded
+    );
+
+    let redeemed_collateral: u64 = (settlement_reserve.collateral_amount as u128)
+        .checked_mul(amount.into())
+        .and_then(|value| value.checked_div(settlement_reserve.onasset_amount.into()))
+        .ok_or(error!(CloneError::CheckedMathError))?
+        .try_into()
+        .map_err(|_| CloneError::IntTypeConversionError)?;
+    settlement_reserve.collateral_amount = settlement_reserve
+        .collateral_amount
+        .checked_sub(redeemed_collateral)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+    settlement_reserve.onasset_amount = settlement_reserve
+        .onasset_amount
+        .checked_sub(amount)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+    pool.settlement.registered_reserve_onasset = pool
+        .settlement
+        .registered_reserve_onasset
+        .checked_sub(amount)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+
+    // burn onasset from user
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.onasset_mint.to_account_info().clone(),
+        from: ctx
+            .accounts
+            .user_onasset_token_account
+            .to_account_info()
+            .clone(),
+        authority: ctx.accounts.user.to_account_info().clone(),
+    };
+    token::burn(
+        CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
+        amount,
+    )?;
+
+    // send the redeemed collateral to the user
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.vault.to_account_info().clone(),
+        to: ctx
+            .accounts
+            .user_collateral_token_account
+            .to_account_info()
+            .clone(),
+        authority: ctx.accounts.clone.to_account_info().clone(),
+    };
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            seeds,
+        ),
+        redeemed_collateral,
+    )?;
+
+    Ok(())
+}
@@ -38,6 +38,17 @@ pub struct RemovePool<'info> {
         associated_token::authority = clone.treasury_address,
     )]
     pub treasury_asset_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        address = clone.collateral.vault,
+    )]
+    pub vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = vault.mint,
+        associated_token::authority = clone.treasury_address,
+    )]
+    pub treasury_collateral_token_account: Box<Account<'info, TokenAccount>>,
     pub token_program: Program<'info, Token>,
 }
 
@@ -47,13 +58,27 @@ pub fn execute(ctx: Context<RemovePool>, pool_index: u8) -> Result<()> {
         bytemuck::bytes_of(&ctx.accounts.clone.bump),
     ][..]];
 
-    let pools = &mut ctx.accounts.pools;
-    let pool = &pools.pools[pool_index as usize];
+    let pool = &mut ctx.accounts.pools.pools[pool_index as usize];
 
     return_error_if_false!(
         pool.status == Status::Deprecation,
         CloneError::StatusPreventsAction
     );
+    // Holders keep their redemptions until the deadline and positions must be settled first.
+    return_error_if_false!(
+        pool.settlement.redemption_deadline != 0
+            && !pool.settlement.is_redeemable(Clock::get()?.unix_timestamp),
+        CloneError::RedemptionPeriodActive
+    );
+    return_error_if_false!(
+        pool.committed_collateral_liquidity == 0 && pool.borrowed_onasset == 0,
+        CloneError::RequireAllPositionsClosed
+    );
+    // Registered settlement reserves are swept through `close_settlement_reserve`.
+    return_error_if_false!(
+        pool.settlement.registered_reserve_onasset == 0,
+        CloneError::RequireAllPositionsClosed
+    );
 
     let balance = ctx.accounts.underlying_asset_token_account.amount;
 
@@ -82,7 +107,31 @@ pub fn execute(ctx: Context<RemovePool>, pool_index: u8) -> Result<()> {
         )?;
     }
 
-    pools.pools.remove(pool_index as usize);
+    let collateral_reserve = pool.settlement.collateral_reserve;
+    if collateral_reserve > 0 {
+        // transfer unredeemed collateral to treasury
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.vault.to_account_info().clone(),
+            to: ctx
+                .accounts
+                .treasury_collateral_token_account
+                .to_account_info()
+                .clone(),
+            authority: ctx.accounts.clone.to_account_info().clone(),
+        };
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info().clone(),
+                cpi_accounts,
+                seeds,
+            ),
+            collateral_reserve,
+        )?;
+    }
+
+    // The slot stays as a tombstone, positions refer to pools by index and borrows opened
+    // before `borrowed_onasset` was tracked can still settle against it.
+    pool.settlement.collateral_reserve = 0;
 
     Ok(())
 }
@@ -0,0 +1,241 @@
+use crate::decimal::{rescale_away_from_zero, rescale_toward_zero, CLONE_TOKEN_SCALE};
+use crate::error::*;
+use crate::events::*;
+use crate::math::*;
+use crate::states::*;
+use crate::{
+    return_error_if_false, to_clone_decimal, CLONE_PROGRAM_SEED, COLLATERALS_SEED, ORACLES_SEED,
+    POOLS_SEED, USER_SEED,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, *};
+use std::convert::TryInto;
+
+#[derive(Accounts)]
+#[instruction(user: Pubkey, borrow_index: u8)]
+pub struct SettleBorrowPosition<'info> {
+    #[account(
+        mut,
+        seeds = [USER_SEED.as_ref(), user.as_ref()],
+        bump,
+        constraint = (borrow_index as usize) < user_account.borrows.len() @ CloneError::InvalidInputPositionIndex
+    )]
+    pub user_account: Box<Account<'info, User>>,
+    #[account(
+        mut,
+        seeds = [CLONE_PROGRAM_SEED.as_ref()],
+        bump = clone.bump,
+    )]
+    pub clone: Box<Account<'info, Clone>>,
+    #[account(
+        mut,
+        seeds = [POOLS_SEED.as_ref()],
+        bump,
+        constraint = pools.pools[user_account.borrows[borrow_index as usize].pool_index as usize].is_settling() @ CloneError::StatusPreventsAction
+    )]
+    pub pools: Box<Account<'info, Pools>>,
+    #[account(
+        seeds = [ORACLES_SEED.as_ref()],
+        bump,
+    )]
+    pub oracles: Box<Account<'info, Oracles>>,
+    #[account(
+        seeds = [COLLATERALS_SEED.as_ref()],
+        bump,
+    )]
+    pub collaterals: Box<Account<'info, Collaterals>>,
+    #[account(mut)]
+    pub settlement_reserve: Option<Box<Account<'info, SettlementReserve>>>,
+    #[account(
+        mut,
+        address = collaterals.get_collateral(
+            &clone.collateral,
+            user_account.borrows[borrow_index as usize].collateral_index
+        )?.vault,
+    )]
+    pub vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = vault.mint,
+        associated_token::authority = user
+    )]
+    pub user_collateral_token_account: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+// Permissionless, closes the position by paying its debt at the settlement price into the
+// pool's settlement reserve and returning the remaining collateral to the user. Positions
+// backed by a registered collateral pay into that collateral's settlement reserve, converted
+// at the current collateral prices.
+pub fn execute(ctx: Context<SettleBorrowPosition>, user: Pubkey, borrow_index: u8) -> Result<()> {
+    let seeds = &[&[
+        CLONE_PROGRAM_SEED.as_ref(),
+        bytemuck::bytes_of(&ctx.accounts.clone.bump),
+    ][..]];
+    let base_collateral = &ctx.accounts.clone.collateral;
+    let oracles = &ctx.accounts.oracles;
+    let pools = &mut ctx.accounts.pools;
+    let borrows = &mut ctx.accounts.user_account.borrows;
+
+    // Fees accrued up to settlement are paid into the reserve rather than minted.
+    let pool_index = borrows[borrow_index as usize].pool_index as usize;
+    accrue_borrow_stability_fee(
+        &mut pools.pools[pool_index],
+        &mut borrows[borrow_index as usize],
+    )?;
+    let borrow_position = borrows[borrow_index as usize];
+    let collateral = ctx
+        .accounts
+        .collaterals
+        .get_collateral(base_collateral, borrow_position.collateral_index)?;
+    let pool = &mut pools.pools[pool_index];
+    let settlement_price = if borrow_position.collateral_index == 0 {
+        pool.settlement.get_pool_price()
+    } else {
+        let slot = Clock::get()?.slot;
+        let base_collateral_oracle = &oracles.oracles[base_collateral.oracle_info_index as usize];
+        let collateral_oracle = &oracles.oracles[collateral.oracle_info_index as usize];
+        check_feed_update(base_collateral_oracle, slot)?;
+        check_feed_update(collateral_oracle, slot)?;
+        pool.settlement
+            .get_pool_price()
+            .checked_mul(base_collateral.get_price(base_collateral_oracle)?)
+            .ok_or(error!(CloneError::CheckedMathError))?
+            .checked_div(collateral.get_price(collateral_oracle)?)
+            .ok_or(error!(CloneError::CheckedMathError))?
+    };
+
+    let debt_value: u64 = rescale_away_from_zero(
+        to_clone_decimal!(borrow_position.borrowed_onasset)
+            .checked_mul(settlement_price)
+            .ok_or(error!(CloneError::CheckedMathError))?,
+        collateral.scale.into(),
+    )
+    .mantissa()
+    .try_into()
+    .map_err(|_| CloneError::IntTypeConversionError)?;
+    let reserve_paid = debt_value.min(borrow_position.collateral_amount);
+    let onasset_paid = if reserve_paid == debt_value {
+        borrow_position.borrowed_onasset
+    } else {
+        rescale_toward_zero(
+            collateral
+                .to_collateral_decimal(reserve_paid)?
+                .checked_div(settlement_price)
+                .ok_or(error!(CloneError::CheckedMathError))?,
+            CLONE_TOKEN_SCALE,
+        )
+        .mantissa()
+        .try_into()
+        .map_err(|_| CloneError::IntTypeConversionError)?
+    };
+    let onasset_written_off = borrow_position
+        .borrowed_onasset
+        .saturating_sub(onasset_paid);
+    let collateral_returned = borrow_position
+        .collateral_amount
+        .checked_sub(reserve_paid)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+
+    pool.remove_borrowed_onasset(borrow_position.borrowed_onasset);
+    if borrow_position.collateral_index == 0 {
+        pool.settlement.collateral_reserve = pool
+            .settlement
+            .collateral_reserve
+            .checked_add(reserve_paid)
+            .ok_or(error!(CloneError::CheckedMathError))?;
+    } else {
+        let settlement_reserve = ctx
+            .accounts
+            .settlement_reserve
+            .as_mut()
+            .ok_or(error!(CloneError::SettlementUnavailable))?;
+        return_error_if_false!(
+            settlement_reserve.onasset_mint == pool.asset_info.onasset_mint
+                && settlement_reserve.collateral_index == borrow_position.collateral_index,
+            CloneError::SettlementUnavailable
+        );
+        settlement_reserve.collateral_amount = settlement_reserve
+            .collateral_amount
+            .checked_add(reserve_paid)
+            .ok_or(error!(CloneError::CheckedMathError))?;
+        settlement_reserve.onasset_amount = settlement_reserve
+            .onasset_amount
+            .checked_add(onasset_paid)
+            .ok_or(error!(CloneError::CheckedMathError))?;
+        pool.settlement.registered_reserve_onasset = pool
+            .settlement
+            .registered_reserve_onasset
+            .checked_add(onasset_paid)
+            .ok_or(error!(CloneError::CheckedMathError))?;
+    }
+
+    // Debt the collateral can't cover is socialised across the pool's LPs as onasset ILD.
+    if onasset_written_off > 0 {
+        let is_socialized = pool.socialize_ild(
+            onasset_written_off
+                .try_into()
+                .map_err(|_| CloneError::IntTypeConversionError)?,
+            0,
+        )?;
+        emit!(BadDebtWriteOff {
+            event_id: ctx.accounts.clone.event_counter,
+            user_address: user,
+            pool_index: pool_index
+                .try_into()
+                .map_err(|_| CloneError::IntTypeConversionError)?,
+            is_borrow: true,
+            insurance_fund_paid: 0,
+            onasset_written_off,
+            collateral_written_off: 0,
+            is_socialized,
+        });
+    }
+
+    // Return the remaining collateral to the user
+    if collateral_returned > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.vault.to_account_info().clone(),
+            to: ctx
+                .accounts
+                .user_collateral_token_account
+                .to_account_info()
+                .clone(),
+            authority: ctx.accounts.clone.to_account_info().clone(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        token::transfer(
+            CpiContext::new_with_signer(cpi_program, cpi_accounts, seeds),
+            collateral_returned,
+        )?;
+    }
+
+    emit!(BorrowUpdate {
+        event_id: ctx.accounts.clone.event_counter,
+        user_address: user,
+        pool_index: pool_index
+            .try_into()
+            .map_err(|_| CloneError::IntTypeConversionError)?,
+        is_liquidation: false,
+        collateral_supplied: 0,
+        collateral_delta: -(borrow_position
+            .collateral_amount
+            .try_into()
+            .map_err(|_| CloneError::IntTypeConversionError)?),
+        borrowed_amount: 0,
+        borrowed_delta: -(borrow_position
+            .borrowed_onasset
+            .try_into()
+            .map_err(|_| CloneError::IntTypeConversionError)?)
+    });
+    ctx.accounts.clone.event_counter = ctx
+        .accounts
+        .clone
+        .event_counter
+        .checked_add(1)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+
+    borrows.remove(borrow_index as usize);
+
+    Ok(())
+}
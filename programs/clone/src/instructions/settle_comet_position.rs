@@ -0,0 +1,310 @@
+use crate::decimal::{rescale_away_from_zero, rescale_toward_zero, CLONE_TOKEN_SCALE};
+use crate::error::*;
+use crate::events::*;
+use crate::instructions::{seize_comet_collateral_deposit, withdraw_liquidity};
+use crate::math::*;
+use crate::states::*;
+use crate::{
+    return_error_if_false, CLONE_PROGRAM_SEED, COLLATERALS_SEED, ORACLES_SEED, POOLS_SEED,
+    USER_SEED,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::*;
+use rust_decimal::prelude::*;
+use std::convert::TryInto;
+
+#[derive(Accounts)]
+#[instruction(user: Pubkey, comet_position_index: u8)]
+pub struct SettleCometPosition<'info> {
+    #[account(
+        mut,
+        seeds = [USER_SEED.as_ref(), user.as_ref()],
+        bump,
+        constraint = (comet_position_index as usize) < user_account.comet.positions.len() @ CloneError::InvalidInputPositionIndex
+    )]
+    pub user_account: Box<Account<'info, User>>,
+    #[account(
+        mut,
+        seeds = [CLONE_PROGRAM_SEED.as_ref()],
+        bump = clone.bump,
+    )]
+    pub clone: Box<Account<'info, Clone>>,
+    #[account(
+        mut,
+        seeds = [POOLS_SEED.as_ref()],
+        bump,
+        constraint = pools.pools[user_account.comet.positions[comet_position_index as usize].pool_index as usize].is_settling() @ CloneError::StatusPreventsAction
+    )]
+    pub pools: Box<Account<'info, Pools>>,
+    #[account(
+        seeds = [ORACLES_SEED.as_ref()],
+        bump,
+    )]
+    pub oracles: Box<Account<'info, Oracles>>,
+    #[account(
+        mut,
+        seeds = [COLLATERALS_SEED.as_ref()],
+        bump,
+    )]
+    pub collaterals: Box<Account<'info, Collaterals>>,
+    pub deposit_vault: Option<Box<Account<'info, TokenAccount>>>,
+    #[account(mut)]
+    pub settlement_reserve: Option<Box<Account<'info, SettlementReserve>>>,
+}
+
+// Permissionless, withdraws the position's liquidity and pays its ILD debts from the comet's
+// collateral at the settlement price. Onasset debt the base collateral can't cover is paid from
+// the registered deposit in `deposit_vault` into that collateral's settlement reserve, and
+// nothing is written off while the comet still holds deposits, so keepers settle again with
+// each of them. Rewards stay claimable through `collect_lp_rewards`.
+pub fn execute(
+    ctx: Context<SettleCometPosition>,
+    user: Pubkey,
+    comet_position_index: u8,
+) -> Result<()> {
+    let collateral = &ctx.accounts.clone.collateral;
+    let pools = &mut ctx.accounts.pools;
+    let oracles = &ctx.accounts.oracles;
+    let collaterals = &mut ctx.accounts.collaterals;
+    let comet = &mut ctx.accounts.user_account.comet;
+    let comet_position = comet.positions[comet_position_index as usize];
+    let pool_index = comet_position.pool_index as usize;
+    let collateral_scale: u32 = collateral.scale.into();
+
+    if comet_position.committed_collateral_liquidity > 0 {
+        withdraw_liquidity(
+            pools,
+            oracles,
+            comet,
+            collateral,
+            comet_position_index,
+            comet_position.committed_collateral_liquidity,
+            user,
+            ctx.accounts.clone.event_counter,
+        )?;
+    }
+
+    let ild_share = calculate_ild_share(
+        &comet.positions[comet_position_index as usize],
+        pools,
+        collateral,
+    )?;
+    let onasset_debt = ild_share.onasset_ild_share.max(Decimal::ZERO);
+    let collateral_debt: u64 = ild_share
+        .collateral_ild_share
+        .max(Decimal::ZERO)
+        .mantissa()
+        .try_into()
+        .map_err(|_| CloneError::IntTypeConversionError)?;
+    let settlement_price = pools.pools[pool_index].settlement.get_pool_price();
+
+    // The onasset debt goes to the reserve backing redemptions and is paid first, the
+    // collateral debt stays in the vault as it would for an ILD payment.
+    let onasset_debt_value: u64 = rescale_away_from_zero(
+        onasset_debt
+            .checked_mul(settlement_price)
+            .ok_or(error!(CloneError::CheckedMathError))?,
+        collateral_scale,
+    )
+    .mantissa()
+    .try_into()
+    .map_err(|_| CloneError::IntTypeConversionError)?;
+    let reserve_paid = onasset_debt_value.min(comet.collateral_amount);
+    let collateral_paid = collateral_debt.min(
+        comet
+            .collateral_amount
+            .checked_sub(reserve_paid)
+            .ok_or(error!(CloneError::CheckedMathError))?,
+    );
+    let onasset_reserve_paid = if reserve_paid == onasset_debt_value {
+        onasset_debt
+    } else {
+        rescale_toward_zero(
+            collateral
+                .to_collateral_decimal(reserve_paid)?
+                .checked_div(settlement_price)
+                .ok_or(error!(CloneError::CheckedMathError))?,
+            CLONE_TOKEN_SCALE,
+        )
+    };
+
+    let onasset_shortfall_value = onasset_debt_value
+        .checked_sub(reserve_paid)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+    let onasset_deposit_paid = match (
+        &ctx.accounts.deposit_vault,
+        &mut ctx.accounts.settlement_reserve,
+    ) {
+        (Some(deposit_vault), Some(settlement_reserve)) if onasset_shortfall_value > 0 => {
+            let (deposit_collateral_index, deposit_seized, deposit_paid) =
+                seize_comet_collateral_deposit(
+                    comet,
+                    oracles,
+                    collateral,
+                    collaterals,
+                    &deposit_vault.key(),
+                    onasset_shortfall_value,
+                )?;
+            let slot = Clock::get()?.slot;
+            let deposit_collateral =
+                collaterals.get_collateral(collateral, deposit_collateral_index)?;
+            check_feed_update(
+                &oracles.oracles[collateral.oracle_info_index as usize],
+                slot,
+            )?;
+            check_feed_update(
+                &oracles.oracles[deposit_collateral.oracle_info_index as usize],
+                slot,
+            )?;
+            return_error_if_false!(
+                settlement_reserve.onasset_mint == pools.pools[pool_index].asset_info.onasset_mint
+                    && settlement_reserve.collateral_index == deposit_collateral_index,
+                CloneError::SettlementUnavailable
+            );
+
+            let onasset_deposit_paid = if deposit_paid == onasset_shortfall_value {
+                onasset_debt
+                    .checked_sub(onasset_reserve_paid)
+                    .ok_or(error!(CloneError::CheckedMathError))?
+            } else {
+                rescale_toward_zero(
+                    collateral
+                        .to_collateral_decimal(deposit_paid)?
+                        .checked_div(settlement_price)
+                        .ok_or(error!(CloneError::CheckedMathError))?,
+                    CLONE_TOKEN_SCALE,
+                )
+            };
+            let onasset_deposit_paid_amount: u64 = onasset_deposit_paid
+                .mantissa()
+                .try_into()
+                .map_err(|_| CloneError::IntTypeConversionError)?;
+            settlement_reserve.collateral_amount = settlement_reserve
+                .collateral_amount
+                .checked_add(deposit_seized)
+                .ok_or(error!(CloneError::CheckedMathError))?;
+            settlement_reserve.onasset_amount = settlement_reserve
+                .onasset_amount
+                .checked_add(onasset_deposit_paid_amount)
+                .ok_or(error!(CloneError::CheckedMathError))?;
+            let pool = &mut pools.pools[pool_index];
+            pool.settlement.registered_reserve_onasset = pool
+                .settlement
+                .registered_reserve_onasset
+                .checked_add(onasset_deposit_paid_amount)
+                .ok_or(error!(CloneError::CheckedMathError))?;
+
+            emit!(CometCollateralUpdate {
+                event_id: ctx.accounts.clone.event_counter,
+                user_address: user,
+                collateral_index: deposit_collateral_index,
+                collateral_supplied: comet.get_collateral_amount(deposit_collateral_index),
+                collateral_delta: -(deposit_seized
+                    .try_into()
+                    .map_err(|_| CloneError::IntTypeConversionError)?),
+            });
+            onasset_deposit_paid
+        }
+        _ => Decimal::ZERO,
+    };
+    let onasset_paid = onasset_reserve_paid
+        .checked_add(onasset_deposit_paid)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+
+    let onasset_debt: i64 = onasset_debt
+        .mantissa()
+        .try_into()
+        .map_err(|_| CloneError::IntTypeConversionError)?;
+    let onasset_paid: i64 = onasset_paid
+        .mantissa()
+        .try_into()
+        .map_err(|_| CloneError::IntTypeConversionError)?;
+    let collateral_paid_amount: i64 = collateral_paid
+        .try_into()
+        .map_err(|_| CloneError::IntTypeConversionError)?;
+    let collateral_debt: i64 = collateral_debt
+        .try_into()
+        .map_err(|_| CloneError::IntTypeConversionError)?;
+    let (onasset_written_off, collateral_written_off) =
+        if onasset_paid < onasset_debt && !comet.collateral_deposits.is_empty() {
+            (0, 0)
+        } else {
+            (
+                onasset_debt
+                    .checked_sub(onasset_paid)
+                    .ok_or(error!(CloneError::CheckedMathError))?,
+                collateral_debt
+                    .checked_sub(collateral_paid_amount)
+                    .ok_or(error!(CloneError::CheckedMathError))?,
+            )
+        };
+
+    let total_paid = reserve_paid
+        .checked_add(collateral_paid)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+    comet.collateral_amount = comet
+        .collateral_amount
+        .checked_sub(total_paid)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+    let position = &mut comet.positions[comet_position_index as usize];
+    position.onasset_ild_rebate = position
+        .onasset_ild_rebate
+        .checked_add(onasset_paid)
+        .and_then(|rebate| rebate.checked_add(onasset_written_off))
+        .ok_or(error!(CloneError::CheckedMathError))?;
+    position.collateral_ild_rebate = position
+        .collateral_ild_rebate
+        .checked_add(collateral_paid_amount)
+        .and_then(|rebate| rebate.checked_add(collateral_written_off))
+        .ok_or(error!(CloneError::CheckedMathError))?;
+
+    let pool = &mut pools.pools[pool_index];
+    pool.settlement.collateral_reserve = pool
+        .settlement
+        .collateral_reserve
+        .checked_add(reserve_paid)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+
+    if total_paid > 0 {
+        emit!(CometCollateralUpdate {
+            event_id: ctx.accounts.clone.event_counter,
+            user_address: user,
+            collateral_index: 0,
+            collateral_supplied: comet.collateral_amount,
+            collateral_delta: -(total_paid
+                .try_into()
+                .map_err(|_| CloneError::IntTypeConversionError)?),
+        });
+    }
+
+    // Unpaid debts fall on the remaining LPs, once none are left unpaid onasset debt dilutes
+    // the redemptions instead.
+    if onasset_written_off > 0 || collateral_written_off > 0 {
+        let is_socialized = pool.socialize_ild(onasset_written_off, collateral_written_off)?;
+        emit!(BadDebtWriteOff {
+            event_id: ctx.accounts.clone.event_counter,
+            user_address: user,
+            pool_index: pool_index
+                .try_into()
+                .map_err(|_| CloneError::IntTypeConversionError)?,
+            is_borrow: false,
+            insurance_fund_paid: 0,
+            onasset_written_off: onasset_written_off
+                .try_into()
+                .map_err(|_| CloneError::IntTypeConversionError)?,
+            collateral_written_off: collateral_written_off
+                .try_into()
+                .map_err(|_| CloneError::IntTypeConversionError)?,
+            is_socialized,
+        });
+    }
+
+    ctx.accounts.clone.event_counter = ctx
+        .accounts
+        .clone
+        .event_counter
+        .checked_add(1)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+
+    Ok(())
+}
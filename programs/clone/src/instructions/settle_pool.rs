@@ -0,0 +1,46 @@
+use crate::error::*;
+use crate::math::*;
+use crate::return_error_if_false;
+use crate::states::*;
+use crate::{CLONE_PROGRAM_SEED, ORACLES_SEED, POOLS_SEED};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(pool_index: u8)]
+pub struct SettlePool<'info> {
+    #[account(address = clone.admin)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [CLONE_PROGRAM_SEED.as_ref()],
+        bump = clone.bump,
+    )]
+    pub clone: Box<Account<'info, Clone>>,
+    #[account(
+        mut,
+        seeds = [POOLS_SEED.as_ref()],
+        bump,
+        constraint = (pool_index as usize) < pools.pools.len() @ CloneError::PoolNotFound,
+    )]
+    pub pools: Box<Account<'info, Pools>>,
+    #[account(
+        seeds = [ORACLES_SEED.as_ref()],
+        bump,
+    )]
+    pub oracles: Box<Account<'info, Oracles>>,
+}
+
+// Emergency settlement, winding the whole protocol down means settling every pool. Positions
+// are then settled at the snapshot price and onasset holders redeem from the collected reserve.
+pub fn execute(ctx: Context<SettlePool>, pool_index: u8) -> Result<()> {
+    let collateral = &ctx.accounts.clone.collateral;
+    let oracles = &ctx.accounts.oracles;
+    let pool = &mut ctx.accounts.pools.pools[pool_index as usize];
+    return_error_if_false!(!pool.is_settling(), CloneError::StatusPreventsAction);
+
+    let pool_oracle = &oracles.oracles[pool.asset_info.oracle_info_index as usize];
+    let collateral_oracle = &oracles.oracles[collateral.oracle_info_index as usize];
+    snapshot_settlement_price(pool, pool_oracle, collateral_oracle)?;
+    pool.status = Status::Extraction;
+
+    Ok(())
+}
@@ -17,13 +17,21 @@ use clone_staking::{
 use rust_decimal::prelude::*;
 use std::convert::TryInto;
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum SwapExpiry {
+    Slot { value: u64 },
+    UnixTimestamp { value: i64 },
+}
+
 #[derive(Accounts)]
 #[instruction(
     pool_index: u8,
     quantity: u64,
     quantity_is_input: bool,
     quantity_is_collateral: bool,
-    result_threshold: u64
+    result_threshold: u64,
+    expiry: Option<SwapExpiry>,
+    max_oracle_age_slots: Option<u64>
 )]
 pub struct Swap<'info> {
     pub user: Signer<'info>,
@@ -92,25 +100,66 @@ pub struct Swap<'info> {
         seeds::program = clone_staking_program.clone().ok_or(error!(CloneError::ExpectedAccountNotFound))?.key(),
     )]
     pub clone_staking: Option<Account<'info, CloneStaking>>,
+    /// CHECK: Deserialized in the handler, swappers who never staked pay the pool fees.
     #[account(
         seeds = [USER_STAKING_SEED.as_ref(), user.key.as_ref()],
         bump,
         seeds::program = clone_staking_program.clone().ok_or(error!(CloneError::ExpectedAccountNotFound))?.key(),
     )]
-    pub user_staking_account: Option<Account<'info, UserStaking>>,
+    pub user_staking_account: Option<UncheckedAccount<'info>>,
     pub clone_staking_program: Option<Program<'info, CloneStakingProgram>>,
 }
 
-pub fn execute(
-    ctx: Context<Swap>,
+type ReferralAccounts<'info> = (Account<'info, Referrer>, Account<'info, TokenAccount>);
+
+// Referral accounts trail the oracle accounts in `remaining_accounts`, so swaps without a
+// referrer keep their account layout: [..oracles, referrer, referrer_token_account].
+fn split_referral_accounts<'a, 'info>(
+    program_id: &Pubkey,
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<(&'a [AccountInfo<'info>], Option<ReferralAccounts<'info>>)> {
+    let oracle_accounts_len = remaining_accounts.len().saturating_sub(2);
+    if remaining_accounts.len() < 2 || remaining_accounts[oracle_accounts_len].owner != program_id {
+        return Ok((remaining_accounts, None));
+    }
+    let (oracle_accounts, referral_accounts) = remaining_accounts.split_at(oracle_accounts_len);
+    return_error_if_false!(
+        referral_accounts[0].is_writable && referral_accounts[1].is_writable,
+        CloneError::InvalidReferrerAccount
+    );
+    Ok((
+        oracle_accounts,
+        Some((
+            Account::try_from(&referral_accounts[0])?,
+            Account::try_from(&referral_accounts[1])?,
+        )),
+    ))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn execute<'info>(
+    ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
     pool_index: u8,
     quantity: u64,
     quantity_is_input: bool,
     quantity_is_collateral: bool,
     result_threshold: u64,
+    expiry: Option<SwapExpiry>,
+    max_oracle_age_slots: Option<u64>,
 ) -> Result<()> {
     return_error_if_false!(quantity > 0, CloneError::InvalidTokenAmount);
 
+    let clock = Clock::get()?;
+    if let Some(expiry) = expiry {
+        return_error_if_false!(
+            match expiry {
+                SwapExpiry::Slot { value } => clock.slot <= value,
+                SwapExpiry::UnixTimestamp { value } => clock.unix_timestamp <= value,
+            },
+            CloneError::SwapExpired
+        );
+    }
+
     let seeds = &[&[
         CLONE_PROGRAM_SEED.as_ref(),
         bytemuck::bytes_of(&ctx.accounts.clone.bump),
@@ -136,31 +185,43 @@ pub fn execute(
             .clone_staking
             .as_ref()
             .ok_or(error!(CloneError::ExpectedAccountNotFound))?;
-        if let Some((lp_fees, treasury_fees)) =
-            clone_staking.get_tier_fees(user_staking_account.staked_tokens)
-        {
-            override_liquidity_trading_fee = Some(to_bps_decimal!(lp_fees));
-            override_treasury_trading_fee = Some(to_bps_decimal!(treasury_fees));
+        // Routers pass the swapper's staking address whether or not it was ever created.
+        if !user_staking_account.data_is_empty() {
+            let user_staking_account: Account<UserStaking> =
+                Account::try_from(user_staking_account)?;
+            if let Some((lp_fees, treasury_fees)) =
+                clone_staking.get_tier_fees(user_staking_account.staked_tokens)
+            {
+                override_liquidity_trading_fee = Some(to_bps_decimal!(lp_fees));
+                override_treasury_trading_fee = Some(to_bps_decimal!(treasury_fees));
+            }
         }
     }
 
-    if ctx.remaining_accounts.len() >= 2 {
+    let (oracle_accounts, mut referral) =
+        split_referral_accounts(ctx.program_id, ctx.remaining_accounts)?;
+    if oracle_accounts.len() >= 2 {
         let oracle_indices = vec![
             collateral.oracle_info_index,
             pool.asset_info.oracle_info_index,
         ];
-        update_prices::update_oracles(
-            &mut ctx.accounts.oracles,
-            oracle_indices,
-            &ctx.remaining_accounts,
-        )?;
+        update_prices::update_oracles(&mut ctx.accounts.oracles, oracle_indices, oracle_accounts)?;
     }
 
     let pool_oracle = &ctx.accounts.oracles.oracles[pool.asset_info.oracle_info_index as usize];
     let collateral_oracle = &ctx.accounts.oracles.oracles[collateral.oracle_info_index as usize];
 
-    check_feed_update(&pool_oracle, Clock::get()?.slot)?;
-    check_feed_update(&collateral_oracle, Clock::get()?.slot)?;
+    check_feed_update(pool_oracle, clock.slot)?;
+    check_feed_update(collateral_oracle, clock.slot)?;
+
+    if let Some(max_oracle_age_slots) = max_oracle_age_slots {
+        for oracle in [pool_oracle, collateral_oracle].iter() {
+            return_error_if_false!(
+                clock.slot.saturating_sub(oracle.last_publish_slot) <= max_oracle_age_slots,
+                CloneError::OracleAgeExceeded
+            );
+        }
+    }
 
     return_error_if_false!(
         pool.committed_collateral_liquidity > 0,
@@ -201,12 +262,58 @@ pub fn execute(
         .mantissa()
         .try_into()
         .map_err(|_| CloneError::IntTypeConversionError)?;
-    let mut input_is_collateral = false;
+    let input_is_collateral = (quantity_is_input && quantity_is_collateral)
+        || (!quantity_is_input && !quantity_is_collateral);
 
-    let (onasset_ild_delta, collateral_ild_delta) = if (quantity_is_input && quantity_is_collateral)
-        || (!quantity_is_input && !quantity_is_collateral)
-    {
-        input_is_collateral = true;
+    // Referrers receive a share of the treasury fees, paid in the output token.
+    let referral_fees: u64 = match referral.as_ref() {
+        Some((referrer, referrer_token_account)) => {
+            let (output_mint, output_scale) = if input_is_collateral {
+                (ctx.accounts.onasset_mint.key(), CLONE_TOKEN_SCALE)
+            } else {
+                (ctx.accounts.collateral_mint.key(), collateral.scale.into())
+            };
+            return_error_if_false!(
+                referrer_token_account.owner == referrer.authority
+                    && referrer_token_account.mint == output_mint,
+                CloneError::InvalidReferrerAccount
+            );
+            rescale_toward_zero(
+                swap_summary
+                    .treasury_fees_paid
+                    .checked_mul(to_bps_decimal!(referrer.fee_share_bps))
+                    .ok_or(error!(CloneError::CheckedMathError))?,
+                output_scale,
+            )
+            .mantissa()
+            .try_into()
+            .map_err(|_| CloneError::IntTypeConversionError)?
+        }
+        None => 0,
+    };
+    let net_treasury_fees = treasury_fees
+        .checked_sub(referral_fees)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+    // A share of the treasury fees paid in collateral stays in the vault for the insurance fund.
+    let insurance_fund_fees: u64 = if input_is_collateral {
+        0
+    } else {
+        rescale_toward_zero(
+            collateral
+                .to_collateral_decimal(net_treasury_fees)?
+                .checked_mul(to_bps_decimal!(ctx.accounts.clone.insurance_fund_fee_bps))
+                .ok_or(error!(CloneError::CheckedMathError))?,
+            collateral.scale.into(),
+        )
+        .mantissa()
+        .try_into()
+        .map_err(|_| CloneError::IntTypeConversionError)?
+    };
+    let net_treasury_fees = net_treasury_fees
+        .checked_sub(insurance_fund_fees)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+
+    let (onasset_ild_delta, collateral_ild_delta) = if input_is_collateral {
         // User transfers collateral to vault, mint onasset to user, mint onasset as fees
         let (transfer_amount, mint_amount) = if quantity_is_input {
             (quantity, result_amount)
@@ -258,8 +365,24 @@ pub fn execute(
                 },
                 seeds,
             ),
-            treasury_fees,
+            net_treasury_fees,
         )?;
+        if let Some((_, referrer_token_account)) = referral.as_ref() {
+            if referral_fees > 0 {
+                token::mint_to(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info().clone(),
+                        MintTo {
+                            mint: ctx.accounts.onasset_mint.to_account_info().clone(),
+                            to: referrer_token_account.to_account_info().clone(),
+                            authority: ctx.accounts.clone.to_account_info().clone(),
+                        },
+                        seeds,
+                    ),
+                    referral_fees,
+                )?;
+            }
+        }
 
         (
             (mint_amount
@@ -322,8 +445,24 @@ pub fn execute(
                 },
                 seeds,
             ),
-            treasury_fees,
+            net_treasury_fees,
         )?;
+        if let Some((_, referrer_token_account)) = referral.as_ref() {
+            if referral_fees > 0 {
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info().clone(),
+                        Transfer {
+                            from: ctx.accounts.collateral_vault.to_account_info().clone(),
+                            to: referrer_token_account.to_account_info().clone(),
+                            authority: ctx.accounts.clone.to_account_info().clone(),
+                        },
+                        seeds,
+                    ),
+                    referral_fees,
+                )?;
+            }
+        }
         (
             -(TryInto::<i64>::try_into(burn_amount)
                 .map_err(|_| CloneError::IntTypeConversionError)?),
@@ -343,6 +482,13 @@ pub fn execute(
         .collateral_ild
         .checked_add(collateral_ild_delta)
         .ok_or(error!(CloneError::CheckedMathError))?;
+    // Collateral flowing into the pool counts as positive net volume.
+    pools.pools[pool_index as usize].swap_volume.record(
+        collateral_ild_delta
+            .checked_neg()
+            .ok_or(error!(CloneError::CheckedMathError))?,
+        clock.slot,
+    )?;
 
     let (input, output) = if quantity_is_input {
         return_error_if_false!(
@@ -358,6 +504,15 @@ pub fn execute(
         (result_amount, quantity)
     };
 
+    if let Some((referrer, _)) = referral.as_mut() {
+        let collateral_volume = if input_is_collateral { input } else { output };
+        referrer.referred_volume = referrer
+            .referred_volume
+            .checked_add(collateral_volume)
+            .ok_or(error!(CloneError::CheckedMathError))?;
+        referrer.exit(ctx.program_id)?;
+    }
+
     emit!(SwapEvent {
         event_id: ctx.accounts.clone.event_counter,
         user_address: ctx.accounts.user.key(),
@@ -370,7 +525,8 @@ pub fn execute(
             .mantissa()
             .try_into()
             .map_err(|_| CloneError::IntTypeConversionError)?,
-        treasury_fee: treasury_fees
+        treasury_fee: net_treasury_fees,
+        referral_fee: referral_fees
     });
 
     let pool = &pools.pools[pool_index as usize];
@@ -394,6 +550,12 @@ pub fn execute(
             .map_err(|_| CloneError::IntTypeConversionError)?,
         pool_scale: pool_price.scale()
     });
+    ctx.accounts.clone.insurance_fund = ctx
+        .accounts
+        .clone
+        .insurance_fund
+        .checked_add(insurance_fund_fees)
+        .ok_or(error!(CloneError::CheckedMathError))?;
     ctx.accounts.clone.event_counter = ctx
         .accounts
         .clone
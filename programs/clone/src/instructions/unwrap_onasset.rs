@@ -54,17 +54,18 @@ pub struct UnwrapOnAsset<'info> {
 pub fn execute(ctx: Context<UnwrapOnAsset>, amount: u64, pool_index: u8) -> Result<()> {
     let pool = &ctx.accounts.pools.pools[pool_index as usize];
     return_error_if_false!(
-        pool.status == Status::Active || pool.status == Status::Deprecation,
+        pool.status == Status::Active
+            || pool.status == Status::Deprecation
+            || pool.status == Status::Extraction,
         CloneError::StatusPreventsAction
     );
+    return_error_if_false!(
+        pool.settlement.is_redeemable(Clock::get()?.unix_timestamp),
+        CloneError::RedemptionPeriodEnded
+    );
     return_error_if_false!(amount > 0, CloneError::InvalidTokenAmount);
 
-    let underlying_mint_scale = ctx
-        .accounts
-        .asset_mint
-        .decimals
-        .try_into()
-        .map_err(|_| CloneError::IntTypeConversionError)?;
+    let underlying_mint_scale = ctx.accounts.asset_mint.decimals.into();
     let unwrapped_amount = rescale_toward_zero(to_clone_decimal!(amount), underlying_mint_scale)
         .mantissa()
         .try_into()
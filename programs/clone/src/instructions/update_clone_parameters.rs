@@ -13,6 +13,16 @@ pub enum CloneParameters {
     TreasuryAddress { address: Pubkey },
     CollateralizationRatio { value: u8 },
     NonAuthLiquidationsEnabled { value: bool },
+    FlashLoanFee { value: u16 },
+    BorrowLiquidationMaxFee { value: u16 },
+    BorrowLiquidationCloseFactor { value: u16 },
+    BorrowLiquidationProtocolFee { value: u16 },
+    CometCollateralLiquidationMaxFee { value: u16 },
+    CometOnassetLiquidationMaxFee { value: u16 },
+    LiquidationAuctionSlots { value: u64 },
+    InsuranceFundFee { value: u16 },
+    CometLiquidationTargetHealthScore { value: u8 },
+    AuthLiquidationGraceSlots { value: u64 },
 }
 
 #[derive(Accounts)]
@@ -85,6 +95,44 @@ pub fn execute(ctx: Context<UpdateCloneParameters>, params: CloneParameters) ->
         CloneParameters::NonAuthLiquidationsEnabled { value } => {
             clone.non_auth_liquidations_enabled = value;
         }
+        CloneParameters::FlashLoanFee { value } => {
+            return_error_if_false!(value < 10000, CloneError::InvalidValueRange);
+            clone.flash_loan_fee_bps = value;
+        }
+        CloneParameters::BorrowLiquidationMaxFee { value } => {
+            return_error_if_false!(value < 10000, CloneError::InvalidValueRange);
+            clone.borrow_liquidator_max_fee_bps = value;
+        }
+        CloneParameters::BorrowLiquidationCloseFactor { value } => {
+            return_error_if_false!(value <= 10000, CloneError::InvalidValueRange);
+            clone.borrow_liquidation_close_factor_bps = value;
+        }
+        CloneParameters::BorrowLiquidationProtocolFee { value } => {
+            return_error_if_false!(value <= 10000, CloneError::InvalidValueRange);
+            clone.borrow_liquidation_protocol_fee_bps = value;
+        }
+        CloneParameters::CometCollateralLiquidationMaxFee { value } => {
+            return_error_if_false!(value < 10000, CloneError::InvalidValueRange);
+            clone.comet_collateral_ild_liquidator_max_fee_bps = value;
+        }
+        CloneParameters::CometOnassetLiquidationMaxFee { value } => {
+            return_error_if_false!(value < 10000, CloneError::InvalidValueRange);
+            clone.comet_onasset_ild_liquidator_max_fee_bps = value;
+        }
+        CloneParameters::LiquidationAuctionSlots { value } => {
+            clone.liquidation_auction_slots = value;
+        }
+        CloneParameters::InsuranceFundFee { value } => {
+            return_error_if_false!(value <= 10000, CloneError::InvalidValueRange);
+            clone.insurance_fund_fee_bps = value;
+        }
+        CloneParameters::CometLiquidationTargetHealthScore { value } => {
+            return_error_if_false!(value < 100, CloneError::InvalidValueRange);
+            clone.comet_liquidation_target_health_score = value;
+        }
+        CloneParameters::AuthLiquidationGraceSlots { value } => {
+            clone.auth_liquidation_grace_slots = value;
+        }
     }
 
     Ok(())
@@ -0,0 +1,76 @@
+use crate::{error::CloneError, states::*};
+use crate::{return_error_if_false, CLONE_PROGRAM_SEED, COLLATERALS_SEED};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Copy, Eq, Debug)]
+pub enum CollateralParameters {
+    OracleInfoIndex { value: u8 },
+    CollateralizationRatio { value: u8 },
+    DepositCap { value: u64 },
+    MinOvercollateralRatio { value: u16 },
+    MaxLiquidationOvercollateralRatio { value: u16 },
+}
+
+#[derive(Accounts)]
+#[instruction(
+    index: u8,
+    params: CollateralParameters
+)]
+pub struct UpdateCollateralParameters<'info> {
+    #[account(address = clone.admin)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CLONE_PROGRAM_SEED.as_ref()],
+        bump = clone.bump,
+    )]
+    pub clone: Box<Account<'info, Clone>>,
+    #[account(
+        mut,
+        seeds = [COLLATERALS_SEED.as_ref()],
+        bump,
+        constraint = (index as usize) <= collaterals.collaterals.len() @ CloneError::InvalidCollateralIndex,
+    )]
+    pub collaterals: Box<Account<'info, Collaterals>>,
+}
+
+pub fn execute(
+    ctx: Context<UpdateCollateralParameters>,
+    index: u8,
+    params: CollateralParameters,
+) -> Result<()> {
+    // Index 0 updates the base collateral held on the clone account.
+    let collateral = if index == 0 {
+        &mut ctx.accounts.clone.collateral
+    } else {
+        &mut ctx.accounts.collaterals.collaterals[index as usize - 1]
+    };
+
+    match params {
+        CollateralParameters::OracleInfoIndex { value } => {
+            collateral.oracle_info_index = value;
+        }
+        CollateralParameters::CollateralizationRatio { value } => {
+            return_error_if_false!(value <= 100, CloneError::InvalidValueRange);
+            collateral.collateralization_ratio = value;
+        }
+        CollateralParameters::DepositCap { value } => {
+            // Deposits of the base collateral aren't counted, see `Collaterals::add_deposit`.
+            return_error_if_false!(index > 0, CloneError::InvalidValueRange);
+            collateral.deposit_cap = value;
+        }
+        CollateralParameters::MinOvercollateralRatio { value } => {
+            collateral.min_overcollateral_ratio = value;
+        }
+        CollateralParameters::MaxLiquidationOvercollateralRatio { value } => {
+            collateral.max_liquidation_overcollateral_ratio = value;
+        }
+    }
+
+    return_error_if_false!(
+        collateral.is_valid_overcollateral_ratios(),
+        CloneError::InvalidOvercollateralizationRatios
+    );
+
+    Ok(())
+}
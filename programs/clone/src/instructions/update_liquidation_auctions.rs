@@ -0,0 +1,95 @@
+use crate::error::*;
+use crate::math::*;
+use crate::states::*;
+use crate::{to_clone_decimal, to_ratio_decimal};
+use crate::{CLONE_PROGRAM_SEED, COLLATERALS_SEED, ORACLES_SEED, POOLS_SEED, USER_SEED};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct UpdateLiquidationAuctions<'info> {
+    #[account(
+        seeds = [CLONE_PROGRAM_SEED.as_ref()],
+        bump = clone.bump,
+    )]
+    pub clone: Box<Account<'info, Clone>>,
+    #[account(
+        seeds = [POOLS_SEED.as_ref()],
+        bump,
+    )]
+    pub pools: Box<Account<'info, Pools>>,
+    #[account(
+        seeds = [ORACLES_SEED.as_ref()],
+        bump,
+    )]
+    pub oracles: Box<Account<'info, Oracles>>,
+    #[account(
+        seeds = [COLLATERALS_SEED.as_ref()],
+        bump,
+    )]
+    pub collaterals: Box<Account<'info, Collaterals>>,
+    #[account(
+        mut,
+        seeds = [USER_SEED.as_ref(), user.as_ref()],
+        bump,
+    )]
+    pub user_account: Box<Account<'info, User>>,
+}
+
+// Permissionless, keepers call it to start the auctions of unhealthy positions, and with
+// them the non-auth grace period, and users to clear them once their positions are healthy
+// again.
+pub fn execute(ctx: Context<UpdateLiquidationAuctions>, _user: Pubkey) -> Result<()> {
+    let slot = Clock::get()?.slot;
+    let clone = &ctx.accounts.clone;
+    let pools = &ctx.accounts.pools;
+    let oracles = &ctx.accounts.oracles;
+    let collaterals = &ctx.accounts.collaterals;
+    let user_account = &mut ctx.accounts.user_account;
+
+    if !user_account.comet.positions.is_empty() {
+        let health_score = calculate_health_score(
+            &user_account.comet,
+            pools,
+            oracles,
+            &clone.collateral,
+            collaterals,
+        )?;
+        user_account
+            .comet
+            .update_liquidation_auction(!health_score.is_healthy(), slot);
+    }
+
+    for borrow_position in user_account.borrows.iter_mut() {
+        let collateral =
+            collaterals.get_collateral(&clone.collateral, borrow_position.collateral_index)?;
+        let pool = &pools.pools[borrow_position.pool_index as usize];
+        // Settled pools close their borrows through settlement instead.
+        if pool.is_settling() {
+            borrow_position.update_liquidation_auction(false, slot);
+            continue;
+        }
+        let pool_oracle = &oracles.oracles[pool.asset_info.oracle_info_index as usize];
+        let collateral_oracle = &oracles.oracles[collateral.oracle_info_index as usize];
+        // Stale prices must fail here rather than read as undercollateralized.
+        check_feed_update(pool_oracle, slot)?;
+        check_feed_update(collateral_oracle, slot)?;
+
+        let (min_overcollateral_ratio, _) = collateral.get_overcollateral_ratios(&pool.asset_info);
+        let is_undercollateralized = check_mint_collateral_sufficient(
+            pool_oracle,
+            collateral,
+            collateral_oracle,
+            to_clone_decimal!(borrow_position.borrowed_onasset),
+            to_ratio_decimal!(min_overcollateral_ratio),
+            collateral.to_collateral_decimal(borrow_position.collateral_amount)?,
+        )
+        .is_err();
+        borrow_position.update_liquidation_auction(
+            is_undercollateralized || pool.status == Status::Liquidation,
+            slot,
+        );
+    }
+
+    Ok(())
+}
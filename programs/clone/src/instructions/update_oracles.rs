@@ -47,6 +47,7 @@ pub fn execute(ctx: Context<UpdateOracles>, params: UpdateOracleParameters) -> R
     let is_admin = auth_key.eq(&clone.admin);
     let is_auth = clone_auth.iter().any(|auth| auth_key.eq(auth));
 
+    let oracles_data_len = ctx.accounts.oracles.to_account_info().data_len();
     let oracles = &mut ctx.accounts.oracles.oracles;
 
     match params {
@@ -56,6 +57,14 @@ pub fn execute(ctx: Context<UpdateOracles>, params: UpdateOracleParameters) -> R
             rescale_factor,
         } => {
             return_error_if_false!(is_admin, CloneError::Unauthorized);
+            return_error_if_false!(
+                oracles_data_len >= ORACLES_SPACE,
+                CloneError::AccountMigrationRequired
+            );
+            return_error_if_false!(
+                oracles.len() < NUM_ORACLES,
+                CloneError::AccountCapacityReached
+            );
             oracles.push(OracleInfo {
                 source,
                 address,
@@ -12,6 +12,12 @@ pub enum PoolParameters {
     MaxLiquidationOvercollateralRatio { value: u16 },
     IlHealthScoreCoefficient { value: u16 },
     PositionHealthScoreCoefficient { value: u16 },
+    VolumeWindowSlots { value: u64 },
+    MaxNetVolume { value: u64 },
+    MaxGrossVolume { value: u64 },
+    StabilityFee { value: u16 },
+    DebtCeiling { value: u64 },
+    MaxCommittedCollateralLiquidity { value: u64 },
 }
 
 #[derive(Accounts)]
@@ -42,13 +48,13 @@ pub fn execute(
 ) -> Result<()> {
     let auth_key = *ctx.accounts.auth.key;
     let clone_admin = ctx.accounts.clone.admin;
-    let clone_auth = ctx.accounts.clone.auth.clone();
+    let clone_auth = ctx.accounts.clone.auth;
 
     let pools = &mut ctx.accounts.pools;
     let pool = &mut pools.pools[index as usize];
 
     let is_admin = auth_key == clone_admin;
-    let is_auth = clone_auth.iter().any(|auth| *auth == auth_key);
+    let is_auth = clone_auth.contains(&auth_key);
 
     // Always allow admin, auth only if Status is updated to Frozen
     return_error_if_false!(
@@ -66,6 +72,19 @@ pub fn execute(
         CloneError::Unauthorized
     );
 
+    // Settlement is final and only entered through `settle_pool` or `deprecate_pool`, which
+    // snapshot prices.
+    return_error_if_false!(
+        !pool.is_settling()
+            && !matches!(
+                params,
+                PoolParameters::Status {
+                    value: Status::Extraction | Status::Deprecation
+                }
+            ),
+        CloneError::StatusPreventsAction
+    );
+
     match params {
         PoolParameters::Status { value } => {
             pool.status = value;
@@ -91,6 +110,26 @@ pub fn execute(
         PoolParameters::PositionHealthScoreCoefficient { value } => {
             pool.asset_info.position_health_score_coefficient = value;
         }
+        PoolParameters::VolumeWindowSlots { value } => {
+            pool.swap_volume.window_slots = value;
+        }
+        PoolParameters::MaxNetVolume { value } => {
+            pool.swap_volume.max_net_volume = value;
+        }
+        PoolParameters::MaxGrossVolume { value } => {
+            pool.swap_volume.max_gross_volume = value;
+        }
+        PoolParameters::StabilityFee { value } => {
+            // settle accrual at the previous rate before switching
+            pool.stability_fee.accrue(Clock::get()?.unix_timestamp)?;
+            pool.stability_fee.annual_rate_bps = value;
+        }
+        PoolParameters::DebtCeiling { value } => {
+            pool.debt_ceiling = value;
+        }
+        PoolParameters::MaxCommittedCollateralLiquidity { value } => {
+            pool.max_committed_collateral_liquidity = value;
+        }
     }
 
     return_error_if_false!(
@@ -3,7 +3,7 @@ use crate::return_error_if_false;
 use crate::states::*;
 use crate::ORACLES_SEED;
 use anchor_lang::prelude::*;
-use pyth_sdk_solana::state::SolanaPriceAccount;
+use pyth_sdk_solana::state::{load_price_account, SolanaPriceAccount};
 use std::convert::TryInto;
 use switchboard_solana::AggregatorAccountData;
 
@@ -18,6 +18,75 @@ pub struct UpdatePrices<'info> {
     pub oracles: Box<Account<'info, Oracles>>,
 }
 
+pub struct OraclePrice {
+    pub price: i64,
+    pub expo: u8,
+    pub publish_slot: u64,
+}
+
+pub fn read_oracle_price(
+    source: &OracleSource,
+    oracle_account: &AccountInfo,
+) -> Result<OraclePrice> {
+    match source {
+        OracleSource::PYTH => {
+            let raw = oracle_account.try_borrow_data()?;
+            let price_account: &SolanaPriceAccount =
+                load_price_account(&raw).map_err(|_| error!(CloneError::FailedToLoadPyth))?;
+            // TODO: Consider updating this to check latest ts/conf
+            let info = price_account
+                .to_price_feed(oracle_account.key)
+                .get_price_unchecked();
+            let publish_slot = price_account.agg.pub_slot;
+            if info.expo <= 0 {
+                Ok(OraclePrice {
+                    price: info.price,
+                    expo: (-info.expo)
+                        .try_into()
+                        .map_err(|_| CloneError::IntTypeConversionError)?,
+                    publish_slot,
+                })
+            } else {
+                Ok(OraclePrice {
+                    price: info
+                        .price
+                        .checked_mul(
+                            10_i64
+                                .checked_pow(
+                                    info.expo
+                                        .try_into()
+                                        .map_err(|_| CloneError::IntTypeConversionError)?,
+                                )
+                                .ok_or(error!(CloneError::CheckedMathError))?,
+                        )
+                        .ok_or(error!(CloneError::CheckedMathError))?,
+                    expo: 0,
+                    publish_slot,
+                })
+            }
+        }
+        OracleSource::SWITCHBOARD => {
+            let raw = oracle_account.try_borrow_data()?;
+            let data_feed = AggregatorAccountData::new_from_bytes(*raw)
+                .map_err(|_| error!(CloneError::FailedToLoadSwitchboard))?;
+            let result = data_feed
+                .get_result()
+                .map_err(|_| error!(CloneError::FailedToLoadSwitchboard))?;
+            Ok(OraclePrice {
+                price: result
+                    .mantissa
+                    .try_into()
+                    .map_err(|_| CloneError::IntTypeConversionError)?,
+                expo: result
+                    .scale
+                    .try_into()
+                    .map_err(|_| CloneError::IntTypeConversionError)?,
+                publish_slot: data_feed.latest_confirmed_round.round_open_slot,
+            })
+        }
+    }
+}
+
 pub fn update_oracles(
     oracles: &mut Oracles,
     oracle_indices: Vec<u8>,
@@ -34,65 +103,18 @@ pub fn update_oracles(
             CloneError::IncorrectOracleAddress
         );
 
-        let (price, expo) = match oracle.source {
-            OracleSource::PYTH => {
-                if let Ok(price_info) =
-                    SolanaPriceAccount::account_info_to_feed(&supplied_oracle_address)
-                {
-                    // TODO: Consider updating this to check latest ts/conf
-                    let info = price_info.get_price_unchecked();
-                    if info.expo <= 0 {
-                        (
-                            info.price,
-                            (-info.expo)
-                                .try_into()
-                                .map_err(|_| CloneError::IntTypeConversionError)?,
-                        )
-                    } else {
-                        (
-                            info.price
-                                .checked_mul(
-                                    10_i64
-                                        .checked_pow(
-                                            info.expo
-                                                .try_into()
-                                                .map_err(|_| CloneError::IntTypeConversionError)?,
-                                        )
-                                        .ok_or(error!(CloneError::CheckedMathError))?,
-                                )
-                                .ok_or(error!(CloneError::CheckedMathError))?,
-                            0,
-                        )
-                    }
-                } else {
-                    return Err(error!(CloneError::FailedToLoadPyth));
-                }
-            }
-            OracleSource::SWITCHBOARD => {
-                let raw = supplied_oracle_address.try_borrow_data()?;
-                let data_feed = AggregatorAccountData::new_from_bytes(*raw)
-                    .map_err(|_| error!(CloneError::FailedToLoadSwitchboard))?;
-                let result = data_feed
-                    .get_result()
-                    .map_err(|_| error!(CloneError::FailedToLoadSwitchboard))?;
-                (
-                    result
-                        .mantissa
-                        .try_into()
-                        .map_err(|_| CloneError::IntTypeConversionError)?,
-                    result
-                        .scale
-                        .try_into()
-                        .map_err(|_| CloneError::IntTypeConversionError)?,
-                )
-            }
-        };
+        let OraclePrice {
+            price,
+            expo,
+            publish_slot,
+        } = read_oracle_price(&oracle.source, supplied_oracle_address)?;
 
         msg!("PRICE: {} {}", price, expo);
 
         oracles.oracles[oracle_index].price = price;
         oracles.oracles[oracle_index].expo = expo;
         oracles.oracles[oracle_index].last_update_slot = Clock::get()?.slot;
+        oracles.oracles[oracle_index].last_publish_slot = publish_slot;
 
         msg!("UPDATED ORACLE: {:?}", oracles.oracles[oracle_index]);
     }
@@ -0,0 +1,30 @@
+use crate::{error::*, return_error_if_false, states::*};
+use crate::{CLONE_PROGRAM_SEED, REFERRER_SEED};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(fee_share_bps: u16)]
+pub struct UpdateReferrer<'info> {
+    #[account(address = clone.admin)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [CLONE_PROGRAM_SEED.as_ref()],
+        bump = clone.bump,
+        has_one = admin,
+    )]
+    pub clone: Box<Account<'info, Clone>>,
+    #[account(
+        mut,
+        seeds = [REFERRER_SEED.as_ref(), referrer.authority.as_ref()],
+        bump,
+    )]
+    pub referrer: Account<'info, Referrer>,
+}
+
+pub fn execute(ctx: Context<UpdateReferrer>, fee_share_bps: u16) -> Result<()> {
+    return_error_if_false!(fee_share_bps <= 10000, CloneError::InvalidValueRange);
+
+    ctx.accounts.referrer.fee_share_bps = fee_share_bps;
+
+    Ok(())
+}
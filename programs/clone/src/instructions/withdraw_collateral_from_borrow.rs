@@ -1,10 +1,12 @@
 use crate::error::*;
 use crate::events::*;
+use crate::instructions::accrue_and_mint_stability_fee;
 use crate::math::*;
 use crate::return_error_if_false;
 use crate::states::*;
 use crate::{
-    to_clone_decimal, to_ratio_decimal, CLONE_PROGRAM_SEED, ORACLES_SEED, POOLS_SEED, USER_SEED,
+    to_clone_decimal, to_ratio_decimal, CLONE_PROGRAM_SEED, COLLATERALS_SEED, ORACLES_SEED,
+    POOLS_SEED, USER_SEED,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, *};
@@ -41,16 +43,36 @@ pub struct WithdrawCollateralFromBorrow<'info> {
     pub oracles: Box<Account<'info, Oracles>>,
     #[account(
         mut,
-        address = clone.collateral.vault,
+        seeds = [COLLATERALS_SEED.as_ref()],
+        bump,
+    )]
+    pub collaterals: Box<Account<'info, Collaterals>>,
+    #[account(
+        mut,
+        address = collaterals.get_collateral(
+            &clone.collateral,
+            user_account.borrows[borrow_index as usize].collateral_index
+        )?.vault,
         constraint = vault.amount >= amount @ CloneError::InvalidTokenAccountBalance
     )]
     pub vault: Box<Account<'info, TokenAccount>>,
     #[account(
         mut,
-        associated_token::mint = clone.collateral.mint,
+        associated_token::mint = vault.mint,
         associated_token::authority = user
     )]
     pub user_collateral_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = pools.pools[user_account.borrows[borrow_index as usize].pool_index as usize].asset_info.onasset_mint,
+    )]
+    pub onasset_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = onasset_mint,
+        associated_token::authority = clone.treasury_address
+    )]
+    pub treasury_onasset_token_account: Box<Account<'info, TokenAccount>>,
     pub token_program: Program<'info, Token>,
 }
 
@@ -64,18 +86,30 @@ pub fn execute(
         CLONE_PROGRAM_SEED.as_ref(),
         bytemuck::bytes_of(&ctx.accounts.clone.bump),
     ][..]];
-    let collateral = &ctx.accounts.clone.collateral;
     let pools = &mut ctx.accounts.pools;
     let oracles = &ctx.accounts.oracles;
     let borrows = &mut ctx.accounts.user_account.borrows;
+    let collateral = ctx.accounts.collaterals.get_collateral(
+        &ctx.accounts.clone.collateral,
+        borrows[borrow_index as usize].collateral_index,
+    )?;
 
     let pool_index = borrows[borrow_index as usize].pool_index;
+    accrue_and_mint_stability_fee(
+        &mut pools.pools[pool_index as usize],
+        &mut borrows[borrow_index as usize],
+        ctx.accounts.onasset_mint.to_account_info(),
+        ctx.accounts.treasury_onasset_token_account.to_account_info(),
+        ctx.accounts.clone.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        seeds,
+    )?;
     let pool = &pools.pools[pool_index as usize];
     let pool_oracle = &oracles.oracles[pool.asset_info.oracle_info_index as usize];
     let collateral_oracle = &oracles.oracles[collateral.oracle_info_index as usize];
 
-    let min_overcollateral_ratio = to_ratio_decimal!(pool.asset_info.min_overcollateral_ratio);
-    let collateralization_ratio = to_ratio_decimal!(collateral.collateralization_ratio);
+    let (min_overcollateral_ratio, _) = collateral.get_overcollateral_ratios(&pool.asset_info);
+    let min_overcollateral_ratio = to_ratio_decimal!(min_overcollateral_ratio);
     let borrow_position = &mut borrows[borrow_index as usize];
     let asset_amount_borrowed = to_clone_decimal!(borrow_position.borrowed_onasset);
     let amount_to_withdraw = amount.min(borrow_position.collateral_amount);
@@ -89,10 +123,10 @@ pub fn execute(
     // ensure position sufficiently over collateralized and oracle prices are up to date
     check_mint_collateral_sufficient(
         pool_oracle,
+        collateral,
         collateral_oracle,
         asset_amount_borrowed,
         min_overcollateral_ratio,
-        collateralization_ratio,
         collateral.to_collateral_decimal(borrow_position.collateral_amount)?,
     )?;
 
@@ -111,13 +145,14 @@ pub fn execute(
         CpiContext::new_with_signer(cpi_program, cpi_accounts, seeds),
         amount_to_withdraw,
     )?;
+    ctx.accounts
+        .collaterals
+        .remove_deposit(borrow_position.collateral_index, amount_to_withdraw)?;
 
     emit!(BorrowUpdate {
         event_id: ctx.accounts.clone.event_counter,
         user_address: ctx.accounts.user.key(),
-        pool_index: pool_index
-            .try_into()
-            .map_err(|_| CloneError::IntTypeConversionError)?,
+        pool_index,
         is_liquidation: false,
         collateral_supplied: borrow_position.collateral_amount,
         collateral_delta: -(amount_to_withdraw
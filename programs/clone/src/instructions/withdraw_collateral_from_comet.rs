@@ -3,13 +3,13 @@ use crate::events::*;
 use crate::math::*;
 use crate::return_error_if_false;
 use crate::states::*;
-use crate::{CLONE_PROGRAM_SEED, ORACLES_SEED, POOLS_SEED, USER_SEED};
+use crate::{CLONE_PROGRAM_SEED, COLLATERALS_SEED, ORACLES_SEED, POOLS_SEED, USER_SEED};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, *};
 use std::convert::TryInto;
 
 #[derive(Accounts)]
-#[instruction(collateral_amount: u64)]
+#[instruction(collateral_index: u8, collateral_amount: u64)]
 pub struct WithdrawCollateralFromComet<'info> {
     pub user: Signer<'info>,
     #[account(
@@ -38,7 +38,13 @@ pub struct WithdrawCollateralFromComet<'info> {
     pub oracles: Box<Account<'info, Oracles>>,
     #[account(
         mut,
-        address = clone.collateral.vault
+        seeds = [COLLATERALS_SEED.as_ref()],
+        bump,
+    )]
+    pub collaterals: Box<Account<'info, Collaterals>>,
+    #[account(
+        mut,
+        address = collaterals.get_collateral(&clone.collateral, collateral_index)?.vault
    )]
     pub vault: Box<Account<'info, TokenAccount>>,
     #[account(
@@ -50,7 +56,11 @@ pub struct WithdrawCollateralFromComet<'info> {
     pub token_program: Program<'info, Token>,
 }
 
-pub fn execute(ctx: Context<WithdrawCollateralFromComet>, collateral_amount: u64) -> Result<()> {
+pub fn execute(
+    ctx: Context<WithdrawCollateralFromComet>,
+    collateral_index: u8,
+    collateral_amount: u64,
+) -> Result<()> {
     return_error_if_false!(collateral_amount > 0, CloneError::InvalidTokenAmount);
 
     let seeds = &[&[
@@ -60,15 +70,14 @@ pub fn execute(ctx: Context<WithdrawCollateralFromComet>, collateral_amount: u64
     let collateral = &ctx.accounts.clone.collateral;
     let pools = &mut ctx.accounts.pools;
     let oracles = &ctx.accounts.oracles;
+    let collaterals = &ctx.accounts.collaterals;
 
     let comet = &mut ctx.accounts.user_account.comet;
-    let collateral_to_withdraw = collateral_amount.min(comet.collateral_amount);
+    let collateral_to_withdraw =
+        collateral_amount.min(comet.get_collateral_amount(collateral_index));
 
     // update the collateral amount
-    comet.collateral_amount = comet
-        .collateral_amount
-        .checked_sub(collateral_to_withdraw)
-        .ok_or(error!(CloneError::CheckedMathError))?;
+    comet.remove_collateral(collateral_index, collateral_to_withdraw)?;
 
     // send collateral from vault to user
     let cpi_accounts = Transfer {
@@ -85,14 +94,18 @@ pub fn execute(ctx: Context<WithdrawCollateralFromComet>, collateral_amount: u64
         CpiContext::new_with_signer(cpi_program, cpi_accounts, seeds),
         collateral_to_withdraw,
     )?;
-    let health_score = calculate_health_score(comet, pools, oracles, collateral)?;
+    let health_score = calculate_health_score(comet, pools, oracles, collateral, collaterals)?;
 
     return_error_if_false!(health_score.is_healthy(), CloneError::HealthScoreTooLow);
+    ctx.accounts
+        .collaterals
+        .remove_deposit(collateral_index, collateral_to_withdraw)?;
 
     emit!(CometCollateralUpdate {
         event_id: ctx.accounts.clone.event_counter,
         user_address: *ctx.accounts.user.key,
-        collateral_supplied: comet.collateral_amount,
+        collateral_index,
+        collateral_supplied: comet.get_collateral_amount(collateral_index),
         collateral_delta: -(collateral_to_withdraw
             .try_into()
             .map_err(|_| CloneError::IntTypeConversionError)?),
@@ -39,6 +39,7 @@ pub struct WithdrawLiquidityFromComet<'info> {
     pub oracles: Box<Account<'info, Oracles>>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn withdraw_liquidity(
     pools: &mut Pools,
     oracles: &Oracles,
@@ -76,10 +77,7 @@ pub fn withdraw_liquidity(
             .to_collateral_decimal(pool.collateral_ild)?
             .checked_mul(proportional_value)
             .ok_or(error!(CloneError::CheckedMathError))?,
-        collateral
-            .scale
-            .try_into()
-            .map_err(|_| CloneError::IntTypeConversionError)?,
+        collateral.scale.into(),
     );
     let onasset_ild_claim = rescale_toward_zero(
         to_clone_decimal!(pool.onasset_ild)
@@ -142,9 +140,7 @@ pub fn withdraw_liquidity(
     emit!(LiquidityDelta {
         event_id: event_counter,
         user_address: user,
-        pool_index: pool_index
-            .try_into()
-            .map_err(|_| CloneError::IntTypeConversionError)?,
+        pool_index,
         committed_collateral_delta: -TryInto::<i64>::try_into(collateral_value_to_withdraw)
             .map_err(|_| CloneError::IntTypeConversionError)?,
         onasset_ild_delta: -TryInto::<i64>::try_into(onasset_ild_claim.mantissa())
@@ -166,9 +162,7 @@ pub fn withdraw_liquidity(
 
     emit!(PoolState {
         event_id: event_counter,
-        pool_index: pool_index
-            .try_into()
-            .map_err(|_| CloneError::IntTypeConversionError)?,
+        pool_index,
         onasset_ild: pool.onasset_ild,
         collateral_ild: pool.collateral_ild,
         committed_collateral_liquidity: pool.committed_collateral_liquidity,
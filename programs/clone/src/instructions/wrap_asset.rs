@@ -21,7 +21,8 @@ pub struct WrapAsset<'info> {
         bump,
         constraint = (pool_index as usize) < pools.pools.len(),
         constraint = pools.pools[pool_index as usize].status != Status::Frozen &&
-        pools.pools[pool_index as usize].status != Status::Deprecation @ CloneError::StatusPreventsAction,
+        pools.pools[pool_index as usize].status != Status::Deprecation &&
+        pools.pools[pool_index as usize].status != Status::Extraction @ CloneError::StatusPreventsAction,
     )]
     pub pools: Box<Account<'info, Pools>>,
     #[account(
@@ -56,12 +57,7 @@ pub struct WrapAsset<'info> {
 pub fn execute(ctx: Context<WrapAsset>, amount: u64, _pool_index: u8) -> Result<()> {
     return_error_if_false!(amount > 0, CloneError::InvalidTokenAmount);
 
-    let underlying_mint_scale = ctx
-        .accounts
-        .asset_mint
-        .decimals
-        .try_into()
-        .map_err(|_| CloneError::IntTypeConversionError)?;
+    let underlying_mint_scale = ctx.accounts.asset_mint.decimals.into();
     let onasset_amount = rescale_toward_zero(
         Decimal::new(
             amount
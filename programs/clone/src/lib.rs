@@ -5,6 +5,7 @@ pub mod error;
 pub mod events;
 pub mod instructions;
 pub mod math;
+pub mod migration;
 pub mod states;
 
 use instructions::*;
@@ -45,6 +46,36 @@ pub mod clone {
         instructions::initialize_oracles::execute(ctx)
     }
 
+    pub fn initialize_collaterals(ctx: Context<InitializeCollaterals>) -> Result<()> {
+        instructions::initialize_collaterals::execute(ctx)
+    }
+
+    pub fn add_collateral(
+        ctx: Context<AddCollateral>,
+        oracle_info_index: u8,
+        collateralization_ratio: u8,
+        deposit_cap: u64,
+        min_overcollateral_ratio: u16,
+        max_liquidation_overcollateral_ratio: u16,
+    ) -> Result<()> {
+        instructions::add_collateral::execute(
+            ctx,
+            oracle_info_index,
+            collateralization_ratio,
+            deposit_cap,
+            min_overcollateral_ratio,
+            max_liquidation_overcollateral_ratio,
+        )
+    }
+
+    pub fn update_collateral_parameters(
+        ctx: Context<UpdateCollateralParameters>,
+        index: u8,
+        params: CollateralParameters,
+    ) -> Result<()> {
+        instructions::update_collateral_parameters::execute(ctx, index, params)
+    }
+
     pub fn update_clone_parameters(
         ctx: Context<UpdateCloneParameters>,
         params: CloneParameters,
@@ -71,6 +102,7 @@ pub mod clone {
         instructions::initialize_user::execute(ctx, authority)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn add_pool(
         ctx: Context<AddPool>,
         min_overcollateral_ratio: u16,
@@ -105,12 +137,14 @@ pub mod clone {
         pool_index: u8,
         onasset_amount: u64,
         collateral_amount: u64,
+        collateral_index: u8,
     ) -> Result<()> {
         instructions::initialize_borrow_position::execute(
             ctx,
             pool_index,
             onasset_amount,
             collateral_amount,
+            collateral_index,
         )
     }
 
@@ -145,16 +179,22 @@ pub mod clone {
 
     pub fn add_collateral_to_comet(
         ctx: Context<AddCollateralToComet>,
+        collateral_index: u8,
         collateral_amount: u64,
     ) -> Result<()> {
-        instructions::add_collateral_to_comet::execute(ctx, collateral_amount)
+        instructions::add_collateral_to_comet::execute(ctx, collateral_index, collateral_amount)
     }
 
     pub fn withdraw_collateral_from_comet(
         ctx: Context<WithdrawCollateralFromComet>,
+        collateral_index: u8,
         collateral_amount: u64,
     ) -> Result<()> {
-        instructions::withdraw_collateral_from_comet::execute(ctx, collateral_amount)
+        instructions::withdraw_collateral_from_comet::execute(
+            ctx,
+            collateral_index,
+            collateral_amount,
+        )
     }
 
     pub fn add_liquidity_to_comet(
@@ -186,8 +226,15 @@ pub mod clone {
         user: Pubkey,
         comet_position_index: u8,
         amount: u64,
+        flash: bool,
     ) -> Result<()> {
-        instructions::liquidate_comet_onasset_ild::execute(ctx, user, comet_position_index, amount)
+        instructions::liquidate_comet_onasset_ild::execute(
+            ctx,
+            user,
+            comet_position_index,
+            amount,
+            flash,
+        )
     }
 
     pub fn liquidate_borrow_position(
@@ -195,8 +242,9 @@ pub mod clone {
         user: Pubkey,
         borrow_index: u8,
         amount: u64,
+        flash: bool,
     ) -> Result<()> {
-        instructions::liquidate_borrow_position::execute(ctx, user, borrow_index, amount)
+        instructions::liquidate_borrow_position::execute(ctx, user, borrow_index, amount, flash)
     }
 
     pub fn collect_lp_rewards(
@@ -241,13 +289,16 @@ pub mod clone {
         instructions::remove_comet_position::execute(ctx, comet_position_index)
     }
 
-    pub fn swap(
-        ctx: Context<Swap>,
+    #[allow(clippy::too_many_arguments)]
+    pub fn swap<'info>(
+        ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
         pool_index: u8,
         quantity: u64,
         quantity_is_input: bool,
         quantity_is_collateral: bool,
         result_threshold: u64,
+        expiry: Option<SwapExpiry>,
+        max_oracle_age_slots: Option<u64>,
     ) -> Result<()> {
         instructions::swap::execute(
             ctx,
@@ -256,6 +307,8 @@ pub mod clone {
             quantity_is_input,
             quantity_is_collateral,
             result_threshold,
+            expiry,
+            max_oracle_age_slots,
         )
     }
 
@@ -269,4 +322,110 @@ pub mod clone {
     pub fn remove_pool(ctx: Context<RemovePool>, pool_index: u8) -> Result<()> {
         instructions::remove_pool::execute(ctx, pool_index)
     }
+
+    pub fn migrate_pools(ctx: Context<MigratePools>) -> Result<()> {
+        instructions::migrate_pools::execute(ctx)
+    }
+
+    pub fn initialize_referrer(
+        ctx: Context<InitializeReferrer>,
+        authority: Pubkey,
+        fee_share_bps: u16,
+    ) -> Result<()> {
+        instructions::initialize_referrer::execute(ctx, authority, fee_share_bps)
+    }
+
+    pub fn update_referrer(ctx: Context<UpdateReferrer>, fee_share_bps: u16) -> Result<()> {
+        instructions::update_referrer::execute(ctx, fee_share_bps)
+    }
+
+    pub fn flash_borrow(
+        ctx: Context<FlashBorrow>,
+        asset: FlashLoanAsset,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::flash_borrow::execute(ctx, asset, amount)
+    }
+
+    pub fn flash_repay(ctx: Context<FlashRepay>, asset: FlashLoanAsset, amount: u64) -> Result<()> {
+        instructions::flash_repay::execute(ctx, asset, amount)
+    }
+
+    pub fn update_liquidation_auctions(
+        ctx: Context<UpdateLiquidationAuctions>,
+        user: Pubkey,
+    ) -> Result<()> {
+        instructions::update_liquidation_auctions::execute(ctx, user)
+    }
+
+    pub fn settle_pool(ctx: Context<SettlePool>, pool_index: u8) -> Result<()> {
+        instructions::settle_pool::execute(ctx, pool_index)
+    }
+
+    pub fn settle_comet_position(
+        ctx: Context<SettleCometPosition>,
+        user: Pubkey,
+        comet_position_index: u8,
+    ) -> Result<()> {
+        instructions::settle_comet_position::execute(ctx, user, comet_position_index)
+    }
+
+    pub fn settle_borrow_position(
+        ctx: Context<SettleBorrowPosition>,
+        user: Pubkey,
+        borrow_index: u8,
+    ) -> Result<()> {
+        instructions::settle_borrow_position::execute(ctx, user, borrow_index)
+    }
+
+    pub fn redeem_settled_onasset(
+        ctx: Context<RedeemSettledOnasset>,
+        pool_index: u8,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::redeem_settled_onasset::execute(ctx, pool_index, amount)
+    }
+
+    pub fn initialize_settlement_reserve(
+        ctx: Context<InitializeSettlementReserve>,
+        pool_index: u8,
+        collateral_index: u8,
+    ) -> Result<()> {
+        instructions::initialize_settlement_reserve::execute(ctx, pool_index, collateral_index)
+    }
+
+    pub fn redeem_settlement_reserve(
+        ctx: Context<RedeemSettlementReserve>,
+        pool_index: u8,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::redeem_settlement_reserve::execute(ctx, pool_index, amount)
+    }
+
+    pub fn deprecate_pool(
+        ctx: Context<DeprecatePool>,
+        pool_index: u8,
+        redemption_deadline: i64,
+    ) -> Result<()> {
+        instructions::deprecate_pool::execute(ctx, pool_index, redemption_deadline)
+    }
+
+    pub fn close_settlement_reserve(
+        ctx: Context<CloseSettlementReserve>,
+        pool_index: u8,
+    ) -> Result<()> {
+        instructions::close_settlement_reserve::execute(ctx, pool_index)
+    }
+
+    pub fn migrate_clone(ctx: Context<MigrateClone>) -> Result<()> {
+        instructions::migrate_clone::execute(ctx)
+    }
+
+    pub fn migrate_oracles(ctx: Context<MigrateOracles>) -> Result<()> {
+        instructions::migrate_oracles::execute(ctx)
+    }
+
+    pub fn migrate_user(ctx: Context<MigrateUser>, authority: Pubkey) -> Result<()> {
+        instructions::migrate_user::execute(ctx, authority)
+    }
 }
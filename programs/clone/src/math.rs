@@ -1,8 +1,8 @@
 use std::convert::TryInto;
 
-use crate::decimal::{rescale_toward_zero, CLONE_TOKEN_SCALE};
+use crate::decimal::{rescale_away_from_zero, rescale_toward_zero, CLONE_TOKEN_SCALE};
 use crate::{error::*, to_ratio_decimal};
-use crate::{return_error_if_false, to_clone_decimal};
+use crate::{return_error_if_false, to_bps_decimal, to_clone_decimal};
 use crate::{states::*, to_pct_decimal};
 use anchor_lang::prelude::*;
 use rust_decimal::prelude::*;
@@ -35,17 +35,18 @@ pub fn calculate_liquidity_proportion_from_collateral(
     let denominator = collateral_amm_value
         .checked_add(collateral_liquidity_value)
         .ok_or(error!(CloneError::CheckedMathError))?;
-    Ok(collateral_liquidity_value
+    collateral_liquidity_value
         .checked_div(denominator)
-        .ok_or(error!(CloneError::CheckedMathError))?)
+        .ok_or(error!(CloneError::CheckedMathError))
 }
 
+// The pool price is taken against whichever registered collateral backs the position.
 pub fn check_mint_collateral_sufficient(
     pool_oracle: &OracleInfo,
+    collateral: &Collateral,
     collateral_oracle: &OracleInfo,
     asset_amount_borrowed: Decimal,
     min_overcollateral_ratio: Decimal,
-    collateralization_ratio: Decimal,
     collateral_amount: Decimal,
 ) -> Result<()> {
     let slot = Clock::get().expect("Failed to get slot.").slot;
@@ -53,8 +54,9 @@ pub fn check_mint_collateral_sufficient(
     check_feed_update(collateral_oracle, slot)?;
     let pool_price = pool_oracle
         .get_price()?
-        .checked_div(collateral_oracle.get_price()?)
+        .checked_div(collateral.get_price(collateral_oracle)?)
         .ok_or(error!(CloneError::CheckedMathError))?;
+    let collateralization_ratio = to_ratio_decimal!(collateral.collateralization_ratio);
 
     let numerator = collateral_amount
         .checked_mul(collateralization_ratio)
@@ -74,6 +76,175 @@ pub fn check_mint_collateral_sufficient(
     Ok(())
 }
 
+// How far a borrow position sits between `min_overcollateral_ratio` (0) and the point where
+// its collateral value no longer covers its debt (1).
+pub fn calculate_borrow_health_shortfall(
+    c_ratio: Decimal,
+    min_overcollateral_ratio: Decimal,
+) -> Result<Decimal> {
+    if c_ratio >= min_overcollateral_ratio {
+        return Ok(Decimal::ZERO);
+    }
+    Ok(min_overcollateral_ratio
+        .checked_sub(c_ratio)
+        .ok_or(error!(CloneError::CheckedMathError))?
+        .checked_div(
+            min_overcollateral_ratio
+                .checked_sub(Decimal::ONE)
+                .ok_or(error!(CloneError::CheckedMathError))?,
+        )
+        .ok_or(error!(CloneError::CheckedMathError))?
+        .min(Decimal::ONE))
+}
+
+// Liquidation bonuses run as a dutch auction that completes `auction_slots` after the
+// position was first seen liquidatable.
+pub fn calculate_liquidation_auction_progress(
+    start_slot: u64,
+    slot: u64,
+    auction_slots: u64,
+) -> Decimal {
+    if auction_slots == 0 {
+        return Decimal::ONE;
+    }
+    let elapsed_slots = slot.saturating_sub(start_slot).min(auction_slots);
+    Decimal::from(elapsed_slots) / Decimal::from(auction_slots)
+}
+
+// Interpolates the liquidator fee between its minimum and maximum, a maximum at or below
+// the minimum keeps the fee fixed.
+pub fn calculate_liquidation_fee_rate(
+    min_fee_bps: u16,
+    max_fee_bps: u16,
+    progress: Decimal,
+) -> Result<Decimal> {
+    let min_fee_rate = to_bps_decimal!(min_fee_bps);
+    let max_fee_rate = to_bps_decimal!(max_fee_bps);
+    if max_fee_rate <= min_fee_rate {
+        return Ok(min_fee_rate);
+    }
+    min_fee_rate
+        .checked_add(
+            max_fee_rate
+                .checked_sub(min_fee_rate)
+                .ok_or(error!(CloneError::CheckedMathError))?
+                .checked_mul(progress.min(Decimal::ONE))
+                .ok_or(error!(CloneError::CheckedMathError))?,
+        )
+        .ok_or(error!(CloneError::CheckedMathError))
+}
+
+// Snapshots the price positions settle and onasset redeems at, stability fees stop accruing.
+pub fn snapshot_settlement_price(
+    pool: &mut Pool,
+    pool_oracle: &OracleInfo,
+    collateral_oracle: &OracleInfo,
+) -> Result<()> {
+    let slot = Clock::get()?.slot;
+    check_feed_update(pool_oracle, slot)?;
+    check_feed_update(collateral_oracle, slot)?;
+
+    pool.settlement.pool_price = rescale_toward_zero(
+        pool_oracle
+            .get_price()?
+            .checked_div(collateral_oracle.get_price()?)
+            .ok_or(error!(CloneError::CheckedMathError))?,
+        CLONE_TOKEN_SCALE,
+    )
+    .mantissa()
+    .try_into()
+    .map_err(|_| CloneError::IntTypeConversionError)?;
+
+    pool.stability_fee.accrue(Clock::get()?.unix_timestamp)?;
+    pool.stability_fee.annual_rate_bps = 0;
+    Ok(())
+}
+
+// Buys `onasset_amount` from the pool with seized collateral for flash liquidations. No tokens
+// move: the bought onasset is cancelled against the liquidated debt and the collateral stays in
+// the vault, so only the pool's ILD is updated as a swap would. Returns the collateral spent and
+// the onasset treasury fees to mint.
+pub fn swap_collateral_for_liquidated_onasset(
+    pool: &mut Pool,
+    pool_oracle: &OracleInfo,
+    collateral_oracle: &OracleInfo,
+    collateral: &Collateral,
+    onasset_amount: Decimal,
+) -> Result<(Decimal, u64)> {
+    let slot = Clock::get().expect("Failed to get slot.").slot;
+    check_feed_update(pool_oracle, slot)?;
+    check_feed_update(collateral_oracle, slot)?;
+    return_error_if_false!(
+        pool.status == Status::Active,
+        CloneError::FlashLiquidationUnavailable
+    );
+    return_error_if_false!(
+        pool.committed_collateral_liquidity > 0,
+        CloneError::PoolEmpty
+    );
+
+    let swap_summary = pool.calculate_swap(
+        pool_oracle.get_price()?,
+        collateral_oracle.get_price()?,
+        onasset_amount,
+        false,
+        false,
+        collateral,
+        None,
+        None,
+    )?;
+    let collateral_spent: i64 = swap_summary
+        .result
+        .mantissa()
+        .try_into()
+        .map_err(|_| CloneError::IntTypeConversionError)?;
+    let onasset_amount: i64 = onasset_amount
+        .mantissa()
+        .try_into()
+        .map_err(|_| CloneError::IntTypeConversionError)?;
+    let treasury_fees: i64 = swap_summary
+        .treasury_fees_paid
+        .mantissa()
+        .try_into()
+        .map_err(|_| CloneError::IntTypeConversionError)?;
+
+    pool.onasset_ild = pool
+        .onasset_ild
+        .checked_add(onasset_amount)
+        .ok_or(error!(CloneError::CheckedMathError))?
+        .checked_add(treasury_fees)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+    pool.collateral_ild = pool
+        .collateral_ild
+        .checked_sub(collateral_spent)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+    pool.swap_volume.record(collateral_spent, slot)?;
+
+    Ok((
+        swap_summary.result,
+        treasury_fees
+            .try_into()
+            .map_err(|_| CloneError::IntTypeConversionError)?,
+    ))
+}
+
+// Accrues the pool's stability fee up to now and applies it to the borrow position,
+// returning the onasset amount owed to the treasury.
+pub fn accrue_borrow_stability_fee(pool: &mut Pool, borrow: &mut Borrow) -> Result<u64> {
+    pool.stability_fee.accrue(
+        Clock::get()
+            .expect("Failed to get timestamp.")
+            .unix_timestamp,
+    )?;
+    let stability_fee = borrow.accrue_stability_fee(&pool.stability_fee)?;
+    // accrued fees count towards supply but are not blocked by the debt ceiling
+    pool.borrowed_onasset = pool
+        .borrowed_onasset
+        .checked_add(stability_fee)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+    Ok(stability_fee)
+}
+
 #[derive(Clone, Debug)]
 pub struct HealthScore {
     pub score: Decimal,
@@ -88,6 +259,104 @@ impl HealthScore {
     }
 }
 
+// How far a comet's health score sits below zero, reaching 1 once its terms are twice what
+// its effective collateral supports.
+pub fn calculate_comet_health_shortfall(health_score: &HealthScore) -> Result<Decimal> {
+    if health_score.is_healthy() {
+        return Ok(Decimal::ZERO);
+    }
+    Ok(health_score
+        .score
+        .abs()
+        .checked_div(Decimal::ONE_HUNDRED)
+        .ok_or(error!(CloneError::CheckedMathError))?
+        .min(Decimal::ONE))
+}
+
+// How far the comet's terms exceed what its effective collateral supports at `target_score`,
+// zero once the comet is at or above the target.
+pub fn calculate_health_score_deficit(
+    health_score: &HealthScore,
+    target_score: u8,
+) -> Result<Decimal> {
+    let supported_terms = health_score
+        .effective_collateral
+        .checked_mul(
+            Decimal::ONE
+                .checked_sub(to_pct_decimal!(target_score))
+                .ok_or(error!(CloneError::CheckedMathError))?,
+        )
+        .ok_or(error!(CloneError::CheckedMathError))?;
+    Ok(health_score
+        .total_il_term
+        .checked_add(health_score.total_position_term)
+        .ok_or(error!(CloneError::CheckedMathError))?
+        .checked_sub(supported_terms)
+        .ok_or(error!(CloneError::CheckedMathError))?
+        .max(Decimal::ZERO))
+}
+
+// The ILD payment, in units of the ILD asset, that closes `deficit`. Each unit lowers the IL
+// term but also spends `1 + liquidator_fee` units of collateral, so paying ILD only helps
+// while the IL coefficient outweighs the collateral it costs; returns None otherwise.
+pub fn calculate_liquidation_ild_payment(
+    deficit: Decimal,
+    ild_price: Decimal,
+    pool: &Pool,
+    collateral: &Collateral,
+    liquidator_fee: Decimal,
+    target_score: u8,
+) -> Result<Option<Decimal>> {
+    let collateral_cost = Decimal::ONE
+        .checked_add(liquidator_fee)
+        .ok_or(error!(CloneError::CheckedMathError))?
+        .checked_mul(to_ratio_decimal!(collateral.collateralization_ratio))
+        .ok_or(error!(CloneError::CheckedMathError))?
+        .checked_mul(
+            Decimal::ONE
+                .checked_sub(to_pct_decimal!(target_score))
+                .ok_or(error!(CloneError::CheckedMathError))?,
+        )
+        .ok_or(error!(CloneError::CheckedMathError))?;
+    let improvement = to_pct_decimal!(pool.asset_info.il_health_score_coefficient)
+        .checked_sub(collateral_cost)
+        .ok_or(error!(CloneError::CheckedMathError))?
+        .checked_mul(ild_price)
+        .ok_or(error!(CloneError::CheckedMathError))?;
+    if improvement <= Decimal::ZERO {
+        return Ok(None);
+    }
+    Ok(Some(
+        deficit
+            .checked_div(improvement)
+            .ok_or(error!(CloneError::CheckedMathError))?,
+    ))
+}
+
+// The liquidity to withdraw from a position so its position term closes `deficit`, the
+// whole position when its coefficient is zero and withdrawing can't improve the score.
+pub fn calculate_liquidation_liquidity_withdrawal(
+    deficit: Decimal,
+    pool: &Pool,
+    liquidity_position: &LiquidityPosition,
+    collateral: &Collateral,
+) -> Result<u64> {
+    let position_coefficient = to_pct_decimal!(pool.asset_info.position_health_score_coefficient);
+    if position_coefficient.is_zero() {
+        return Ok(liquidity_position.committed_collateral_liquidity);
+    }
+    let liquidity: u64 = rescale_away_from_zero(
+        deficit
+            .checked_div(position_coefficient)
+            .ok_or(error!(CloneError::CheckedMathError))?,
+        collateral.scale.into(),
+    )
+    .mantissa()
+    .try_into()
+    .map_err(|_| CloneError::IntTypeConversionError)?;
+    Ok(liquidity.min(liquidity_position.committed_collateral_liquidity))
+}
+
 pub fn calculate_liquidity_position_loss(
     pools: &Pools,
     oracles: &Oracles,
@@ -120,10 +389,7 @@ pub fn calculate_liquidity_position_loss(
                 collateral.to_collateral_decimal(liquidity_position.collateral_ild_rebate)?,
             )
             .ok_or(error!(CloneError::CheckedMathError))?,
-        collateral
-            .scale
-            .try_into()
-            .map_err(|_| CloneError::IntTypeConversionError)?,
+        collateral.scale.into(),
     );
     let onasset_ild_share = rescale_toward_zero(
         to_clone_decimal!(pool.onasset_ild)
@@ -134,10 +400,14 @@ pub fn calculate_liquidity_position_loss(
         CLONE_TOKEN_SCALE,
     );
 
-    let pool_price = oracle
-        .get_price()?
-        .checked_div(collateral_oracle.get_price()?)
-        .ok_or(error!(CloneError::CheckedMathError))?;
+    let pool_price = if pool.is_settling() {
+        pool.settlement.get_pool_price()
+    } else {
+        oracle
+            .get_price()?
+            .checked_div(collateral_oracle.get_price()?)
+            .ok_or(error!(CloneError::CheckedMathError))?
+    };
 
     let impermanent_loss = collateral_ild_share
         .max(Decimal::ZERO)
@@ -165,6 +435,7 @@ pub fn calculate_health_score(
     pools: &Pools,
     oracles: &Oracles,
     collateral: &Collateral,
+    collaterals: &Collaterals,
 ) -> Result<HealthScore> {
     let slot = Clock::get().expect("Failed to get slot.").slot;
     let collateral_oracle = &oracles.oracles[collateral.oracle_info_index as usize];
@@ -173,12 +444,15 @@ pub fn calculate_health_score(
     let mut total_il_term = Decimal::zero();
     let mut total_position_term = Decimal::zero();
 
-    for index in 0..(comet.positions.len() as usize) {
+    for index in 0..comet.positions.len() {
         let liquidity_position = comet.positions[index];
         let pool = &&pools.pools[liquidity_position.pool_index as usize];
         let oracle = &oracles.oracles[pool.asset_info.oracle_info_index as usize];
 
-        check_feed_update(oracle, slot)?;
+        // Settled pools are valued at their snapshot price, their oracles may have stopped.
+        if !pool.is_settling() {
+            check_feed_update(oracle, slot)?;
+        }
         let (impermanent_loss_term, position_term) =
             calculate_liquidity_position_loss(pools, oracles, &liquidity_position, collateral)?;
 
@@ -190,13 +464,40 @@ pub fn calculate_health_score(
             .ok_or(error!(CloneError::CheckedMathError))?;
     }
 
-    let effective_collateral = collateral
+    let mut effective_collateral = collateral
         .to_collateral_decimal(comet.collateral_amount)?
         .checked_mul(to_ratio_decimal!(collateral.collateralization_ratio))
         .ok_or(error!(CloneError::CheckedMathError))?;
 
+    // Registered collaterals are valued in terms of the base collateral after their haircut.
+    for deposit in comet.collateral_deposits.iter() {
+        let deposit_collateral =
+            collaterals.get_collateral(collateral, deposit.collateral_index)?;
+        let deposit_oracle = &oracles.oracles[deposit_collateral.oracle_info_index as usize];
+        check_feed_update(deposit_oracle, slot)?;
+        let deposit_price = deposit_collateral
+            .get_price(deposit_oracle)?
+            .checked_div(collateral_oracle.get_price()?)
+            .ok_or(error!(CloneError::CheckedMathError))?;
+        effective_collateral = effective_collateral
+            .checked_add(
+                deposit_collateral
+                    .to_collateral_decimal(deposit.amount)?
+                    .checked_mul(deposit_price)
+                    .ok_or(error!(CloneError::CheckedMathError))?
+                    .checked_mul(to_ratio_decimal!(
+                        deposit_collateral.collateralization_ratio
+                    ))
+                    .ok_or(error!(CloneError::CheckedMathError))?,
+            )
+            .ok_or(error!(CloneError::CheckedMathError))?;
+    }
+
     let score = if total_il_term.is_zero() && total_position_term.is_zero() {
         Decimal::new(100, 0)
+    } else if effective_collateral.is_zero() {
+        // A comet liquidated down to no collateral is unhealthy for as long as it has terms.
+        Decimal::MIN
     } else {
         let summed_terms = total_il_term
             .checked_add(total_position_term)
@@ -256,10 +557,7 @@ pub fn calculate_ild_share(
             .to_collateral_decimal(pool.collateral_ild)?
             .checked_mul(claimable_ratio)
             .ok_or(error!(CloneError::CheckedMathError))?,
-        collateral
-            .scale
-            .try_into()
-            .map_err(|_| CloneError::IntTypeConversionError)?,
+        collateral.scale.into(),
     );
     let onasset_ild_claim = rescale_toward_zero(
         to_clone_decimal!(pool.onasset_ild)
@@ -274,10 +572,7 @@ pub fn calculate_ild_share(
                 collateral.to_collateral_decimal(liquidity_position.collateral_ild_rebate)?,
             )
             .ok_or(error!(CloneError::CheckedMathError))?,
-        collateral
-            .scale
-            .try_into()
-            .map_err(|_| CloneError::IntTypeConversionError)?,
+        collateral.scale.into(),
     );
     let onasset_ild_share = rescale_toward_zero(
         onasset_ild_claim
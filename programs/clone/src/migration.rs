@@ -0,0 +1,234 @@
+use crate::states::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+
+// Layouts of accounts created before the program's state grew, kept so deployed
+// accounts can be read once and rewritten in the current layout.
+
+#[derive(Clone, Debug, AnchorDeserialize, AnchorSerialize)]
+pub struct LegacyCollateral {
+    pub oracle_info_index: u8,
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub collateralization_ratio: u8,
+    pub scale: u8,
+}
+
+impl From<LegacyCollateral> for Collateral {
+    fn from(legacy: LegacyCollateral) -> Self {
+        Collateral {
+            oracle_info_index: legacy.oracle_info_index,
+            mint: legacy.mint,
+            vault: legacy.vault,
+            collateralization_ratio: legacy.collateralization_ratio,
+            scale: legacy.scale,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, AnchorDeserialize, AnchorSerialize)]
+pub struct LegacyClone {
+    pub admin: Pubkey,
+    pub auth: [Pubkey; NUM_AUTH],
+    pub bump: u8,
+    pub collateral: LegacyCollateral,
+    pub comet_collateral_ild_liquidator_fee_bps: u16,
+    pub comet_onasset_ild_liquidator_fee_bps: u16,
+    pub borrow_liquidator_fee_bps: u16,
+    pub treasury_address: Pubkey,
+    pub event_counter: u64,
+    pub non_auth_liquidations_enabled: bool,
+}
+
+// Parameters added since are left at zero, which disables them until the admin sets them.
+impl From<LegacyClone> for Clone {
+    fn from(legacy: LegacyClone) -> Self {
+        Clone {
+            admin: legacy.admin,
+            auth: legacy.auth,
+            bump: legacy.bump,
+            collateral: legacy.collateral.into(),
+            comet_collateral_ild_liquidator_fee_bps: legacy.comet_collateral_ild_liquidator_fee_bps,
+            comet_onasset_ild_liquidator_fee_bps: legacy.comet_onasset_ild_liquidator_fee_bps,
+            borrow_liquidator_fee_bps: legacy.borrow_liquidator_fee_bps,
+            treasury_address: legacy.treasury_address,
+            event_counter: legacy.event_counter,
+            non_auth_liquidations_enabled: legacy.non_auth_liquidations_enabled,
+            flash_loan_fee_bps: 0,
+            borrow_liquidator_max_fee_bps: 0,
+            borrow_liquidation_close_factor_bps: 0,
+            borrow_liquidation_protocol_fee_bps: 0,
+            comet_collateral_ild_liquidator_max_fee_bps: 0,
+            comet_onasset_ild_liquidator_max_fee_bps: 0,
+            liquidation_auction_slots: 0,
+            insurance_fund_fee_bps: 0,
+            insurance_fund: 0,
+            comet_liquidation_target_health_score: 0,
+            auth_liquidation_grace_slots: 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, AnchorDeserialize, AnchorSerialize)]
+pub struct LegacyPool {
+    pub underlying_asset_token_account: Pubkey,
+    pub committed_collateral_liquidity: u64,
+    pub collateral_ild: i64,
+    pub onasset_ild: i64,
+    pub treasury_trading_fee_bps: u16,
+    pub liquidity_trading_fee_bps: u16,
+    pub asset_info: AssetInfo,
+    pub status: Status,
+}
+
+// Outstanding borrows predate `borrowed_onasset`, so it starts at zero and debt
+// ceilings only count borrows made after the migration.
+impl From<LegacyPool> for Pool {
+    fn from(legacy: LegacyPool) -> Self {
+        Pool {
+            underlying_asset_token_account: legacy.underlying_asset_token_account,
+            committed_collateral_liquidity: legacy.committed_collateral_liquidity,
+            collateral_ild: legacy.collateral_ild,
+            onasset_ild: legacy.onasset_ild,
+            treasury_trading_fee_bps: legacy.treasury_trading_fee_bps,
+            liquidity_trading_fee_bps: legacy.liquidity_trading_fee_bps,
+            asset_info: legacy.asset_info,
+            status: legacy.status,
+            swap_volume: SwapVolume::default(),
+            stability_fee: StabilityFee::default(),
+            borrowed_onasset: 0,
+            debt_ceiling: 0,
+            max_committed_collateral_liquidity: 0,
+            settlement: Settlement::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, AnchorDeserialize, AnchorSerialize)]
+pub struct LegacyPools {
+    pub pools: Vec<LegacyPool>,
+}
+
+#[derive(Clone, Debug, AnchorDeserialize, AnchorSerialize)]
+pub struct LegacyOracleInfo {
+    pub source: OracleSource,
+    pub address: Pubkey,
+    pub price: i64,
+    pub expo: u8,
+    pub status: Status,
+    pub last_update_slot: u64,
+    pub rescale_factor: u8,
+}
+
+impl From<LegacyOracleInfo> for OracleInfo {
+    fn from(legacy: LegacyOracleInfo) -> Self {
+        OracleInfo {
+            source: legacy.source,
+            address: legacy.address,
+            price: legacy.price,
+            expo: legacy.expo,
+            status: legacy.status,
+            last_update_slot: legacy.last_update_slot,
+            rescale_factor: legacy.rescale_factor,
+            last_publish_slot: 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, AnchorDeserialize, AnchorSerialize)]
+pub struct LegacyOracles {
+    pub oracles: Vec<LegacyOracleInfo>,
+}
+
+#[derive(Clone, Debug, AnchorDeserialize, AnchorSerialize)]
+pub struct LegacyBorrow {
+    pub pool_index: u8,
+    pub borrowed_onasset: u64,
+    pub collateral_amount: u64,
+}
+
+// Legacy borrows are backed by the base collateral and start accruing stability fees
+// from the pool's index at their next update. No liquidation auction is running yet.
+impl From<LegacyBorrow> for Borrow {
+    fn from(legacy: LegacyBorrow) -> Self {
+        Borrow {
+            pool_index: legacy.pool_index,
+            borrowed_onasset: legacy.borrowed_onasset,
+            collateral_amount: legacy.collateral_amount,
+            collateral_index: 0,
+            stability_fee_index: 0,
+            liquidation_start_slot: 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, AnchorDeserialize, AnchorSerialize)]
+pub struct LegacyComet {
+    pub collateral_amount: u64,
+    pub positions: Vec<LiquidityPosition>,
+}
+
+#[derive(Clone, Debug, AnchorDeserialize, AnchorSerialize)]
+pub struct LegacyUser {
+    pub borrows: Vec<LegacyBorrow>,
+    pub comet: LegacyComet,
+}
+
+impl From<LegacyUser> for User {
+    fn from(legacy: LegacyUser) -> Self {
+        User {
+            borrows: legacy.borrows.into_iter().map(Borrow::from).collect(),
+            comet: Comet {
+                collateral_amount: legacy.comet.collateral_amount,
+                positions: legacy.comet.positions,
+                collateral_deposits: Vec::new(),
+                liquidation_start_slot: 0,
+            },
+        }
+    }
+}
+
+// Reads an account written in a legacy layout behind `T`'s discriminator.
+pub fn read_legacy_account<T: Discriminator, L: AnchorDeserialize>(
+    account: &AccountInfo,
+) -> Result<L> {
+    if account.owner != &crate::ID {
+        return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+    }
+    let data = account.try_borrow_data()?;
+    if data.len() < 8 || data[..8] != T::discriminator() {
+        return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+    L::deserialize(&mut &data[8..]).map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+}
+
+// Resizes `account` to `space`, topping up its rent exemption from `payer`, and rewrites
+// it in the current layout.
+pub fn write_migrated_account<'info, T: AccountSerialize>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    state: &T,
+) -> Result<()> {
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(space);
+    if account.lamports() < rent_exempt_lamports {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent_exempt_lamports - account.lamports(),
+        )?;
+    }
+    account.realloc(space, true)?;
+    let mut data = account.try_borrow_mut_data()?;
+    data.fill(0);
+    let mut writer: &mut [u8] = &mut data;
+    state.try_serialize(&mut writer)
+}
@@ -1,7 +1,8 @@
-use crate::decimal::{rescale_toward_zero, CLONE_TOKEN_SCALE};
+use crate::decimal::{rescale_toward_zero, CLONE_TOKEN_SCALE, INDEX_SCALE};
 use crate::error::CloneError;
-use crate::{to_bps_decimal, to_clone_decimal};
+use crate::{return_error_if_false, to_bps_decimal, to_clone_decimal};
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
 use rust_decimal::prelude::*;
 use std::convert::TryInto;
 use std::vec::Vec;
@@ -19,6 +20,47 @@ pub enum Status {
 pub const NUM_POOLS: usize = 64;
 pub const NUM_BORROW_POSITIONS: usize = 24;
 pub const NUM_AUTH: usize = 10;
+pub const NUM_ORACLES: usize = 256;
+pub const NUM_COLLATERALS: usize = 64;
+
+// Accounts created before their layout grew keep the old size until they are migrated.
+pub const LEGACY_ACCOUNT_SPACE: usize = 10240;
+pub const CLONE_SPACE: usize = 8 + 541;
+// Vec fields are sized from their in-memory entries, which are never smaller than
+// their serialized form.
+pub const POOLS_SPACE: usize = migrated_space(8 + 4 + NUM_POOLS * std::mem::size_of::<Pool>());
+pub const ORACLES_SPACE: usize =
+    migrated_space(8 + 4 + NUM_ORACLES * std::mem::size_of::<OracleInfo>());
+pub const USER_SPACE: usize = migrated_space(
+    8 + 4
+        + NUM_BORROW_POSITIONS * std::mem::size_of::<Borrow>()
+        + 8
+        + 4
+        + NUM_POOLS * std::mem::size_of::<LiquidityPosition>()
+        + 4
+        + NUM_COLLATERALS * std::mem::size_of::<CollateralDeposit>(),
+);
+// The collaterals account is created at full size, which a single instruction can't
+// grow an account past.
+pub const COLLATERALS_SPACE: usize = 8 + 4 + NUM_COLLATERALS * std::mem::size_of::<Collateral>();
+
+// Migrated accounts are always sized past the legacy allocation, which is how the migrate
+// instructions tell them apart from accounts still in the legacy layout.
+const fn migrated_space(space: usize) -> usize {
+    if space > LEGACY_ACCOUNT_SPACE {
+        space
+    } else {
+        LEGACY_ACCOUNT_SPACE + 8
+    }
+}
+
+// A migration grows an account in a single realloc, which is capped per instruction.
+const _: () = assert!(POOLS_SPACE - LEGACY_ACCOUNT_SPACE <= MAX_PERMITTED_DATA_INCREASE);
+const _: () = assert!(ORACLES_SPACE - LEGACY_ACCOUNT_SPACE <= MAX_PERMITTED_DATA_INCREASE);
+const _: () = assert!(USER_SPACE - LEGACY_ACCOUNT_SPACE <= MAX_PERMITTED_DATA_INCREASE);
+const _: () = assert!(COLLATERALS_SPACE <= MAX_PERMITTED_DATA_INCREASE);
+
+pub const SECONDS_PER_YEAR: i64 = 31_536_000;
 
 #[account]
 #[derive(Default)]
@@ -33,28 +75,107 @@ pub struct Clone {
     pub treasury_address: Pubkey,
     pub event_counter: u64,
     pub non_auth_liquidations_enabled: bool,
+    pub flash_loan_fee_bps: u16,
+    pub borrow_liquidator_max_fee_bps: u16,
+    pub borrow_liquidation_close_factor_bps: u16,
+    pub borrow_liquidation_protocol_fee_bps: u16,
+    pub comet_collateral_ild_liquidator_max_fee_bps: u16,
+    pub comet_onasset_ild_liquidator_max_fee_bps: u16,
+    pub liquidation_auction_slots: u64,
+    pub insurance_fund_fee_bps: u16,
+    pub insurance_fund: u64,
+    pub comet_liquidation_target_health_score: u8,
+    pub auth_liquidation_grace_slots: u64,
+}
+
+impl Clone {
+    // Auth keepers may always liquidate, anyone else only once non-auth liquidations are
+    // enabled and the position's auction has outlasted the grace period. The grace period
+    // shrinks with the position's health shortfall, so positions that were unhealthy long
+    // before their auction was first recorded don't wait out all of it. Keepers start the
+    // auction through `update_liquidation_auctions`.
+    pub fn check_liquidator(
+        &self,
+        liquidator: &Pubkey,
+        liquidation_start_slot: u64,
+        slot: u64,
+        health_shortfall: Decimal,
+    ) -> Result<()> {
+        if self.auth.contains(liquidator) {
+            return Ok(());
+        }
+        return_error_if_false!(self.non_auth_liquidations_enabled, CloneError::Unauthorized);
+        let grace_slots = Decimal::from(self.auth_liquidation_grace_slots)
+            .checked_mul(
+                Decimal::ONE
+                    .checked_sub(health_shortfall.min(Decimal::ONE))
+                    .ok_or(error!(CloneError::CheckedMathError))?,
+            )
+            .ok_or(error!(CloneError::CheckedMathError))?
+            .ceil()
+            .to_u64()
+            .ok_or(error!(CloneError::IntTypeConversionError))?;
+        return_error_if_false!(
+            slot >= liquidation_start_slot.saturating_add(grace_slots),
+            CloneError::LiquidationGracePeriodActive
+        );
+        Ok(())
+    }
 }
 #[account]
+#[derive(Default)]
 pub struct Pools {
     pub pools: Vec<Pool>,
 }
 
-impl Default for Pools {
-    fn default() -> Self {
-        Self { pools: Vec::new() }
-    }
-}
-
 #[account]
+#[derive(Default)]
 pub struct Oracles {
     pub oracles: Vec<OracleInfo>,
 }
 
-impl Default for Oracles {
-    fn default() -> Self {
-        Self {
-            oracles: Vec::new(),
+#[account]
+#[derive(Default)]
+pub struct Collaterals {
+    pub collaterals: Vec<Collateral>,
+}
+
+impl Collaterals {
+    // Index 0 is the base collateral stored on `Clone` which pools are denominated in,
+    // registered collaterals follow from index 1.
+    pub fn get_collateral<'a>(
+        &'a self,
+        base_collateral: &'a Collateral,
+        collateral_index: u8,
+    ) -> Result<&'a Collateral> {
+        if collateral_index == 0 {
+            return Ok(base_collateral);
+        }
+        self.collaterals
+            .get(collateral_index as usize - 1)
+            .ok_or(error!(CloneError::InvalidCollateralIndex))
+    }
+
+    // Only registered collaterals count their deposits, the base collateral also backs
+    // pool liquidity and ILD payments so it is left uncapped.
+    pub fn add_deposit(&mut self, collateral_index: u8, amount: u64) -> Result<()> {
+        if collateral_index == 0 {
+            return Ok(());
+        }
+        self.collaterals
+            .get_mut(collateral_index as usize - 1)
+            .ok_or(error!(CloneError::InvalidCollateralIndex))?
+            .add_deposit(amount)
+    }
+
+    pub fn remove_deposit(&mut self, collateral_index: u8, amount: u64) -> Result<()> {
+        if collateral_index == 0 {
+            return Ok(());
         }
+        self.collaterals
+            .get_mut(collateral_index as usize - 1)
+            .ok_or(error!(CloneError::InvalidCollateralIndex))?
+            .remove_deposit(amount)
     }
 }
 
@@ -91,6 +212,7 @@ pub struct OracleInfo {
     pub status: Status,
     pub last_update_slot: u64,
     pub rescale_factor: u8,
+    pub last_publish_slot: u64,
 }
 
 impl OracleInfo {
@@ -101,7 +223,7 @@ impl OracleInfo {
                 .checked_div(Decimal::new(1, self.rescale_factor.into()))
                 .ok_or(error!(CloneError::CheckedMathError))?;
         }
-        return Ok(price);
+        Ok(price)
     }
 }
 
@@ -115,6 +237,128 @@ pub struct Pool {
     pub liquidity_trading_fee_bps: u16,
     pub asset_info: AssetInfo,
     pub status: Status,
+    pub swap_volume: SwapVolume,
+    pub stability_fee: StabilityFee,
+    pub borrowed_onasset: u64,
+    pub debt_ceiling: u64,
+    pub max_committed_collateral_liquidity: u64,
+    pub settlement: Settlement,
+}
+
+#[derive(Clone, PartialEq, Eq, Default, Debug, AnchorDeserialize, AnchorSerialize)]
+pub struct Settlement {
+    pub pool_price: u64,
+    pub collateral_reserve: u64,
+    pub registered_reserve_onasset: u64,
+    pub redemption_deadline: i64,
+}
+
+impl Settlement {
+    // Onasset price in base collateral snapshotted when the pool was settled or deprecated.
+    pub fn get_pool_price(&self) -> Decimal {
+        Decimal::new(self.pool_price as i64, CLONE_TOKEN_SCALE)
+    }
+
+    // Redemptions never close without a deadline, as in extraction.
+    pub fn is_redeemable(&self, timestamp: i64) -> bool {
+        self.redemption_deadline == 0 || timestamp <= self.redemption_deadline
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Default, Debug, AnchorDeserialize, AnchorSerialize)]
+pub struct StabilityFee {
+    pub annual_rate_bps: u16,
+    pub cumulative_index: u64,
+    pub last_update_timestamp: i64,
+}
+
+impl StabilityFee {
+    pub fn get_index(&self) -> Decimal {
+        if self.cumulative_index == 0 {
+            Decimal::ONE
+        } else {
+            Decimal::new(self.cumulative_index as i64, INDEX_SCALE)
+        }
+    }
+
+    // The index compounds on every accrual, so the realised rate sits slightly above
+    // `annual_rate_bps` for frequently touched pools.
+    pub fn accrue(&mut self, timestamp: i64) -> Result<()> {
+        let elapsed_seconds = timestamp.saturating_sub(self.last_update_timestamp);
+        let mut index = self.get_index();
+        if self.last_update_timestamp > 0 && elapsed_seconds > 0 && self.annual_rate_bps > 0 {
+            let growth = to_bps_decimal!(self.annual_rate_bps)
+                .checked_mul(Decimal::from(elapsed_seconds))
+                .ok_or(error!(CloneError::CheckedMathError))?
+                .checked_div(Decimal::from(SECONDS_PER_YEAR))
+                .ok_or(error!(CloneError::CheckedMathError))?;
+            index = index
+                .checked_mul(
+                    Decimal::ONE
+                        .checked_add(growth)
+                        .ok_or(error!(CloneError::CheckedMathError))?,
+                )
+                .ok_or(error!(CloneError::CheckedMathError))?;
+        }
+        self.cumulative_index = rescale_toward_zero(index, INDEX_SCALE)
+            .mantissa()
+            .try_into()
+            .map_err(|_| CloneError::IntTypeConversionError)?;
+        self.last_update_timestamp = timestamp;
+        Ok(())
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Default, Debug, AnchorDeserialize, AnchorSerialize)]
+pub struct SwapVolume {
+    pub window_slots: u64,
+    pub max_net_volume: u64,
+    pub max_gross_volume: u64,
+    pub last_update_slot: u64,
+    pub net_volume: i64,
+    pub gross_volume: u64,
+}
+
+impl SwapVolume {
+    // Tracked volumes decay linearly to zero over `window_slots`, which approximates
+    // a rolling window without storing per-slot history. A zero cap disables that check.
+    pub fn record(&mut self, collateral_volume: i64, slot: u64) -> Result<()> {
+        if self.window_slots == 0 {
+            return Ok(());
+        }
+        let elapsed_slots = slot.saturating_sub(self.last_update_slot);
+        let remaining_slots = self.window_slots.saturating_sub(elapsed_slots);
+        let decay = |value: i128| -> Result<i128> {
+            value
+                .checked_mul(remaining_slots.into())
+                .and_then(|v| v.checked_div(self.window_slots.into()))
+                .ok_or(error!(CloneError::CheckedMathError))
+        };
+        let net_volume = decay(self.net_volume.into())?
+            .checked_add(collateral_volume.into())
+            .ok_or(error!(CloneError::CheckedMathError))?;
+        let gross_volume = decay(self.gross_volume.into())?
+            .checked_add(collateral_volume.unsigned_abs().into())
+            .ok_or(error!(CloneError::CheckedMathError))?;
+
+        return_error_if_false!(
+            self.max_net_volume == 0 || net_volume.unsigned_abs() <= self.max_net_volume.into(),
+            CloneError::VolumeCapExceeded
+        );
+        return_error_if_false!(
+            self.max_gross_volume == 0 || gross_volume <= self.max_gross_volume.into(),
+            CloneError::VolumeCapExceeded
+        );
+
+        self.net_volume = net_volume
+            .try_into()
+            .map_err(|_| CloneError::IntTypeConversionError)?;
+        self.gross_volume = gross_volume
+            .try_into()
+            .map_err(|_| CloneError::IntTypeConversionError)?;
+        self.last_update_slot = slot;
+        Ok(())
+    }
 }
 
 #[derive(Default, Debug)]
@@ -125,6 +369,59 @@ pub struct SwapSummary {
 }
 
 impl Pool {
+    // Pools in extraction or deprecation are wound down at their settlement price.
+    pub fn is_settling(&self) -> bool {
+        self.status == Status::Extraction || self.status == Status::Deprecation
+    }
+
+    // Spreads written off ILD across the pool's remaining LPs through the pool totals,
+    // returns false when no LPs are left to absorb it.
+    pub fn socialize_ild(&mut self, onasset_ild: i64, collateral_ild: i64) -> Result<bool> {
+        if self.committed_collateral_liquidity == 0 {
+            return Ok(false);
+        }
+        self.onasset_ild = self
+            .onasset_ild
+            .checked_add(onasset_ild)
+            .ok_or(error!(CloneError::CheckedMathError))?;
+        self.collateral_ild = self
+            .collateral_ild
+            .checked_add(collateral_ild)
+            .ok_or(error!(CloneError::CheckedMathError))?;
+        Ok(true)
+    }
+
+    // Caps of zero leave the pool uncapped.
+    pub fn add_borrowed_onasset(&mut self, amount: u64) -> Result<()> {
+        self.borrowed_onasset = self
+            .borrowed_onasset
+            .checked_add(amount)
+            .ok_or(error!(CloneError::CheckedMathError))?;
+        return_error_if_false!(
+            self.debt_ceiling == 0 || self.borrowed_onasset <= self.debt_ceiling,
+            CloneError::DebtCeilingExceeded
+        );
+        Ok(())
+    }
+
+    // Positions opened before supply was tracked are not counted, so repayments saturate.
+    pub fn remove_borrowed_onasset(&mut self, amount: u64) {
+        self.borrowed_onasset = self.borrowed_onasset.saturating_sub(amount);
+    }
+
+    pub fn check_liquidity_cap(&self, collateral_amount: u64) -> Result<()> {
+        let committed_collateral_liquidity = self
+            .committed_collateral_liquidity
+            .checked_add(collateral_amount)
+            .ok_or(error!(CloneError::CheckedMathError))?;
+        return_error_if_false!(
+            self.max_committed_collateral_liquidity == 0
+                || committed_collateral_liquidity <= self.max_committed_collateral_liquidity,
+            CloneError::LiquidityCapExceeded
+        );
+        Ok(())
+    }
+
     pub fn calculate_jit_pool(
         &self,
         onasset_price: Decimal,
@@ -157,6 +454,7 @@ impl Pool {
     // This function calculate either the resultant amount received or
     // required as input into the pool depending on the `quantity` specifications.
     // Fees are calculated as well and are always of the output type of the swap.
+    #[allow(clippy::too_many_arguments)]
     pub fn calculate_swap(
         &self,
         onasset_price: Decimal,
@@ -297,6 +595,19 @@ impl Pool {
     }
 }
 
+#[derive(Clone, PartialEq, Eq, Default, Debug, AnchorDeserialize, AnchorSerialize)]
+pub enum CollateralType {
+    #[default]
+    Token,
+    Onasset,
+    // Each depository token redeems for `ratio` units of the depositing token,
+    // which is priced by the collateral's oracle.
+    DepositoryToken {
+        ratio: u64,
+        depositing_scale: u8,
+    },
+}
+
 #[derive(Clone, PartialEq, Eq, Default, Debug, AnchorDeserialize, AnchorSerialize)]
 pub struct Collateral {
     pub oracle_info_index: u8,
@@ -304,23 +615,113 @@ pub struct Collateral {
     pub vault: Pubkey,
     pub collateralization_ratio: u8,
     pub scale: u8,
+    pub deposit_cap: u64,
+    pub collateral_type: CollateralType,
+    pub min_overcollateral_ratio: u16,
+    pub max_liquidation_overcollateral_ratio: u16,
+    // Collateral held in user positions, counted against `deposit_cap`.
+    pub total_deposits: u64,
 }
 
 impl Collateral {
+    pub fn get_price(&self, oracle: &OracleInfo) -> Result<Decimal> {
+        let price = oracle.get_price()?;
+        match self.collateral_type {
+            CollateralType::DepositoryToken {
+                ratio,
+                depositing_scale,
+            } => {
+                let depositing_amount = Decimal::new(
+                    ratio
+                        .try_into()
+                        .map_err(|_| CloneError::IntTypeConversionError)?,
+                    depositing_scale.into(),
+                )
+                .checked_mul(Decimal::from(
+                    10u64
+                        .checked_pow(self.scale.into())
+                        .ok_or(error!(CloneError::CheckedMathError))?,
+                ))
+                .ok_or(error!(CloneError::CheckedMathError))?;
+                price
+                    .checked_mul(depositing_amount)
+                    .ok_or(error!(CloneError::CheckedMathError))
+            }
+            _ => Ok(price),
+        }
+    }
+
+    // Borrows backed by this collateral use its own overcollateral ratios when set,
+    // otherwise those of the borrowed pool.
+    pub fn get_overcollateral_ratios(&self, asset_info: &AssetInfo) -> (u16, u16) {
+        if self.min_overcollateral_ratio > 0 {
+            (
+                self.min_overcollateral_ratio,
+                self.max_liquidation_overcollateral_ratio,
+            )
+        } else {
+            (
+                asset_info.min_overcollateral_ratio,
+                asset_info.max_liquidation_overcollateral_ratio,
+            )
+        }
+    }
+
+    pub fn is_valid_overcollateral_ratios(&self) -> bool {
+        (self.min_overcollateral_ratio == 0 && self.max_liquidation_overcollateral_ratio == 0)
+            || (self.min_overcollateral_ratio > 100
+                && self.max_liquidation_overcollateral_ratio > self.min_overcollateral_ratio)
+    }
+
+    // A zero cap leaves deposits uncapped.
+    pub fn add_deposit(&mut self, amount: u64) -> Result<()> {
+        self.total_deposits = self
+            .total_deposits
+            .checked_add(amount)
+            .ok_or(error!(CloneError::CheckedMathError))?;
+        return_error_if_false!(
+            self.deposit_cap == 0 || self.total_deposits <= self.deposit_cap,
+            CloneError::DepositCapExceeded
+        );
+        Ok(())
+    }
+
+    pub fn remove_deposit(&mut self, amount: u64) -> Result<()> {
+        self.total_deposits = self
+            .total_deposits
+            .checked_sub(amount)
+            .ok_or(error!(CloneError::CheckedMathError))?;
+        Ok(())
+    }
+
     pub fn to_collateral_decimal<T: TryInto<i64>>(&self, value: T) -> Result<Decimal> {
         if let Ok(num) = TryInto::<i64>::try_into(value) {
-            Ok(Decimal::new(
-                num,
-                self.scale
-                    .try_into()
-                    .map_err(|_| CloneError::IntTypeConversionError)?,
-            ))
+            Ok(Decimal::new(num, self.scale.into()))
         } else {
             Err(error!(CloneError::InvalidConversion))
         }
     }
 }
 
+// Registered collateral paid in by settled positions, it is redeemed pro rata to the onasset
+// it backs so holders choosing it carry its price risk.
+#[account]
+#[derive(Default)]
+pub struct SettlementReserve {
+    pub onasset_mint: Pubkey,
+    pub collateral_index: u8,
+    pub collateral_amount: u64,
+    pub onasset_amount: u64,
+}
+
+#[account]
+#[derive(Default)]
+pub struct Referrer {
+    pub authority: Pubkey,
+    pub fee_share_bps: u16,
+    pub referred_volume: u64,
+}
+
 #[account]
 pub struct User {
     pub borrows: Vec<Borrow>,
@@ -331,22 +732,97 @@ pub struct User {
 pub struct Comet {
     pub collateral_amount: u64,
     pub positions: Vec<LiquidityPosition>,
+    pub collateral_deposits: Vec<CollateralDeposit>,
+    pub liquidation_start_slot: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Copy, Debug, AnchorDeserialize, AnchorSerialize)]
+pub struct CollateralDeposit {
+    pub collateral_index: u8,
+    pub amount: u64,
 }
 
 impl Comet {
+    // Starts the liquidation auction the first time the comet is seen liquidatable and
+    // clears it once the comet is healthy again.
+    pub fn update_liquidation_auction(&mut self, is_liquidatable: bool, slot: u64) {
+        if !is_liquidatable {
+            self.liquidation_start_slot = 0;
+        } else if self.liquidation_start_slot == 0 {
+            self.liquidation_start_slot = slot;
+        }
+    }
+
     pub fn calculate_effective_collateral_value(&self, collateral: &Collateral) -> Result<Decimal> {
-        let collateralization_ratio = collateral
-            .collateralization_ratio
-            .try_into()
-            .map_err(|_| CloneError::IntTypeConversionError)?;
+        let collateralization_ratio = collateral.collateralization_ratio.into();
         Ok(to_clone_decimal!(self
             .collateral_amount
             .checked_mul(collateralization_ratio)
             .ok_or(error!(CloneError::CheckedMathError))?))
     }
 
+    pub fn get_collateral_amount(&self, collateral_index: u8) -> u64 {
+        if collateral_index == 0 {
+            return self.collateral_amount;
+        }
+        self.collateral_deposits
+            .iter()
+            .find(|deposit| deposit.collateral_index == collateral_index)
+            .map_or(0, |deposit| deposit.amount)
+    }
+
+    pub fn add_collateral(&mut self, collateral_index: u8, amount: u64) -> Result<()> {
+        if collateral_index == 0 {
+            self.collateral_amount = self
+                .collateral_amount
+                .checked_add(amount)
+                .ok_or(error!(CloneError::CheckedMathError))?;
+        } else if let Some(deposit) = self
+            .collateral_deposits
+            .iter_mut()
+            .find(|deposit| deposit.collateral_index == collateral_index)
+        {
+            deposit.amount = deposit
+                .amount
+                .checked_add(amount)
+                .ok_or(error!(CloneError::CheckedMathError))?;
+        } else {
+            self.collateral_deposits.push(CollateralDeposit {
+                collateral_index,
+                amount,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn remove_collateral(&mut self, collateral_index: u8, amount: u64) -> Result<()> {
+        if collateral_index == 0 {
+            self.collateral_amount = self
+                .collateral_amount
+                .checked_sub(amount)
+                .ok_or(error!(CloneError::CheckedMathError))?;
+            return Ok(());
+        }
+        let position = self
+            .collateral_deposits
+            .iter()
+            .position(|deposit| deposit.collateral_index == collateral_index)
+            .ok_or(error!(CloneError::InvalidCollateralIndex))?;
+        let deposit = &mut self.collateral_deposits[position];
+        deposit.amount = deposit
+            .amount
+            .checked_sub(amount)
+            .ok_or(error!(CloneError::CheckedMathError))?;
+        if deposit.amount == 0 {
+            self.collateral_deposits.remove(position);
+        }
+        Ok(())
+    }
+
     pub fn is_empty(&self) -> bool {
-        self.positions.len() == 0 && self.collateral_amount == 0
+        self.positions.len() == 0
+            && self.collateral_amount == 0
+            && self.collateral_deposits.is_empty()
     }
 }
 
@@ -361,7 +837,7 @@ pub struct LiquidityPosition {
 impl Default for LiquidityPosition {
     fn default() -> Self {
         Self {
-            pool_index: u8::MAX.into(),
+            pool_index: u8::MAX,
             committed_collateral_liquidity: 0,
             collateral_ild_rebate: 0,
             onasset_ild_rebate: 0,
@@ -383,9 +859,46 @@ pub struct Borrow {
     pub pool_index: u8,
     pub borrowed_onasset: u64,
     pub collateral_amount: u64,
+    pub collateral_index: u8,
+    pub stability_fee_index: u64,
+    pub liquidation_start_slot: u64,
 }
 
 impl Borrow {
+    // Starts the liquidation auction the first time the position is seen liquidatable and
+    // clears it once the position is sufficiently collateralized again.
+    pub fn update_liquidation_auction(&mut self, is_liquidatable: bool, slot: u64) {
+        if !is_liquidatable {
+            self.liquidation_start_slot = 0;
+        } else if self.liquidation_start_slot == 0 {
+            self.liquidation_start_slot = slot;
+        }
+    }
+
+    // Grows the debt by the pool's stability fee index since the last snapshot,
+    // returning the onasset amount accrued. The pool's fee must be accrued first.
+    pub fn accrue_stability_fee(&mut self, stability_fee: &StabilityFee) -> Result<u64> {
+        let previous_index = self.stability_fee_index;
+        self.stability_fee_index = stability_fee.cumulative_index;
+        if previous_index == 0 || previous_index == stability_fee.cumulative_index {
+            return Ok(0);
+        }
+        let borrowed_onasset: u64 = rescale_toward_zero(
+            to_clone_decimal!(self.borrowed_onasset)
+                .checked_mul(stability_fee.get_index())
+                .ok_or(error!(CloneError::CheckedMathError))?
+                .checked_div(Decimal::new(previous_index as i64, INDEX_SCALE))
+                .ok_or(error!(CloneError::CheckedMathError))?,
+            CLONE_TOKEN_SCALE,
+        )
+        .mantissa()
+        .try_into()
+        .map_err(|_| CloneError::IntTypeConversionError)?;
+        let accrued_fee = borrowed_onasset.saturating_sub(self.borrowed_onasset);
+        self.borrowed_onasset = borrowed_onasset;
+        Ok(accrued_fee)
+    }
+
     pub fn is_empty(&self) -> bool {
         self.borrowed_onasset == 0 && self.collateral_amount == 0
     }
@@ -397,6 +910,9 @@ impl Default for Borrow {
             pool_index: u8::MAX,
             borrowed_onasset: 0,
             collateral_amount: 0,
+            collateral_index: 0,
+            stability_fee_index: 0,
+            liquidation_start_slot: 0,
         }
     }
 }
@@ -0,0 +1,381 @@
+mod common;
+
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use clone::error::CloneError;
+use clone::states::*;
+use common::*;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+fn initialize_borrow(
+    protocol: &Protocol,
+    user: &Pubkey,
+    collateral_index: u8,
+    onasset_amount: u64,
+    collateral_amount: u64,
+) -> Instruction {
+    let collateral = protocol.get_collateral(collateral_index);
+    anchor_instruction(
+        clone::accounts::InitializeBorrowPosition {
+            user: *user,
+            user_account: user_address(user),
+            clone: clone_address(),
+            pools: pools_address(),
+            oracles: oracles_address(),
+            collaterals: collaterals_address(),
+            vault: collateral.vault,
+            user_collateral_token_account: get_associated_token_address(user, &collateral.mint),
+            onasset_mint: protocol.onasset_mint,
+            user_onasset_token_account: get_associated_token_address(user, &protocol.onasset_mint),
+            token_program: spl_token::ID,
+        },
+        clone::instruction::InitializeBorrowPosition {
+            pool_index: 0,
+            onasset_amount,
+            collateral_amount,
+            collateral_index,
+        },
+    )
+}
+
+fn pay_borrow_debt(
+    protocol: &Protocol,
+    payer: &Pubkey,
+    borrow_index: u8,
+    amount: u64,
+) -> Instruction {
+    anchor_instruction(
+        clone::accounts::PayBorrowDebt {
+            payer: *payer,
+            user_account: user_address(payer),
+            clone: clone_address(),
+            pools: pools_address(),
+            payer_onasset_token_account: get_associated_token_address(
+                payer,
+                &protocol.onasset_mint,
+            ),
+            onasset_mint: protocol.onasset_mint,
+            treasury_onasset_token_account: get_associated_token_address(
+                &protocol.treasury.pubkey(),
+                &protocol.onasset_mint,
+            ),
+            token_program: spl_token::ID,
+        },
+        clone::instruction::PayBorrowDebt {
+            user: *payer,
+            borrow_index,
+            amount,
+        },
+    )
+}
+
+// A user account holding `balance` of the collateral at `collateral_index`.
+fn setup_borrower(
+    context: &mut ProgramTestContext,
+    protocol: &Protocol,
+    collateral_index: u8,
+    balance: u64,
+) -> Keypair {
+    let user = Keypair::new();
+    protocol.set_user(context, &user.pubkey(), &empty_user());
+    set_associated_token_account(
+        context,
+        &protocol.get_collateral(collateral_index).mint,
+        &user.pubkey(),
+        balance,
+    );
+    set_associated_token_account(context, &protocol.onasset_mint, &user.pubkey(), 0);
+    user
+}
+
+#[tokio::test]
+async fn registered_collateral_backs_borrows_after_its_haircut() {
+    let mut context = start().await;
+    let mut protocol = Protocol::new(&mut context, 1.0).await;
+    // 1 token is worth 2, counted at 80%, so 10 onasset at 150% needs 9.375 tokens.
+    let collateral_index = protocol.add_collateral(&mut context, 2.0, 80).await;
+    let user = setup_borrower(&mut context, &protocol, collateral_index, 1_000_000_000);
+
+    let instruction = initialize_borrow(
+        &protocol,
+        &user.pubkey(),
+        collateral_index,
+        1_000_000_000,
+        93_749_999,
+    );
+    assert_clone_error(
+        process_instructions(&mut context, &[instruction], &[&user]).await,
+        CloneError::InvalidMintCollateralRatio,
+    );
+
+    let instruction = initialize_borrow(
+        &protocol,
+        &user.pubkey(),
+        collateral_index,
+        1_000_000_000,
+        93_750_000,
+    );
+    process_instructions(&mut context, &[instruction], &[&user])
+        .await
+        .unwrap();
+
+    let borrow = protocol
+        .get_user(&mut context, &user.pubkey())
+        .await
+        .borrows[0];
+    assert_eq!(borrow.collateral_index, collateral_index);
+    assert_eq!(borrow.collateral_amount, 93_750_000);
+    assert_eq!(
+        token_balance(
+            &mut context,
+            &protocol.get_collateral(collateral_index).vault
+        )
+        .await,
+        93_750_000
+    );
+    assert_eq!(
+        token_balance(
+            &mut context,
+            &get_associated_token_address(&user.pubkey(), &protocol.onasset_mint)
+        )
+        .await,
+        1_000_000_000
+    );
+}
+
+#[tokio::test]
+async fn registered_collateral_deposits_are_capped() {
+    let mut context = start().await;
+    let mut protocol = Protocol::new(&mut context, 1.0).await;
+    let collateral_index = protocol.add_collateral(&mut context, 1.0, 100).await;
+    protocol.collaterals[collateral_index as usize - 1].deposit_cap = 250_000_000;
+    protocol.collaterals[collateral_index as usize - 1].total_deposits = 100_000_000;
+    protocol.save(&mut context).await;
+    // Tokens held by the vault outside of positions don't count against the cap.
+    protocol
+        .fund_vault(&mut context, collateral_index, 1_000_000_000)
+        .await;
+    let user = setup_borrower(&mut context, &protocol, collateral_index, 1_000_000_000);
+
+    let instruction = initialize_borrow(
+        &protocol,
+        &user.pubkey(),
+        collateral_index,
+        100_000_000,
+        150_000_001,
+    );
+    assert_clone_error(
+        process_instructions(&mut context, &[instruction], &[&user]).await,
+        CloneError::DepositCapExceeded,
+    );
+
+    let instruction = initialize_borrow(
+        &protocol,
+        &user.pubkey(),
+        collateral_index,
+        100_000_000,
+        150_000_000,
+    );
+    process_instructions(&mut context, &[instruction], &[&user])
+        .await
+        .unwrap();
+    protocol.load(&mut context).await;
+    assert_eq!(
+        protocol.get_collateral(collateral_index).total_deposits,
+        250_000_000
+    );
+}
+
+#[tokio::test]
+async fn onassets_back_borrows_of_other_pools_only() {
+    let mut context = start().await;
+    let mut protocol = Protocol::new(&mut context, 1.0).await;
+    // Another pool's onasset priced at 4, 10 onasset at 150% needs 3.75 of it.
+    let collateral_index = protocol.add_collateral(&mut context, 4.0, 100).await;
+    protocol.collaterals[collateral_index as usize - 1].collateral_type = CollateralType::Onasset;
+    // The pool's own onasset, priced by the pool's oracle.
+    let own_vault = Pubkey::new_unique();
+    set_token_account(
+        &mut context,
+        &own_vault,
+        &protocol.onasset_mint,
+        &clone_address(),
+        0,
+    );
+    protocol.collaterals.push(Collateral {
+        oracle_info_index: 1,
+        mint: protocol.onasset_mint,
+        vault: own_vault,
+        collateralization_ratio: 100,
+        scale: ONASSET_SCALE,
+        collateral_type: CollateralType::Onasset,
+        ..Collateral::default()
+    });
+    protocol.save(&mut context).await;
+    let own_collateral_index = protocol.collaterals.len() as u8;
+    let user = setup_borrower(&mut context, &protocol, collateral_index, 1_000_000_000);
+
+    let instruction = initialize_borrow(
+        &protocol,
+        &user.pubkey(),
+        own_collateral_index,
+        100_000_000,
+        1_000_000_000,
+    );
+    assert_clone_error(
+        process_instructions(&mut context, &[instruction], &[&user]).await,
+        CloneError::InvalidBorrowCollateral,
+    );
+
+    let instruction = initialize_borrow(
+        &protocol,
+        &user.pubkey(),
+        collateral_index,
+        1_000_000_000,
+        37_499_999,
+    );
+    assert_clone_error(
+        process_instructions(&mut context, &[instruction], &[&user]).await,
+        CloneError::InvalidMintCollateralRatio,
+    );
+    let instruction = initialize_borrow(
+        &protocol,
+        &user.pubkey(),
+        collateral_index,
+        1_000_000_000,
+        37_500_000,
+    );
+    process_instructions(&mut context, &[instruction], &[&user])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn depository_tokens_are_priced_through_their_ratio_and_own_ratios() {
+    let mut context = start().await;
+    let mut protocol = Protocol::new(&mut context, 1.0).await;
+    // Each depository token redeems for 2 of a depositing token priced at 1, and borrows
+    // against it need 200% instead of the pool's 150%.
+    let collateral_index = protocol.add_collateral(&mut context, 1.0, 100).await;
+    let collateral = &mut protocol.collaterals[collateral_index as usize - 1];
+    collateral.collateral_type = CollateralType::DepositoryToken {
+        ratio: 2,
+        depositing_scale: COLLATERAL_SCALE,
+    };
+    collateral.min_overcollateral_ratio = 200;
+    collateral.max_liquidation_overcollateral_ratio = 250;
+    protocol.save(&mut context).await;
+    let user = setup_borrower(&mut context, &protocol, collateral_index, 1_000_000_000);
+
+    let instruction = initialize_borrow(
+        &protocol,
+        &user.pubkey(),
+        collateral_index,
+        1_000_000_000,
+        99_999_999,
+    );
+    assert_clone_error(
+        process_instructions(&mut context, &[instruction], &[&user]).await,
+        CloneError::InvalidMintCollateralRatio,
+    );
+    let instruction = initialize_borrow(
+        &protocol,
+        &user.pubkey(),
+        collateral_index,
+        1_000_000_000,
+        100_000_000,
+    );
+    process_instructions(&mut context, &[instruction], &[&user])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn stability_fee_accrues_to_the_treasury_on_repayment() {
+    let mut context = start().await;
+    let mut protocol = Protocol::new(&mut context, 1.0).await;
+    // Half a year at 10% grows the index, and so the debt, by 5%.
+    let now = current_timestamp(&mut context).await;
+    protocol.pools[0].stability_fee = StabilityFee {
+        annual_rate_bps: 1000,
+        cumulative_index: 1_000_000_000_000,
+        last_update_timestamp: now - SECONDS_PER_YEAR / 2,
+    };
+    protocol.pools[0].borrowed_onasset = 1_000_000_000;
+    protocol.save(&mut context).await;
+    let user = Keypair::new();
+    let mut user_account = empty_user();
+    user_account.borrows.push(Borrow {
+        pool_index: 0,
+        borrowed_onasset: 1_000_000_000,
+        collateral_amount: 2_000_000_000,
+        collateral_index: 0,
+        stability_fee_index: 1_000_000_000_000,
+        liquidation_start_slot: 0,
+    });
+    protocol.set_user(&mut context, &user.pubkey(), &user_account);
+    protocol.fund_vault(&mut context, 0, 2_000_000_000).await;
+    set_mint(
+        &mut context,
+        &protocol.onasset_mint,
+        &clone_address(),
+        ONASSET_SCALE,
+        1_000_000_000,
+    );
+    set_associated_token_account(
+        &mut context,
+        &protocol.onasset_mint,
+        &user.pubkey(),
+        1_000_000_000,
+    );
+
+    let instruction = pay_borrow_debt(&protocol, &user.pubkey(), 0, 100_000_000);
+    process_instructions(&mut context, &[instruction], &[&user])
+        .await
+        .unwrap();
+
+    let borrow = protocol
+        .get_user(&mut context, &user.pubkey())
+        .await
+        .borrows[0];
+    assert_eq!(borrow.borrowed_onasset, 950_000_000);
+    assert_eq!(borrow.stability_fee_index, 1_050_000_000_000);
+    assert_eq!(
+        token_balance(
+            &mut context,
+            &get_associated_token_address(&protocol.treasury.pubkey(), &protocol.onasset_mint)
+        )
+        .await,
+        50_000_000
+    );
+    protocol.load(&mut context).await;
+    assert_eq!(protocol.pools[0].borrowed_onasset, 950_000_000);
+}
+
+#[tokio::test]
+async fn borrows_are_capped_by_the_pool_debt_ceiling() {
+    let mut context = start().await;
+    let mut protocol = Protocol::new(&mut context, 1.0).await;
+    protocol.pools[0].borrowed_onasset = 600_000_000;
+    protocol.pools[0].debt_ceiling = 1_000_000_000;
+    protocol.save(&mut context).await;
+    let user = setup_borrower(&mut context, &protocol, 0, 1_000_000_000);
+
+    let instruction = initialize_borrow(&protocol, &user.pubkey(), 0, 400_000_001, 1_000_000_000);
+    assert_clone_error(
+        process_instructions(&mut context, &[instruction], &[&user]).await,
+        CloneError::DebtCeilingExceeded,
+    );
+    let instruction = initialize_borrow(&protocol, &user.pubkey(), 0, 400_000_000, 1_000_000_000);
+    process_instructions(&mut context, &[instruction], &[&user])
+        .await
+        .unwrap();
+
+    protocol.load(&mut context).await;
+    assert_eq!(protocol.pools[0].borrowed_onasset, 1_000_000_000);
+}
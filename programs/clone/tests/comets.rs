@@ -0,0 +1,66 @@
+mod common;
+
+use clone::error::CloneError;
+use clone::states::*;
+use common::*;
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+fn add_liquidity_to_comet(user: &Pubkey, pool_index: u8, collateral_amount: u64) -> Instruction {
+    anchor_instruction(
+        clone::accounts::AddLiquidityToComet {
+            user: *user,
+            user_account: user_address(user),
+            clone: clone_address(),
+            pools: pools_address(),
+            oracles: oracles_address(),
+            collaterals: collaterals_address(),
+        },
+        clone::instruction::AddLiquidityToComet {
+            pool_index,
+            collateral_amount,
+        },
+    )
+}
+
+#[tokio::test]
+async fn comet_liquidity_is_capped_per_pool() {
+    let mut context = start().await;
+    let mut protocol = Protocol::new(&mut context, 1.0).await;
+    protocol.pools[0].committed_collateral_liquidity = 1_000_000_000;
+    protocol.pools[0].max_committed_collateral_liquidity = 1_500_000_000;
+    protocol.pools[0]
+        .asset_info
+        .position_health_score_coefficient = 10;
+    protocol.save(&mut context).await;
+    protocol.fund_vault(&mut context, 0, 1_000_000_000).await;
+    let user = Keypair::new();
+    let mut user_account = empty_user();
+    user_account
+        .comet
+        .collateral_deposits
+        .push(CollateralDeposit {
+            collateral_index: 0,
+            amount: 1_000_000_000,
+        });
+    protocol.set_user(&mut context, &user.pubkey(), &user_account);
+
+    let instruction = add_liquidity_to_comet(&user.pubkey(), 0, 500_000_001);
+    assert_clone_error(
+        process_instructions(&mut context, &[instruction], &[&user]).await,
+        CloneError::LiquidityCapExceeded,
+    );
+    let instruction = add_liquidity_to_comet(&user.pubkey(), 0, 500_000_000);
+    process_instructions(&mut context, &[instruction], &[&user])
+        .await
+        .unwrap();
+
+    protocol.load(&mut context).await;
+    assert_eq!(
+        protocol.pools[0].committed_collateral_liquidity,
+        1_500_000_000
+    );
+}
@@ -0,0 +1,481 @@
+/*
+    Shared ProgramTest fixtures for the clone program tests.
+    The program runs natively through `processor!`, so no BPF build is needed. Protocol state
+    is written straight into the accounts instead of going through the admin and oracle
+    instructions, which lets each test start from the exact positions it exercises.
+*/
+#![allow(dead_code)]
+
+use anchor_lang::prelude::AccountInfo;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::{AccountDeserialize, AccountSerialize, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token::{
+    self,
+    state::{Account as TokenAccount, AccountState, Mint},
+};
+use clone::instructions::{
+    CLONE_PROGRAM_SEED, COLLATERALS_SEED, ORACLES_SEED, POOLS_SEED, USER_SEED,
+};
+use clone::states::*;
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    clock::Clock,
+    instruction::{Instruction, InstructionError},
+    program_option::COption,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+
+pub const COLLATERAL_SCALE: u8 = 7;
+pub const ONASSET_SCALE: u8 = 8;
+pub const PRICE_EXPO: u8 = 8;
+// Anchor accounts are seeded with room to grow, as the init instructions allocate.
+pub const ACCOUNT_SPACE: usize = 10240;
+
+fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // The generated entrypoint ties the account infos to their lifetime parameter.
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    clone::entry(program_id, accounts, data)
+}
+
+pub fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &clone::ID).0
+}
+
+pub fn clone_address() -> Pubkey {
+    pda(&[CLONE_PROGRAM_SEED.as_ref()])
+}
+
+pub fn pools_address() -> Pubkey {
+    pda(&[POOLS_SEED.as_ref()])
+}
+
+pub fn oracles_address() -> Pubkey {
+    pda(&[ORACLES_SEED.as_ref()])
+}
+
+pub fn collaterals_address() -> Pubkey {
+    pda(&[COLLATERALS_SEED.as_ref()])
+}
+
+pub fn user_address(authority: &Pubkey) -> Pubkey {
+    pda(&[USER_SEED.as_ref(), authority.as_ref()])
+}
+
+pub fn anchor_instruction(
+    accounts: impl ToAccountMetas,
+    args: impl InstructionData,
+) -> Instruction {
+    Instruction {
+        program_id: clone::ID,
+        accounts: accounts.to_account_metas(None),
+        data: args.data(),
+    }
+}
+
+pub async fn process_instructions(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let recent_blockhash = context.banks_client.get_latest_blockhash().await?;
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend(signers);
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        recent_blockhash,
+    );
+    context.banks_client.process_transaction(transaction).await
+}
+
+pub fn assert_clone_error(result: Result<(), BanksClientError>, error: clone::error::CloneError) {
+    let expected = TransactionError::InstructionError(0, InstructionError::Custom(error.into()));
+    match result {
+        Err(err) => assert_eq!(err.unwrap(), expected),
+        Ok(()) => panic!("expected {:?}", expected),
+    }
+}
+
+pub async fn current_slot(context: &mut ProgramTestContext) -> u64 {
+    context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .expect("clock")
+        .slot
+}
+
+pub async fn current_timestamp(context: &mut ProgramTestContext) -> i64 {
+    context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .expect("clock")
+        .unix_timestamp
+}
+
+pub fn set_raw_account(
+    context: &mut ProgramTestContext,
+    address: &Pubkey,
+    owner: &Pubkey,
+    data: Vec<u8>,
+) {
+    let account = Account {
+        lamports: 1_000_000_000,
+        data,
+        owner: *owner,
+        executable: false,
+        rent_epoch: 0,
+    };
+    context.set_account(address, &AccountSharedData::from(account));
+}
+
+pub fn set_anchor_account<T: AccountSerialize>(
+    context: &mut ProgramTestContext,
+    address: &Pubkey,
+    state: &T,
+) {
+    let mut data = Vec::new();
+    state.try_serialize(&mut data).expect("serialize");
+    data.resize(data.len().max(ACCOUNT_SPACE), 0);
+    set_raw_account(context, address, &clone::ID, data);
+}
+
+pub async fn get_anchor_account<T: AccountDeserialize>(
+    context: &mut ProgramTestContext,
+    address: &Pubkey,
+) -> T {
+    let account = context
+        .banks_client
+        .get_account(*address)
+        .await
+        .expect("banks client")
+        .expect("account exists");
+    T::try_deserialize(&mut account.data.as_slice()).expect("deserialize")
+}
+
+pub fn set_mint(
+    context: &mut ProgramTestContext,
+    address: &Pubkey,
+    authority: &Pubkey,
+    decimals: u8,
+    supply: u64,
+) {
+    let mint = Mint {
+        mint_authority: COption::Some(*authority),
+        supply,
+        decimals,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    };
+    let mut data = vec![0; Mint::LEN];
+    mint.pack_into_slice(&mut data);
+    set_raw_account(context, address, &spl_token::ID, data);
+}
+
+pub fn set_token_account(
+    context: &mut ProgramTestContext,
+    address: &Pubkey,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+) {
+    let token_account = TokenAccount {
+        mint: *mint,
+        owner: *owner,
+        amount,
+        state: AccountState::Initialized,
+        ..TokenAccount::default()
+    };
+    let mut data = vec![0; TokenAccount::LEN];
+    token_account.pack_into_slice(&mut data);
+    set_raw_account(context, address, &spl_token::ID, data);
+}
+
+// Creates the owner's associated token account, the supply of the mint is left untouched.
+pub fn set_associated_token_account(
+    context: &mut ProgramTestContext,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+) -> Pubkey {
+    let address = get_associated_token_address(owner, mint);
+    set_token_account(context, &address, mint, owner, amount);
+    address
+}
+
+pub async fn token_balance(context: &mut ProgramTestContext, address: &Pubkey) -> u64 {
+    let account = context
+        .banks_client
+        .get_account(*address)
+        .await
+        .expect("banks client")
+        .expect("token account exists");
+    TokenAccount::unpack(&account.data)
+        .expect("token account")
+        .amount
+}
+
+pub async fn mint_supply(context: &mut ProgramTestContext, address: &Pubkey) -> u64 {
+    let account = context
+        .banks_client
+        .get_account(*address)
+        .await
+        .expect("banks client")
+        .expect("mint exists");
+    Mint::unpack(&account.data).expect("mint").supply
+}
+
+// A protocol with a base collateral priced at 1 and a single active pool.
+pub struct Protocol {
+    pub admin: Keypair,
+    pub treasury: Keypair,
+    pub collateral_mint: Pubkey,
+    pub vault: Pubkey,
+    pub onasset_mint: Pubkey,
+    pub underlying_mint: Pubkey,
+    pub underlying_asset_token_account: Pubkey,
+    pub clone: Clone,
+    pub pools: Vec<Pool>,
+    pub oracles: Vec<OracleInfo>,
+    pub collaterals: Vec<Collateral>,
+}
+
+pub fn to_price(price: f64) -> i64 {
+    (price * 10f64.powi(PRICE_EXPO.into())).round() as i64
+}
+
+pub fn oracle(price: f64) -> OracleInfo {
+    OracleInfo {
+        price: to_price(price),
+        expo: PRICE_EXPO,
+        status: Status::Active,
+        ..OracleInfo::default()
+    }
+}
+
+impl Protocol {
+    pub async fn new(context: &mut ProgramTestContext, onasset_price: f64) -> Protocol {
+        let admin = Keypair::new();
+        let treasury = Keypair::new();
+        let clone_address = clone_address();
+        let collateral_mint = Pubkey::new_unique();
+        let vault = Pubkey::new_unique();
+        let onasset_mint = Pubkey::new_unique();
+        let underlying_mint = Pubkey::new_unique();
+        let underlying_asset_token_account = Pubkey::new_unique();
+        set_mint(
+            context,
+            &collateral_mint,
+            &admin.pubkey(),
+            COLLATERAL_SCALE,
+            0,
+        );
+        set_token_account(context, &vault, &collateral_mint, &clone_address, 0);
+        set_mint(context, &onasset_mint, &clone_address, ONASSET_SCALE, 0);
+        set_mint(context, &underlying_mint, &admin.pubkey(), ONASSET_SCALE, 0);
+        set_token_account(
+            context,
+            &underlying_asset_token_account,
+            &underlying_mint,
+            &clone_address,
+            0,
+        );
+        set_associated_token_account(context, &collateral_mint, &treasury.pubkey(), 0);
+        set_associated_token_account(context, &onasset_mint, &treasury.pubkey(), 0);
+
+        let bump = Pubkey::find_program_address(&[CLONE_PROGRAM_SEED.as_ref()], &clone::ID).1;
+        let clone = Clone {
+            admin: admin.pubkey(),
+            bump,
+            collateral: Collateral {
+                oracle_info_index: 0,
+                mint: collateral_mint,
+                vault,
+                collateralization_ratio: 100,
+                scale: COLLATERAL_SCALE,
+                ..Collateral::default()
+            },
+            comet_collateral_ild_liquidator_fee_bps: 500,
+            comet_onasset_ild_liquidator_fee_bps: 500,
+            borrow_liquidator_fee_bps: 500,
+            treasury_address: treasury.pubkey(),
+            non_auth_liquidations_enabled: true,
+            ..Clone::default()
+        };
+        let pool = Pool {
+            underlying_asset_token_account,
+            treasury_trading_fee_bps: 50,
+            liquidity_trading_fee_bps: 50,
+            asset_info: AssetInfo {
+                onasset_mint,
+                oracle_info_index: 1,
+                il_health_score_coefficient: 10000,
+                position_health_score_coefficient: 10000,
+                min_overcollateral_ratio: 150,
+                max_liquidation_overcollateral_ratio: 200,
+            },
+            status: Status::Active,
+            ..Pool::default()
+        };
+        let mut protocol = Protocol {
+            admin,
+            treasury,
+            collateral_mint,
+            vault,
+            onasset_mint,
+            underlying_mint,
+            underlying_asset_token_account,
+            clone,
+            pools: vec![pool],
+            oracles: vec![oracle(1.0), oracle(onasset_price)],
+            collaterals: vec![],
+        };
+        protocol.save(context).await;
+        protocol
+    }
+
+    // Writes the protocol state with every oracle updated in the current slot.
+    pub async fn save(&mut self, context: &mut ProgramTestContext) {
+        let slot = current_slot(context).await;
+        for oracle in self.oracles.iter_mut() {
+            oracle.last_update_slot = slot;
+        }
+        set_anchor_account(context, &clone_address(), &self.clone);
+        set_anchor_account(
+            context,
+            &pools_address(),
+            &Pools {
+                pools: self.pools.clone(),
+            },
+        );
+        set_anchor_account(
+            context,
+            &oracles_address(),
+            &Oracles {
+                oracles: self.oracles.clone(),
+            },
+        );
+        set_anchor_account(
+            context,
+            &collaterals_address(),
+            &Collaterals {
+                collaterals: self.collaterals.clone(),
+            },
+        );
+    }
+
+    // Reloads the protocol state written by the program.
+    pub async fn load(&mut self, context: &mut ProgramTestContext) {
+        self.clone = get_anchor_account::<Clone>(context, &clone_address()).await;
+        self.pools = get_anchor_account::<Pools>(context, &pools_address())
+            .await
+            .pools;
+        self.oracles = get_anchor_account::<Oracles>(context, &oracles_address())
+            .await
+            .oracles;
+        self.collaterals = get_anchor_account::<Collaterals>(context, &collaterals_address())
+            .await
+            .collaterals;
+    }
+
+    // Moves the clock to a later slot and refreshes the oracles there. The clock is set
+    // directly, warping the bank would trip its capitalization check over seeded accounts.
+    pub async fn warp(&mut self, context: &mut ProgramTestContext, slots: u64) {
+        let mut clock = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .expect("clock");
+        clock.slot += slots;
+        context.set_sysvar(&clock);
+        context.get_new_latest_blockhash().await.expect("blockhash");
+        self.load(context).await;
+        self.save(context).await;
+    }
+
+    // Registers a token collateral with its own oracle and vault, returning its index.
+    pub async fn add_collateral(
+        &mut self,
+        context: &mut ProgramTestContext,
+        price: f64,
+        collateralization_ratio: u8,
+    ) -> u8 {
+        let mint = Pubkey::new_unique();
+        let vault = Pubkey::new_unique();
+        set_mint(context, &mint, &self.admin.pubkey(), COLLATERAL_SCALE, 0);
+        set_token_account(context, &vault, &mint, &clone_address(), 0);
+        set_associated_token_account(context, &mint, &self.treasury.pubkey(), 0);
+        self.oracles.push(oracle(price));
+        self.collaterals.push(Collateral {
+            oracle_info_index: (self.oracles.len() - 1) as u8,
+            mint,
+            vault,
+            collateralization_ratio,
+            scale: COLLATERAL_SCALE,
+            ..Collateral::default()
+        });
+        self.save(context).await;
+        self.collaterals.len() as u8
+    }
+
+    pub fn get_collateral(&self, collateral_index: u8) -> &Collateral {
+        if collateral_index == 0 {
+            &self.clone.collateral
+        } else {
+            &self.collaterals[collateral_index as usize - 1]
+        }
+    }
+
+    // Funds a collateral vault as deposits into positions would.
+    pub async fn fund_vault(
+        &self,
+        context: &mut ProgramTestContext,
+        collateral_index: u8,
+        amount: u64,
+    ) {
+        let collateral = self.get_collateral(collateral_index);
+        let (mint, vault) = (collateral.mint, collateral.vault);
+        let balance = token_balance(context, &vault).await;
+        set_token_account(context, &vault, &mint, &clone_address(), balance + amount);
+    }
+
+    pub fn set_user(&self, context: &mut ProgramTestContext, authority: &Pubkey, user: &User) {
+        set_anchor_account(context, &user_address(authority), user);
+    }
+
+    pub async fn get_user(&self, context: &mut ProgramTestContext, authority: &Pubkey) -> User {
+        get_anchor_account::<User>(context, &user_address(authority)).await
+    }
+}
+
+pub fn empty_user() -> User {
+    User {
+        borrows: vec![],
+        comet: Comet {
+            collateral_amount: 0,
+            positions: vec![],
+            collateral_deposits: vec![],
+            liquidation_start_slot: 0,
+        },
+    }
+}
+
+pub async fn start() -> ProgramTestContext {
+    let mut program_test = ProgramTest::new("clone", clone::ID, processor!(process_instruction));
+    program_test.prefer_bpf(false);
+    let mut context = program_test.start_with_context().await;
+    // Tests backdate oracle publish slots, so they start past slot 0.
+    context.warp_to_slot(10).expect("warp");
+    context
+}
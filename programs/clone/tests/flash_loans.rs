@@ -0,0 +1,217 @@
+mod common;
+
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use clone::error::CloneError;
+use clone::instructions::FlashLoanAsset;
+use common::*;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    sysvar,
+};
+
+// The optional onasset mint and collateral vault, and the mint of the loaned asset.
+fn flash_loan_accounts(
+    protocol: &Protocol,
+    asset: FlashLoanAsset,
+) -> (Option<Pubkey>, Option<Pubkey>, Pubkey) {
+    match asset {
+        FlashLoanAsset::Onasset { .. } => {
+            (Some(protocol.onasset_mint), None, protocol.onasset_mint)
+        }
+        FlashLoanAsset::Collateral => (None, Some(protocol.vault), protocol.collateral_mint),
+    }
+}
+
+fn flash_borrow(
+    protocol: &Protocol,
+    user: &Pubkey,
+    asset: FlashLoanAsset,
+    amount: u64,
+) -> Instruction {
+    let (onasset_mint, collateral_vault, mint) = flash_loan_accounts(protocol, asset);
+    anchor_instruction(
+        clone::accounts::FlashBorrow {
+            user: *user,
+            clone: clone_address(),
+            pools: pools_address(),
+            onasset_mint,
+            collateral_vault,
+            user_token_account: get_associated_token_address(user, &mint),
+            instructions_sysvar: sysvar::instructions::ID,
+            token_program: spl_token::ID,
+        },
+        clone::instruction::FlashBorrow { asset, amount },
+    )
+}
+
+fn flash_repay(
+    protocol: &Protocol,
+    user: &Pubkey,
+    asset: FlashLoanAsset,
+    amount: u64,
+) -> Instruction {
+    let (onasset_mint, collateral_vault, mint) = flash_loan_accounts(protocol, asset);
+    anchor_instruction(
+        clone::accounts::FlashRepay {
+            user: *user,
+            clone: clone_address(),
+            pools: pools_address(),
+            onasset_mint,
+            collateral_vault,
+            user_token_account: get_associated_token_address(user, &mint),
+            treasury_token_account: get_associated_token_address(
+                &protocol.treasury.pubkey(),
+                &mint,
+            ),
+            instructions_sysvar: sysvar::instructions::ID,
+            token_program: spl_token::ID,
+        },
+        clone::instruction::FlashRepay { asset, amount },
+    )
+}
+
+// A protocol charging 0.3% on flash loans, with a funded vault and a user holding
+// `fee_balance` of each asset to pay the fees with.
+async fn setup_flash_loans(
+    context: &mut ProgramTestContext,
+    fee_balance: u64,
+) -> (Protocol, Keypair) {
+    let mut protocol = Protocol::new(context, 1.0).await;
+    protocol.clone.flash_loan_fee_bps = 30;
+    protocol.save(context).await;
+    protocol.fund_vault(context, 0, 1_000_000_000).await;
+    let user = Keypair::new();
+    set_associated_token_account(
+        context,
+        &protocol.collateral_mint,
+        &user.pubkey(),
+        fee_balance,
+    );
+    set_associated_token_account(context, &protocol.onasset_mint, &user.pubkey(), fee_balance);
+    set_mint(
+        context,
+        &protocol.onasset_mint,
+        &clone_address(),
+        ONASSET_SCALE,
+        fee_balance,
+    );
+    (protocol, user)
+}
+
+#[tokio::test]
+async fn collateral_flash_loan_returns_to_the_vault_and_pays_the_treasury() {
+    let mut context = start().await;
+    let (protocol, user) = setup_flash_loans(&mut context, 10_000_000).await;
+    let asset = FlashLoanAsset::Collateral;
+
+    process_instructions(
+        &mut context,
+        &[
+            flash_borrow(&protocol, &user.pubkey(), asset, 1_000_000_000),
+            flash_repay(&protocol, &user.pubkey(), asset, 1_000_000_000),
+        ],
+        &[&user],
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        token_balance(&mut context, &protocol.vault).await,
+        1_000_000_000
+    );
+    assert_eq!(
+        token_balance(
+            &mut context,
+            &get_associated_token_address(&user.pubkey(), &protocol.collateral_mint),
+        )
+        .await,
+        7_000_000
+    );
+    assert_eq!(
+        token_balance(
+            &mut context,
+            &get_associated_token_address(&protocol.treasury.pubkey(), &protocol.collateral_mint),
+        )
+        .await,
+        3_000_000
+    );
+}
+
+#[tokio::test]
+async fn onasset_flash_mint_is_burned_on_repayment() {
+    let mut context = start().await;
+    let (protocol, user) = setup_flash_loans(&mut context, 10_000_000).await;
+    let asset = FlashLoanAsset::Onasset { pool_index: 0 };
+
+    process_instructions(
+        &mut context,
+        &[
+            flash_borrow(&protocol, &user.pubkey(), asset, 1_000_000_000),
+            flash_repay(&protocol, &user.pubkey(), asset, 1_000_000_000),
+        ],
+        &[&user],
+    )
+    .await
+    .unwrap();
+
+    // Only the fee moves, from the user to the treasury, the flash minted supply is gone.
+    assert_eq!(
+        mint_supply(&mut context, &protocol.onasset_mint).await,
+        10_000_000
+    );
+    assert_eq!(
+        token_balance(
+            &mut context,
+            &get_associated_token_address(&protocol.treasury.pubkey(), &protocol.onasset_mint),
+        )
+        .await,
+        3_000_000
+    );
+}
+
+#[tokio::test]
+async fn flash_loans_need_a_matching_repayment() {
+    let mut context = start().await;
+    let (protocol, user) = setup_flash_loans(&mut context, 10_000_000).await;
+    let asset = FlashLoanAsset::Collateral;
+
+    assert_clone_error(
+        process_instructions(
+            &mut context,
+            &[flash_borrow(
+                &protocol,
+                &user.pubkey(),
+                asset,
+                1_000_000_000,
+            )],
+            &[&user],
+        )
+        .await,
+        CloneError::FlashLoanRepayNotFound,
+    );
+    assert_clone_error(
+        process_instructions(
+            &mut context,
+            &[
+                flash_borrow(&protocol, &user.pubkey(), asset, 1_000_000_000),
+                flash_repay(&protocol, &user.pubkey(), asset, 999_999_999),
+            ],
+            &[&user],
+        )
+        .await,
+        CloneError::FlashLoanRepayNotFound,
+    );
+    assert_clone_error(
+        process_instructions(
+            &mut context,
+            &[flash_repay(&protocol, &user.pubkey(), asset, 1_000_000_000)],
+            &[&user],
+        )
+        .await,
+        CloneError::FlashLoanBorrowNotFound,
+    );
+}
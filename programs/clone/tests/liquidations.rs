@@ -0,0 +1,634 @@
+mod common;
+
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use clone::error::CloneError;
+use clone::states::*;
+use common::*;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+// A comet holding only a registered collateral deposit, its single position unhealthy
+// through the pool's ILD. The insurance fund is empty.
+async fn setup_deposit_comet(
+    context: &mut ProgramTestContext,
+    onasset_ild: i64,
+    collateral_ild: i64,
+    deposit_amount: u64,
+) -> (Protocol, Pubkey, u8) {
+    let mut protocol = Protocol::new(context, 1.0).await;
+    protocol.pools[0].committed_collateral_liquidity = 1_000_000_000;
+    protocol.pools[0].onasset_ild = onasset_ild;
+    protocol.pools[0].collateral_ild = collateral_ild;
+    protocol.pools[0].asset_info.il_health_score_coefficient = 100;
+    protocol.pools[0]
+        .asset_info
+        .position_health_score_coefficient = 10;
+    let deposit_index = protocol.add_collateral(context, 1.0, 100).await;
+    protocol.collaterals[deposit_index as usize - 1].total_deposits = deposit_amount;
+    protocol.save(context).await;
+    protocol
+        .fund_vault(context, deposit_index, deposit_amount)
+        .await;
+
+    let user = Pubkey::new_unique();
+    let mut user_account = empty_user();
+    user_account.comet.positions.push(LiquidityPosition {
+        pool_index: 0,
+        committed_collateral_liquidity: 1_000_000_000,
+        collateral_ild_rebate: 0,
+        onasset_ild_rebate: 0,
+    });
+    user_account
+        .comet
+        .collateral_deposits
+        .push(CollateralDeposit {
+            collateral_index: deposit_index,
+            amount: deposit_amount,
+        });
+    protocol.set_user(context, &user, &user_account);
+    (protocol, user, deposit_index)
+}
+
+#[tokio::test]
+async fn onasset_ild_liquidation_seizes_registered_deposit() {
+    let mut context = start().await;
+    let (mut protocol, user, deposit_index) =
+        setup_deposit_comet(&mut context, 5_000_000_000, 0, 400_000_000).await;
+    let deposit = protocol.get_collateral(deposit_index).clone();
+    let liquidator = Keypair::new();
+    set_mint(
+        &mut context,
+        &protocol.onasset_mint,
+        &clone_address(),
+        ONASSET_SCALE,
+        1_000_000_000,
+    );
+    let liquidator_onasset_token_account = set_associated_token_account(
+        &mut context,
+        &protocol.onasset_mint,
+        &liquidator.pubkey(),
+        1_000_000_000,
+    );
+    let liquidator_collateral_token_account = set_associated_token_account(
+        &mut context,
+        &protocol.collateral_mint,
+        &liquidator.pubkey(),
+        0,
+    );
+    let liquidator_deposit_token_account =
+        set_associated_token_account(&mut context, &deposit.mint, &liquidator.pubkey(), 0);
+
+    let instruction = anchor_instruction(
+        clone::accounts::LiquidateCometOnassetIld {
+            liquidator: liquidator.pubkey(),
+            user_account: user_address(&user),
+            clone: clone_address(),
+            pools: pools_address(),
+            oracles: oracles_address(),
+            collaterals: collaterals_address(),
+            onasset_mint: protocol.onasset_mint,
+            liquidator_onasset_token_account,
+            liquidator_collateral_token_account,
+            treasury_onasset_token_account: get_associated_token_address(
+                &protocol.treasury.pubkey(),
+                &protocol.onasset_mint,
+            ),
+            vault: protocol.vault,
+            deposit_vault: Some(deposit.vault),
+            liquidator_deposit_token_account: Some(liquidator_deposit_token_account),
+            token_program: spl_token::ID,
+        },
+        clone::instruction::LiquidateCometOnassetIld {
+            user,
+            comet_position_index: 0,
+            amount: 1_000_000_000,
+            flash: false,
+        },
+    );
+    process_instructions(&mut context, &[instruction], &[&liquidator])
+        .await
+        .unwrap();
+
+    // Burning 10 onasset at price 1 with the 5% fee is rewarded 10.5 of the deposit.
+    assert_eq!(
+        token_balance(&mut context, &liquidator_onasset_token_account).await,
+        0
+    );
+    assert_eq!(mint_supply(&mut context, &protocol.onasset_mint).await, 0);
+    assert_eq!(
+        token_balance(&mut context, &liquidator_deposit_token_account).await,
+        105_000_000
+    );
+    assert_eq!(
+        token_balance(&mut context, &deposit.vault).await,
+        295_000_000
+    );
+    assert_eq!(
+        token_balance(&mut context, &liquidator_collateral_token_account).await,
+        0
+    );
+    let user_account = protocol.get_user(&mut context, &user).await;
+    assert_eq!(
+        user_account.comet.get_collateral_amount(deposit_index),
+        295_000_000
+    );
+    protocol.load(&mut context).await;
+    assert_eq!(
+        protocol.get_collateral(deposit_index).total_deposits,
+        295_000_000
+    );
+}
+
+#[tokio::test]
+async fn collateral_ild_liquidation_seizes_registered_deposit() {
+    let mut context = start().await;
+    let (protocol, user, deposit_index) =
+        setup_deposit_comet(&mut context, 0, 500_000_000, 550_000_000).await;
+    let deposit = protocol.get_collateral(deposit_index).clone();
+    let liquidator = Keypair::new();
+    let liquidator_collateral_token_account = set_associated_token_account(
+        &mut context,
+        &protocol.collateral_mint,
+        &liquidator.pubkey(),
+        1_000_000_000,
+    );
+    let liquidator_deposit_token_account =
+        set_associated_token_account(&mut context, &deposit.mint, &liquidator.pubkey(), 0);
+
+    let instruction = anchor_instruction(
+        clone::accounts::LiquidateCometCollateralIld {
+            liquidator: liquidator.pubkey(),
+            user_account: user_address(&user),
+            clone: clone_address(),
+            pools: pools_address(),
+            oracles: oracles_address(),
+            collaterals: collaterals_address(),
+            collateral_mint: protocol.collateral_mint,
+            liquidator_collateral_token_account,
+            vault: protocol.vault,
+            deposit_vault: Some(deposit.vault),
+            liquidator_deposit_token_account: Some(liquidator_deposit_token_account),
+            token_program: spl_token::ID,
+        },
+        clone::instruction::LiquidateCometCollateralIld {
+            user,
+            comet_position_index: 0,
+        },
+    );
+    process_instructions(&mut context, &[instruction], &[&liquidator])
+        .await
+        .unwrap();
+
+    // The deposit covers the 2.5 reward and the 50 ILD, the liquidator buys the part covering
+    // the ILD with base collateral that goes to the pool's vault.
+    assert_eq!(
+        token_balance(&mut context, &liquidator_deposit_token_account).await,
+        525_000_000
+    );
+    assert_eq!(
+        token_balance(&mut context, &liquidator_collateral_token_account).await,
+        500_000_000
+    );
+    assert_eq!(
+        token_balance(&mut context, &protocol.vault).await,
+        500_000_000
+    );
+    let user_account = protocol.get_user(&mut context, &user).await;
+    assert_eq!(
+        user_account.comet.get_collateral_amount(deposit_index),
+        25_000_000
+    );
+}
+
+// A base collateral borrow of 10 onasset at price 1 backed by `collateral_amount`, with the
+// liquidator holding the onasset to repay it.
+async fn setup_borrow(
+    context: &mut ProgramTestContext,
+    protocol: &mut Protocol,
+    collateral_amount: u64,
+) -> (Pubkey, Keypair) {
+    let user = Pubkey::new_unique();
+    let mut user_account = empty_user();
+    user_account.borrows.push(Borrow {
+        pool_index: 0,
+        borrowed_onasset: 1_000_000_000,
+        collateral_amount,
+        collateral_index: 0,
+        stability_fee_index: 0,
+        liquidation_start_slot: 0,
+    });
+    protocol.pools[0].borrowed_onasset = 1_000_000_000;
+    protocol.save(context).await;
+    protocol.set_user(context, &user, &user_account);
+    protocol.fund_vault(context, 0, collateral_amount).await;
+
+    let liquidator = Keypair::new();
+    set_mint(
+        context,
+        &protocol.onasset_mint,
+        &clone_address(),
+        ONASSET_SCALE,
+        1_000_000_000,
+    );
+    set_associated_token_account(
+        context,
+        &protocol.onasset_mint,
+        &liquidator.pubkey(),
+        1_000_000_000,
+    );
+    set_associated_token_account(context, &protocol.collateral_mint, &liquidator.pubkey(), 0);
+    (user, liquidator)
+}
+
+fn liquidate_borrow_instruction(
+    protocol: &Protocol,
+    user: &Pubkey,
+    liquidator: &Keypair,
+    amount: u64,
+    flash: bool,
+) -> Instruction {
+    anchor_instruction(
+        clone::accounts::LiquidateBorrowPosition {
+            liquidator: liquidator.pubkey(),
+            clone: clone_address(),
+            pools: pools_address(),
+            oracles: oracles_address(),
+            collaterals: collaterals_address(),
+            user_account: user_address(user),
+            onasset_mint: protocol.onasset_mint,
+            vault: protocol.vault,
+            liquidator_collateral_token_account: get_associated_token_address(
+                &liquidator.pubkey(),
+                &protocol.collateral_mint,
+            ),
+            treasury_collateral_token_account: get_associated_token_address(
+                &protocol.treasury.pubkey(),
+                &protocol.collateral_mint,
+            ),
+            liquidator_onasset_token_account: get_associated_token_address(
+                &liquidator.pubkey(),
+                &protocol.onasset_mint,
+            ),
+            treasury_onasset_token_account: get_associated_token_address(
+                &protocol.treasury.pubkey(),
+                &protocol.onasset_mint,
+            ),
+            token_program: spl_token::ID,
+        },
+        clone::instruction::LiquidateBorrowPosition {
+            user: *user,
+            borrow_index: 0,
+            amount,
+            flash,
+        },
+    )
+}
+
+async fn liquidate_borrow(
+    context: &mut ProgramTestContext,
+    protocol: &Protocol,
+    user: &Pubkey,
+    liquidator: &Keypair,
+    amount: u64,
+) {
+    let instruction = liquidate_borrow_instruction(protocol, user, liquidator, amount, false);
+    process_instructions(context, &[instruction], &[liquidator])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn borrow_liquidation_repays_at_most_the_close_factor() {
+    let mut context = start().await;
+    let mut protocol = Protocol::new(&mut context, 1.0).await;
+    protocol.clone.borrow_liquidation_close_factor_bps = 5000;
+    let (user, liquidator) = setup_borrow(&mut context, &mut protocol, 120_000_000).await;
+
+    liquidate_borrow(&mut context, &protocol, &user, &liquidator, 1_000_000_000).await;
+
+    // Half of the 10 onasset debt is repaid for 5.25 collateral with the 5% bonus.
+    let user_account = protocol.get_user(&mut context, &user).await;
+    assert_eq!(user_account.borrows[0].borrowed_onasset, 500_000_000);
+    assert_eq!(user_account.borrows[0].collateral_amount, 67_500_000);
+    assert_eq!(
+        token_balance(
+            &mut context,
+            &get_associated_token_address(&liquidator.pubkey(), &protocol.collateral_mint)
+        )
+        .await,
+        52_500_000
+    );
+}
+
+#[tokio::test]
+async fn borrow_liquidation_in_liquidation_mode_repays_the_whole_debt() {
+    let mut context = start().await;
+    let mut protocol = Protocol::new(&mut context, 1.0).await;
+    protocol.clone.borrow_liquidation_close_factor_bps = 5000;
+    protocol.pools[0].status = Status::Liquidation;
+    let (user, liquidator) = setup_borrow(&mut context, &mut protocol, 120_000_000).await;
+
+    liquidate_borrow(&mut context, &protocol, &user, &liquidator, 1_000_000_000).await;
+
+    // The close factor is lifted and the repaid position keeps its remaining collateral.
+    let user_account = protocol.get_user(&mut context, &user).await;
+    assert_eq!(user_account.borrows[0].borrowed_onasset, 0);
+    assert_eq!(user_account.borrows[0].collateral_amount, 15_000_000);
+    assert_eq!(mint_supply(&mut context, &protocol.onasset_mint).await, 0);
+}
+
+#[tokio::test]
+async fn borrow_liquidation_bonus_scales_with_health_and_pays_the_protocol_cut() {
+    let mut context = start().await;
+    let mut protocol = Protocol::new(&mut context, 1.0).await;
+    protocol.clone.borrow_liquidator_max_fee_bps = 1500;
+    protocol.clone.borrow_liquidation_protocol_fee_bps = 5000;
+    protocol.clone.liquidation_auction_slots = 1000;
+    let (user, liquidator) = setup_borrow(&mut context, &mut protocol, 120_000_000).await;
+
+    liquidate_borrow(&mut context, &protocol, &user, &liquidator, 200_000_000).await;
+
+    // A c-ratio of 1.2 against the 1.5 minimum is 60% of the way to the maximum bonus, so
+    // repaying 2 onasset is rewarded 2.22 with half of the 0.22 bonus going to the treasury.
+    let user_account = protocol.get_user(&mut context, &user).await;
+    assert_eq!(user_account.borrows[0].borrowed_onasset, 800_000_000);
+    assert_eq!(user_account.borrows[0].collateral_amount, 97_800_000);
+    assert_eq!(
+        token_balance(
+            &mut context,
+            &get_associated_token_address(&liquidator.pubkey(), &protocol.collateral_mint)
+        )
+        .await,
+        21_100_000
+    );
+    assert_eq!(
+        token_balance(
+            &mut context,
+            &get_associated_token_address(&protocol.treasury.pubkey(), &protocol.collateral_mint)
+        )
+        .await,
+        1_100_000
+    );
+}
+
+async fn update_liquidation_auctions(context: &mut ProgramTestContext, user: &Pubkey) {
+    let instruction = anchor_instruction(
+        clone::accounts::UpdateLiquidationAuctions {
+            clone: clone_address(),
+            pools: pools_address(),
+            oracles: oracles_address(),
+            collaterals: collaterals_address(),
+            user_account: user_address(user),
+        },
+        clone::instruction::UpdateLiquidationAuctions { user: *user },
+    );
+    process_instructions(context, &[instruction], &[])
+        .await
+        .unwrap();
+}
+
+async fn liquidator_collateral(
+    context: &mut ProgramTestContext,
+    protocol: &Protocol,
+    liquidator: &Keypair,
+) -> u64 {
+    token_balance(
+        context,
+        &get_associated_token_address(&liquidator.pubkey(), &protocol.collateral_mint),
+    )
+    .await
+}
+
+#[tokio::test]
+async fn borrow_liquidation_bonus_grows_with_the_auction() {
+    let mut context = start().await;
+    let mut protocol = Protocol::new(&mut context, 1.0).await;
+    protocol.clone.borrow_liquidator_max_fee_bps = 1500;
+    protocol.clone.liquidation_auction_slots = 100;
+    let (user, liquidator) = setup_borrow(&mut context, &mut protocol, 149_000_000).await;
+
+    let start_slot = current_slot(&mut context).await;
+    update_liquidation_auctions(&mut context, &user).await;
+    let user_account = protocol.get_user(&mut context, &user).await;
+    assert_eq!(user_account.borrows[0].liquidation_start_slot, start_slot);
+
+    protocol.warp(&mut context, 50).await;
+    liquidate_borrow(&mut context, &protocol, &user, &liquidator, 200_000_000).await;
+
+    // Halfway through the auction the bonus is 10%, well past the 2% health shortfall.
+    assert_eq!(
+        liquidator_collateral(&mut context, &protocol, &liquidator).await,
+        22_000_000
+    );
+}
+
+#[tokio::test]
+async fn borrow_shortfall_is_covered_by_the_insurance_fund_and_socialised() {
+    let mut context = start().await;
+    let mut protocol = Protocol::new(&mut context, 1.0).await;
+    protocol.clone.insurance_fund = 2_000_000;
+    protocol.pools[0].committed_collateral_liquidity = 1_000_000_000;
+    let (user, liquidator) = setup_borrow(&mut context, &mut protocol, 90_000_000).await;
+    protocol.fund_vault(&mut context, 0, 2_000_000).await;
+
+    liquidate_borrow(&mut context, &protocol, &user, &liquidator, 900_000_000).await;
+
+    // Repaying 9 onasset is owed 9.45 but the position only holds 9, the insurance fund tops
+    // up what it can and the remaining onasset debt becomes the LPs' ILD.
+    assert_eq!(
+        liquidator_collateral(&mut context, &protocol, &liquidator).await,
+        92_000_000
+    );
+    let user_account = protocol.get_user(&mut context, &user).await;
+    assert!(user_account.borrows.is_empty());
+    protocol.load(&mut context).await;
+    assert_eq!(protocol.clone.insurance_fund, 0);
+    assert_eq!(protocol.pools[0].onasset_ild, 100_000_000);
+    assert_eq!(protocol.pools[0].borrowed_onasset, 0);
+    assert_eq!(token_balance(&mut context, &protocol.vault).await, 0);
+}
+
+#[tokio::test]
+async fn comet_liquidation_only_withdraws_liquidity_down_to_the_target_health() {
+    let mut context = start().await;
+    let mut protocol = Protocol::new(&mut context, 1.0).await;
+    protocol.clone.comet_liquidation_target_health_score = 20;
+    protocol.pools[0].committed_collateral_liquidity = 10_000_000_000;
+    protocol.pools[0]
+        .asset_info
+        .position_health_score_coefficient = 12;
+    protocol.save(&mut context).await;
+    protocol.fund_vault(&mut context, 0, 1_000_000_000).await;
+    let user = Pubkey::new_unique();
+    let mut user_account = empty_user();
+    user_account.comet.collateral_amount = 1_000_000_000;
+    user_account.comet.positions.push(LiquidityPosition {
+        pool_index: 0,
+        committed_collateral_liquidity: 10_000_000_000,
+        collateral_ild_rebate: 0,
+        onasset_ild_rebate: 0,
+    });
+    protocol.set_user(&mut context, &user, &user_account);
+    let liquidator = Keypair::new();
+    let liquidator_collateral_token_account = set_associated_token_account(
+        &mut context,
+        &protocol.collateral_mint,
+        &liquidator.pubkey(),
+        0,
+    );
+
+    let instruction = anchor_instruction(
+        clone::accounts::LiquidateCometCollateralIld {
+            liquidator: liquidator.pubkey(),
+            user_account: user_address(&user),
+            clone: clone_address(),
+            pools: pools_address(),
+            oracles: oracles_address(),
+            collaterals: collaterals_address(),
+            collateral_mint: protocol.collateral_mint,
+            liquidator_collateral_token_account,
+            vault: protocol.vault,
+            deposit_vault: None,
+            liquidator_deposit_token_account: None,
+            token_program: spl_token::ID,
+        },
+        clone::instruction::LiquidateCometCollateralIld {
+            user,
+            comet_position_index: 0,
+        },
+    );
+    process_instructions(&mut context, &[instruction], &[&liquidator])
+        .await
+        .unwrap();
+
+    // The 120 position term against 100 collateral is brought down to the 80 a score of 20
+    // allows, withdrawing 333.33 of the 1000 liquidity and leaving the rest in the pool.
+    let user_account = protocol.get_user(&mut context, &user).await;
+    assert_eq!(
+        user_account.comet.positions[0].committed_collateral_liquidity,
+        6_666_666_666
+    );
+    assert_eq!(user_account.comet.collateral_amount, 1_000_000_000);
+    assert_eq!(user_account.comet.liquidation_start_slot, 0);
+    protocol.load(&mut context).await;
+    assert_eq!(
+        protocol.pools[0].committed_collateral_liquidity,
+        6_666_666_666
+    );
+}
+
+#[tokio::test]
+async fn flash_borrow_liquidation_buys_the_onasset_from_the_pool() {
+    let mut context = start().await;
+    let mut protocol = Protocol::new(&mut context, 1.0).await;
+    protocol.pools[0].committed_collateral_liquidity = 100_000_000_000;
+    let (user, liquidator) = setup_borrow(&mut context, &mut protocol, 120_000_000).await;
+
+    let instruction =
+        liquidate_borrow_instruction(&protocol, &user, &liquidator, 200_000_000, true);
+    process_instructions(&mut context, &[instruction], &[&liquidator])
+        .await
+        .unwrap();
+
+    let user_account = protocol.get_user(&mut context, &user).await;
+    assert_eq!(user_account.borrows[0].borrowed_onasset, 800_000_000);
+    assert_eq!(user_account.borrows[0].collateral_amount, 99_000_000);
+    // The liquidator keeps its onasset and is paid the 2.1 reward less the 2.0206 that buying
+    // 2 onasset from the pool cost, the pool's treasury fee is minted to the treasury.
+    assert_eq!(
+        token_balance(
+            &mut context,
+            &get_associated_token_address(&liquidator.pubkey(), &protocol.onasset_mint)
+        )
+        .await,
+        1_000_000_000
+    );
+    assert_eq!(
+        liquidator_collateral(&mut context, &protocol, &liquidator).await,
+        793_898
+    );
+    assert_eq!(
+        token_balance(
+            &mut context,
+            &get_associated_token_address(&protocol.treasury.pubkey(), &protocol.onasset_mint)
+        )
+        .await,
+        1_010_101
+    );
+    protocol.load(&mut context).await;
+    assert_eq!(protocol.pools[0].collateral_ild, -20_206_102);
+    assert_eq!(protocol.pools[0].onasset_ild, 201_010_101);
+}
+
+#[tokio::test]
+async fn flash_borrow_liquidation_fails_when_the_swap_costs_more_than_the_reward() {
+    let mut context = start().await;
+    let mut protocol = Protocol::new(&mut context, 1.0).await;
+    protocol.clone.borrow_liquidator_fee_bps = 0;
+    protocol.pools[0].committed_collateral_liquidity = 100_000_000_000;
+    let (user, liquidator) = setup_borrow(&mut context, &mut protocol, 120_000_000).await;
+
+    // Without a bonus the repaid value can't cover the pool's trading fees.
+    let instruction =
+        liquidate_borrow_instruction(&protocol, &user, &liquidator, 200_000_000, true);
+    assert_clone_error(
+        process_instructions(&mut context, &[instruction], &[&liquidator]).await,
+        CloneError::FlashLiquidationUnprofitable,
+    );
+}
+
+#[tokio::test]
+async fn non_auth_liquidation_fails_inside_the_grace_period() {
+    let mut context = start().await;
+    let mut protocol = Protocol::new(&mut context, 1.0).await;
+    protocol.clone.auth_liquidation_grace_slots = 100;
+    let (user, liquidator) = setup_borrow(&mut context, &mut protocol, 149_000_000).await;
+
+    let instruction =
+        liquidate_borrow_instruction(&protocol, &user, &liquidator, 200_000_000, false);
+    assert_clone_error(
+        process_instructions(&mut context, &[instruction], &[&liquidator]).await,
+        CloneError::LiquidationGracePeriodActive,
+    );
+
+    // Starting the auction leaves 98 slots of grace after the 2% health shortfall.
+    update_liquidation_auctions(&mut context, &user).await;
+    protocol.warp(&mut context, 97).await;
+    let instruction =
+        liquidate_borrow_instruction(&protocol, &user, &liquidator, 200_000_000, false);
+    assert_clone_error(
+        process_instructions(&mut context, &[instruction], &[&liquidator]).await,
+        CloneError::LiquidationGracePeriodActive,
+    );
+
+    protocol.warp(&mut context, 1).await;
+    liquidate_borrow(&mut context, &protocol, &user, &liquidator, 200_000_000).await;
+    let user_account = protocol.get_user(&mut context, &user).await;
+    assert_eq!(user_account.borrows[0].borrowed_onasset, 800_000_000);
+    assert_eq!(
+        liquidator_collateral(&mut context, &protocol, &liquidator).await,
+        21_000_000
+    );
+}
+
+#[tokio::test]
+async fn insolvent_borrow_skips_the_grace_period() {
+    let mut context = start().await;
+    let mut protocol = Protocol::new(&mut context, 1.0).await;
+    protocol.clone.auth_liquidation_grace_slots = 100;
+    let (user, liquidator) = setup_borrow(&mut context, &mut protocol, 100_000_000).await;
+
+    liquidate_borrow(&mut context, &protocol, &user, &liquidator, 200_000_000).await;
+
+    let user_account = protocol.get_user(&mut context, &user).await;
+    assert_eq!(user_account.borrows[0].borrowed_onasset, 800_000_000);
+    assert_eq!(
+        liquidator_collateral(&mut context, &protocol, &liquidator).await,
+        21_000_000
+    );
+}
@@ -0,0 +1,371 @@
+mod common;
+
+use anchor_lang::{AnchorSerialize, Discriminator};
+use clone::error::CloneError;
+use clone::instructions::UpdateOracleParameters;
+use clone::migration::*;
+use clone::states::*;
+use common::*;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+};
+
+// Writes `state` behind `T`'s discriminator in an account of `space` bytes holding just
+// its rent exemption, as the program left accounts created before their layout grew.
+async fn set_legacy_account<T: Discriminator, L: AnchorSerialize>(
+    context: &mut ProgramTestContext,
+    address: &Pubkey,
+    state: &L,
+    space: usize,
+) {
+    let mut data = T::discriminator().to_vec();
+    state.serialize(&mut data).expect("serialize");
+    data.resize(space, 0);
+    let rent = context.banks_client.get_rent().await.expect("rent");
+    let account = Account {
+        lamports: rent.minimum_balance(space),
+        data,
+        owner: clone::ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+    context.set_account(address, &AccountSharedData::from(account));
+}
+
+async fn assert_rent_exempt_space(
+    context: &mut ProgramTestContext,
+    address: &Pubkey,
+    space: usize,
+) {
+    let account = context
+        .banks_client
+        .get_account(*address)
+        .await
+        .expect("banks client")
+        .expect("account exists");
+    let rent = context.banks_client.get_rent().await.expect("rent");
+    assert_eq!(account.data.len(), space);
+    assert!(account.lamports >= rent.minimum_balance(space));
+}
+
+fn fund(context: &mut ProgramTestContext, address: &Pubkey) {
+    set_raw_account(context, address, &system_program::ID, vec![]);
+}
+
+// Waits for a blockhash past the one the last transaction used, so retrying an identical
+// transaction is processed again instead of returning the cached signature status.
+async fn refresh_blockhash(context: &mut ProgramTestContext) {
+    context.last_blockhash = context
+        .banks_client
+        .get_latest_blockhash()
+        .await
+        .expect("blockhash");
+    context.get_new_latest_blockhash().await.expect("blockhash");
+}
+
+fn legacy_collateral(protocol: &Protocol) -> LegacyCollateral {
+    LegacyCollateral {
+        oracle_info_index: 0,
+        mint: protocol.collateral_mint,
+        vault: protocol.vault,
+        collateralization_ratio: 100,
+        scale: COLLATERAL_SCALE,
+    }
+}
+
+fn legacy_pool(protocol: &Protocol) -> LegacyPool {
+    LegacyPool {
+        underlying_asset_token_account: protocol.underlying_asset_token_account,
+        committed_collateral_liquidity: 5_000_000_000,
+        collateral_ild: 10,
+        onasset_ild: -20,
+        treasury_trading_fee_bps: 50,
+        liquidity_trading_fee_bps: 30,
+        asset_info: protocol.pools[0].asset_info.clone(),
+        status: Status::Active,
+    }
+}
+
+#[tokio::test]
+async fn migrate_clone_carries_over_the_legacy_parameters() {
+    let mut context = start().await;
+    let protocol = Protocol::new(&mut context, 1.0).await;
+    fund(&mut context, &protocol.admin.pubkey());
+    let mut auth = [Pubkey::default(); NUM_AUTH];
+    auth[0] = Pubkey::new_unique();
+    set_legacy_account::<Clone, _>(
+        &mut context,
+        &clone_address(),
+        &LegacyClone {
+            admin: protocol.admin.pubkey(),
+            auth,
+            bump: protocol.clone.bump,
+            collateral: legacy_collateral(&protocol),
+            comet_collateral_ild_liquidator_fee_bps: 400,
+            comet_onasset_ild_liquidator_fee_bps: 300,
+            borrow_liquidator_fee_bps: 200,
+            treasury_address: protocol.treasury.pubkey(),
+            event_counter: 7,
+            non_auth_liquidations_enabled: true,
+        },
+        8 + 472,
+    )
+    .await;
+    let migrate_clone = |admin: &Keypair| {
+        anchor_instruction(
+            clone::accounts::MigrateClone {
+                admin: admin.pubkey(),
+                clone: clone_address(),
+                system_program: system_program::ID,
+            },
+            clone::instruction::MigrateClone {},
+        )
+    };
+
+    let impostor = Keypair::new();
+    fund(&mut context, &impostor.pubkey());
+    assert_clone_error(
+        process_instructions(&mut context, &[migrate_clone(&impostor)], &[&impostor]).await,
+        CloneError::Unauthorized,
+    );
+
+    process_instructions(
+        &mut context,
+        &[migrate_clone(&protocol.admin)],
+        &[&protocol.admin],
+    )
+    .await
+    .unwrap();
+    assert_rent_exempt_space(&mut context, &clone_address(), CLONE_SPACE).await;
+    let clone_account = get_anchor_account::<Clone>(&mut context, &clone_address()).await;
+    assert_eq!(clone_account.admin, protocol.admin.pubkey());
+    assert_eq!(clone_account.auth, auth);
+    assert_eq!(clone_account.collateral.mint, protocol.collateral_mint);
+    assert_eq!(clone_account.collateral.scale, COLLATERAL_SCALE);
+    assert_eq!(clone_account.comet_collateral_ild_liquidator_fee_bps, 400);
+    assert_eq!(clone_account.comet_onasset_ild_liquidator_fee_bps, 300);
+    assert_eq!(clone_account.borrow_liquidator_fee_bps, 200);
+    assert_eq!(clone_account.event_counter, 7);
+    assert!(clone_account.non_auth_liquidations_enabled);
+    assert_eq!(clone_account.flash_loan_fee_bps, 0);
+
+    refresh_blockhash(&mut context).await;
+    assert_clone_error(
+        process_instructions(
+            &mut context,
+            &[migrate_clone(&protocol.admin)],
+            &[&protocol.admin],
+        )
+        .await,
+        CloneError::AccountAlreadyMigrated,
+    );
+}
+
+#[tokio::test]
+async fn migrate_pools_grows_the_account_to_hold_num_pools() {
+    let mut context = start().await;
+    let protocol = Protocol::new(&mut context, 1.0).await;
+    fund(&mut context, &protocol.admin.pubkey());
+    set_legacy_account::<Pools, _>(
+        &mut context,
+        &pools_address(),
+        &LegacyPools {
+            pools: vec![legacy_pool(&protocol)],
+        },
+        LEGACY_ACCOUNT_SPACE,
+    )
+    .await;
+
+    let instruction = anchor_instruction(
+        clone::accounts::MigratePools {
+            admin: protocol.admin.pubkey(),
+            clone: clone_address(),
+            pools: pools_address(),
+            system_program: system_program::ID,
+        },
+        clone::instruction::MigratePools {},
+    );
+    process_instructions(&mut context, &[instruction], &[&protocol.admin])
+        .await
+        .unwrap();
+
+    assert_rent_exempt_space(&mut context, &pools_address(), POOLS_SPACE).await;
+    let pools = get_anchor_account::<Pools>(&mut context, &pools_address())
+        .await
+        .pools;
+    assert_eq!(pools.len(), 1);
+    assert_eq!(pools[0].committed_collateral_liquidity, 5_000_000_000);
+    assert_eq!(pools[0].collateral_ild, 10);
+    assert_eq!(pools[0].onasset_ild, -20);
+    assert_eq!(pools[0].liquidity_trading_fee_bps, 30);
+    assert_eq!(pools[0].asset_info, protocol.pools[0].asset_info);
+    assert_eq!(pools[0].swap_volume, SwapVolume::default());
+
+    // A full set of pools fits the migrated account.
+    let full_pools = Pools {
+        pools: vec![protocol.pools[0].clone(); NUM_POOLS],
+    };
+    let mut data = Vec::new();
+    anchor_lang::AccountSerialize::try_serialize(&full_pools, &mut data).unwrap();
+    assert!(data.len() <= POOLS_SPACE);
+}
+
+#[tokio::test]
+async fn oracles_are_added_only_once_migrated() {
+    let mut context = start().await;
+    let protocol = Protocol::new(&mut context, 1.0).await;
+    fund(&mut context, &protocol.admin.pubkey());
+    let legacy_oracle = |price: f64| LegacyOracleInfo {
+        source: OracleSource::PYTH,
+        address: Pubkey::new_unique(),
+        price: to_price(price),
+        expo: PRICE_EXPO,
+        status: Status::Active,
+        last_update_slot: 5,
+        rescale_factor: 0,
+    };
+    set_legacy_account::<Oracles, _>(
+        &mut context,
+        &oracles_address(),
+        &LegacyOracles {
+            oracles: vec![legacy_oracle(1.0), legacy_oracle(2.0)],
+        },
+        LEGACY_ACCOUNT_SPACE,
+    )
+    .await;
+    let add_oracle = anchor_instruction(
+        clone::accounts::UpdateOracles {
+            auth: protocol.admin.pubkey(),
+            clone: clone_address(),
+            oracles: oracles_address(),
+        },
+        clone::instruction::UpdateOracles {
+            params: UpdateOracleParameters::Add {
+                address: Pubkey::new_unique(),
+                source: OracleSource::PYTH,
+                rescale_factor: None,
+            },
+        },
+    );
+    let migrate_oracles = anchor_instruction(
+        clone::accounts::MigrateOracles {
+            admin: protocol.admin.pubkey(),
+            clone: clone_address(),
+            oracles: oracles_address(),
+            system_program: system_program::ID,
+        },
+        clone::instruction::MigrateOracles {},
+    );
+
+    // Oracles can't be added to the legacy account.
+    assert!(process_instructions(
+        &mut context,
+        std::slice::from_ref(&add_oracle),
+        &[&protocol.admin]
+    )
+    .await
+    .is_err());
+
+    process_instructions(&mut context, &[migrate_oracles], &[&protocol.admin])
+        .await
+        .unwrap();
+    assert_rent_exempt_space(&mut context, &oracles_address(), ORACLES_SPACE).await;
+    refresh_blockhash(&mut context).await;
+    process_instructions(&mut context, &[add_oracle], &[&protocol.admin])
+        .await
+        .unwrap();
+
+    let oracles = get_anchor_account::<Oracles>(&mut context, &oracles_address())
+        .await
+        .oracles;
+    assert_eq!(oracles.len(), 3);
+    assert_eq!(oracles[1].price, to_price(2.0));
+    assert_eq!(oracles[1].last_update_slot, 5);
+    assert_eq!(oracles[1].last_publish_slot, 0);
+}
+
+#[tokio::test]
+async fn oracles_at_the_initial_size_require_migration() {
+    let mut context = start().await;
+    let protocol = Protocol::new(&mut context, 1.0).await;
+
+    // `Protocol` seeds Oracles in the current layout at the size the init instruction allocates.
+    let add_oracle = anchor_instruction(
+        clone::accounts::UpdateOracles {
+            auth: protocol.admin.pubkey(),
+            clone: clone_address(),
+            oracles: oracles_address(),
+        },
+        clone::instruction::UpdateOracles {
+            params: UpdateOracleParameters::Add {
+                address: Pubkey::new_unique(),
+                source: OracleSource::PYTH,
+                rescale_factor: None,
+            },
+        },
+    );
+    assert_clone_error(
+        process_instructions(&mut context, &[add_oracle], &[&protocol.admin]).await,
+        CloneError::AccountMigrationRequired,
+    );
+}
+
+#[tokio::test]
+async fn migrate_user_carries_over_borrows_and_comet() {
+    let mut context = start().await;
+    let authority = Pubkey::new_unique();
+    let position = LiquidityPosition {
+        pool_index: 0,
+        committed_collateral_liquidity: 1_000_000,
+        collateral_ild_rebate: 3,
+        onasset_ild_rebate: -4,
+    };
+    set_legacy_account::<User, _>(
+        &mut context,
+        &user_address(&authority),
+        &LegacyUser {
+            borrows: vec![LegacyBorrow {
+                pool_index: 0,
+                borrowed_onasset: 100_000_000,
+                collateral_amount: 2_000_000_000,
+            }],
+            comet: LegacyComet {
+                collateral_amount: 50_000_000,
+                positions: vec![position],
+            },
+        },
+        LEGACY_ACCOUNT_SPACE,
+    )
+    .await;
+    let migrate_user = anchor_instruction(
+        clone::accounts::MigrateUser {
+            payer: context.payer.pubkey(),
+            user_account: user_address(&authority),
+            system_program: system_program::ID,
+        },
+        clone::instruction::MigrateUser { authority },
+    );
+
+    process_instructions(&mut context, std::slice::from_ref(&migrate_user), &[])
+        .await
+        .unwrap();
+    assert_rent_exempt_space(&mut context, &user_address(&authority), USER_SPACE).await;
+    let user = get_anchor_account::<User>(&mut context, &user_address(&authority)).await;
+    assert_eq!(user.borrows.len(), 1);
+    assert_eq!(user.borrows[0].borrowed_onasset, 100_000_000);
+    assert_eq!(user.borrows[0].collateral_amount, 2_000_000_000);
+    assert_eq!(user.borrows[0].collateral_index, 0);
+    assert_eq!(user.comet.collateral_amount, 50_000_000);
+    assert_eq!(user.comet.positions, vec![position]);
+    assert!(user.comet.collateral_deposits.is_empty());
+
+    refresh_blockhash(&mut context).await;
+    assert_clone_error(
+        process_instructions(&mut context, &[migrate_user], &[]).await,
+        CloneError::AccountAlreadyMigrated,
+    );
+}
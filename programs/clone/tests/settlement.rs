@@ -0,0 +1,679 @@
+mod common;
+
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use clone::error::CloneError;
+use clone::instructions::SETTLEMENT_RESERVE_SEED;
+use clone::states::*;
+use common::*;
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    clock::Clock,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+};
+
+fn settlement_reserve_address(protocol: &Protocol, collateral_index: u8) -> Pubkey {
+    pda(&[
+        SETTLEMENT_RESERVE_SEED.as_ref(),
+        protocol.onasset_mint.as_ref(),
+        protocol.get_collateral(collateral_index).mint.as_ref(),
+    ])
+}
+
+async fn settle_pool(context: &mut ProgramTestContext, protocol: &mut Protocol) {
+    let instruction = anchor_instruction(
+        clone::accounts::SettlePool {
+            admin: protocol.admin.pubkey(),
+            clone: clone_address(),
+            pools: pools_address(),
+            oracles: oracles_address(),
+        },
+        clone::instruction::SettlePool { pool_index: 0 },
+    );
+    process_instructions(context, &[instruction], &[&protocol.admin])
+        .await
+        .unwrap();
+    protocol.load(context).await;
+}
+
+async fn initialize_settlement_reserve(
+    context: &mut ProgramTestContext,
+    protocol: &Protocol,
+    collateral_index: u8,
+) {
+    let instruction = anchor_instruction(
+        clone::accounts::InitializeSettlementReserve {
+            payer: context.payer.pubkey(),
+            clone: clone_address(),
+            pools: pools_address(),
+            collaterals: collaterals_address(),
+            settlement_reserve: settlement_reserve_address(protocol, collateral_index),
+            system_program: system_program::ID,
+        },
+        clone::instruction::InitializeSettlementReserve {
+            pool_index: 0,
+            collateral_index,
+        },
+    );
+    process_instructions(context, &[instruction], &[])
+        .await
+        .unwrap();
+}
+
+async fn settle_comet_position(
+    context: &mut ProgramTestContext,
+    user: &Pubkey,
+    deposit_vault: Option<Pubkey>,
+    settlement_reserve: Option<Pubkey>,
+) -> Result<(), BanksClientError> {
+    let instruction = anchor_instruction(
+        clone::accounts::SettleCometPosition {
+            user_account: user_address(user),
+            clone: clone_address(),
+            pools: pools_address(),
+            oracles: oracles_address(),
+            collaterals: collaterals_address(),
+            deposit_vault,
+            settlement_reserve,
+        },
+        clone::instruction::SettleCometPosition {
+            user: *user,
+            comet_position_index: 0,
+        },
+    );
+    process_instructions(context, &[instruction], &[]).await
+}
+
+fn remove_pool(context: &mut ProgramTestContext, protocol: &Protocol) -> Instruction {
+    anchor_instruction(
+        clone::accounts::RemovePool {
+            admin: protocol.admin.pubkey(),
+            clone: clone_address(),
+            pools: pools_address(),
+            underlying_asset_mint: protocol.underlying_mint,
+            underlying_asset_token_account: protocol.underlying_asset_token_account,
+            treasury_asset_token_account: set_associated_token_account(
+                context,
+                &protocol.underlying_mint,
+                &protocol.treasury.pubkey(),
+                0,
+            ),
+            vault: protocol.vault,
+            treasury_collateral_token_account: get_associated_token_address(
+                &protocol.treasury.pubkey(),
+                &protocol.collateral_mint,
+            ),
+            token_program: spl_token::ID,
+        },
+        clone::instruction::RemovePool { pool_index: 0 },
+    )
+}
+
+fn redeem_settled_onasset(
+    protocol: &Protocol,
+    holder: &Pubkey,
+    holder_onasset: Pubkey,
+    holder_collateral: Pubkey,
+    amount: u64,
+) -> Instruction {
+    anchor_instruction(
+        clone::accounts::RedeemSettledOnasset {
+            user: *holder,
+            clone: clone_address(),
+            pools: pools_address(),
+            onasset_mint: protocol.onasset_mint,
+            underlying_asset_mint: protocol.underlying_mint,
+            underlying_asset_token_account: protocol.underlying_asset_token_account,
+            user_onasset_token_account: holder_onasset,
+            vault: protocol.vault,
+            user_collateral_token_account: holder_collateral,
+            token_program: spl_token::ID,
+        },
+        clone::instruction::RedeemSettledOnasset {
+            pool_index: 0,
+            amount,
+        },
+    )
+}
+
+async fn settle_borrow_position(
+    context: &mut ProgramTestContext,
+    protocol: &Protocol,
+    user: &Pubkey,
+    collateral_index: u8,
+    settlement_reserve: Option<Pubkey>,
+) -> Result<(), BanksClientError> {
+    let collateral = protocol.get_collateral(collateral_index);
+    let instruction = anchor_instruction(
+        clone::accounts::SettleBorrowPosition {
+            user_account: user_address(user),
+            clone: clone_address(),
+            pools: pools_address(),
+            oracles: oracles_address(),
+            collaterals: collaterals_address(),
+            settlement_reserve,
+            vault: collateral.vault,
+            user_collateral_token_account: set_associated_token_account(
+                context,
+                &collateral.mint,
+                user,
+                0,
+            ),
+            token_program: spl_token::ID,
+        },
+        clone::instruction::SettleBorrowPosition {
+            user: *user,
+            borrow_index: 0,
+        },
+    );
+    process_instructions(context, &[instruction], &[]).await
+}
+
+fn borrow(collateral_index: u8, collateral_amount: u64, borrowed_onasset: u64) -> Borrow {
+    Borrow {
+        pool_index: 0,
+        borrowed_onasset,
+        collateral_amount,
+        collateral_index,
+        stability_fee_index: 0,
+        liquidation_start_slot: 0,
+    }
+}
+
+#[tokio::test]
+async fn settles_base_collateral_borrow_into_reserve() {
+    let mut context = start().await;
+    let mut protocol = Protocol::new(&mut context, 10.0).await;
+    let user = Pubkey::new_unique();
+    let mut user_account = empty_user();
+    user_account
+        .borrows
+        .push(borrow(0, 200_000_000, 100_000_000));
+    protocol.set_user(&mut context, &user, &user_account);
+    protocol.fund_vault(&mut context, 0, 200_000_000).await;
+
+    settle_pool(&mut context, &mut protocol).await;
+    settle_borrow_position(&mut context, &protocol, &user, 0, None)
+        .await
+        .unwrap();
+
+    protocol.load(&mut context).await;
+    assert_eq!(protocol.pools[0].settlement.collateral_reserve, 100_000_000);
+    let user_collateral = get_associated_token_address(&user, &protocol.collateral_mint);
+    assert_eq!(
+        token_balance(&mut context, &user_collateral).await,
+        100_000_000
+    );
+    assert!(protocol
+        .get_user(&mut context, &user)
+        .await
+        .borrows
+        .is_empty());
+}
+
+#[tokio::test]
+async fn settles_registered_collateral_borrow_into_its_reserve() {
+    let mut context = start().await;
+    let mut protocol = Protocol::new(&mut context, 10.0).await;
+    let collateral_index = protocol.add_collateral(&mut context, 2.0, 100).await;
+    let user = Pubkey::new_unique();
+    let mut user_account = empty_user();
+    user_account
+        .borrows
+        .push(borrow(collateral_index, 200_000_000, 100_000_000));
+    protocol.set_user(&mut context, &user, &user_account);
+    protocol
+        .fund_vault(&mut context, collateral_index, 200_000_000)
+        .await;
+    settle_pool(&mut context, &mut protocol).await;
+
+    // The reserve has to exist before the position can settle into it.
+    assert_clone_error(
+        settle_borrow_position(&mut context, &protocol, &user, collateral_index, None).await,
+        CloneError::SettlementUnavailable,
+    );
+    initialize_settlement_reserve(&mut context, &protocol, collateral_index).await;
+    let reserve_address = settlement_reserve_address(&protocol, collateral_index);
+    settle_borrow_position(
+        &mut context,
+        &protocol,
+        &user,
+        collateral_index,
+        Some(reserve_address),
+    )
+    .await
+    .unwrap();
+
+    // One onasset at 10 is worth 5 of the collateral priced at 2.
+    protocol.load(&mut context).await;
+    let reserve = get_anchor_account::<SettlementReserve>(&mut context, &reserve_address).await;
+    assert_eq!(reserve.collateral_amount, 50_000_000);
+    assert_eq!(reserve.onasset_amount, 100_000_000);
+    assert_eq!(protocol.pools[0].settlement.collateral_reserve, 0);
+    assert_eq!(
+        protocol.pools[0].settlement.registered_reserve_onasset,
+        100_000_000
+    );
+    assert_eq!(protocol.pools[0].borrowed_onasset, 0);
+    let collateral_mint = protocol.get_collateral(collateral_index).mint;
+    let user_collateral = get_associated_token_address(&user, &collateral_mint);
+    assert_eq!(
+        token_balance(&mut context, &user_collateral).await,
+        150_000_000
+    );
+    assert!(protocol
+        .get_user(&mut context, &user)
+        .await
+        .borrows
+        .is_empty());
+
+    // Holders redeem their share of the reserve for the onasset it backs.
+    let holder = Keypair::new();
+    set_mint(
+        &mut context,
+        &protocol.onasset_mint,
+        &clone_address(),
+        ONASSET_SCALE,
+        100_000_000,
+    );
+    let holder_onasset = set_associated_token_account(
+        &mut context,
+        &protocol.onasset_mint,
+        &holder.pubkey(),
+        40_000_000,
+    );
+    let holder_collateral =
+        set_associated_token_account(&mut context, &collateral_mint, &holder.pubkey(), 0);
+    let instruction = anchor_instruction(
+        clone::accounts::RedeemSettlementReserve {
+            user: holder.pubkey(),
+            clone: clone_address(),
+            pools: pools_address(),
+            collaterals: collaterals_address(),
+            settlement_reserve: reserve_address,
+            onasset_mint: protocol.onasset_mint,
+            user_onasset_token_account: holder_onasset,
+            vault: protocol.get_collateral(collateral_index).vault,
+            user_collateral_token_account: holder_collateral,
+            token_program: spl_token::ID,
+        },
+        clone::instruction::RedeemSettlementReserve {
+            pool_index: 0,
+            amount: 40_000_000,
+        },
+    );
+    process_instructions(&mut context, &[instruction], &[&holder])
+        .await
+        .unwrap();
+
+    protocol.load(&mut context).await;
+    assert_eq!(
+        token_balance(&mut context, &holder_collateral).await,
+        20_000_000
+    );
+    assert_eq!(token_balance(&mut context, &holder_onasset).await, 0);
+    assert_eq!(
+        protocol.pools[0].settlement.registered_reserve_onasset,
+        60_000_000
+    );
+}
+
+#[tokio::test]
+async fn redemptions_exclude_wrapped_and_registered_supply() {
+    let mut context = start().await;
+    let mut protocol = Protocol::new(&mut context, 10.0).await;
+    settle_pool(&mut context, &mut protocol).await;
+    // 10 onasset are outstanding: 5 are wrapped and 2 are backed by a registered reserve, so
+    // the 30 collateral in the pool's reserve fully backs the remaining 3 at a price of 10.
+    protocol.pools[0].settlement.collateral_reserve = 300_000_000;
+    protocol.pools[0].settlement.registered_reserve_onasset = 200_000_000;
+    protocol.save(&mut context).await;
+    protocol.fund_vault(&mut context, 0, 300_000_000).await;
+    set_mint(
+        &mut context,
+        &protocol.onasset_mint,
+        &clone_address(),
+        ONASSET_SCALE,
+        1_000_000_000,
+    );
+    set_token_account(
+        &mut context,
+        &protocol.underlying_asset_token_account,
+        &protocol.underlying_mint,
+        &clone_address(),
+        500_000_000,
+    );
+
+    let holder = Keypair::new();
+    let holder_onasset = set_associated_token_account(
+        &mut context,
+        &protocol.onasset_mint,
+        &holder.pubkey(),
+        100_000_000,
+    );
+    let holder_collateral =
+        set_associated_token_account(&mut context, &protocol.collateral_mint, &holder.pubkey(), 0);
+    let instruction = redeem_settled_onasset(
+        &protocol,
+        &holder.pubkey(),
+        holder_onasset,
+        holder_collateral,
+        100_000_000,
+    );
+    process_instructions(&mut context, &[instruction], &[&holder])
+        .await
+        .unwrap();
+
+    protocol.load(&mut context).await;
+    assert_eq!(
+        token_balance(&mut context, &holder_collateral).await,
+        100_000_000
+    );
+    assert_eq!(protocol.pools[0].settlement.collateral_reserve, 200_000_000);
+}
+
+#[tokio::test]
+async fn redemptions_wait_for_positions_to_settle() {
+    let mut context = start().await;
+    let mut protocol = Protocol::new(&mut context, 10.0).await;
+    settle_pool(&mut context, &mut protocol).await;
+    protocol.pools[0].settlement.collateral_reserve = 100_000_000;
+    protocol.pools[0].borrowed_onasset = 100_000_000;
+    protocol.save(&mut context).await;
+    protocol.fund_vault(&mut context, 0, 100_000_000).await;
+    set_mint(
+        &mut context,
+        &protocol.onasset_mint,
+        &clone_address(),
+        ONASSET_SCALE,
+        200_000_000,
+    );
+
+    let holder = Keypair::new();
+    let holder_onasset = set_associated_token_account(
+        &mut context,
+        &protocol.onasset_mint,
+        &holder.pubkey(),
+        100_000_000,
+    );
+    let holder_collateral =
+        set_associated_token_account(&mut context, &protocol.collateral_mint, &holder.pubkey(), 0);
+    let instruction = redeem_settled_onasset(
+        &protocol,
+        &holder.pubkey(),
+        holder_onasset,
+        holder_collateral,
+        100_000_000,
+    );
+    assert_clone_error(
+        process_instructions(&mut context, &[instruction], &[&holder]).await,
+        CloneError::RequireAllPositionsClosed,
+    );
+
+    // Once settled, the reserve is complete but too small to redeem a dust amount.
+    protocol.pools[0].borrowed_onasset = 0;
+    protocol.pools[0].settlement.collateral_reserve = 1;
+    protocol.save(&mut context).await;
+    let dust = redeem_settled_onasset(
+        &protocol,
+        &holder.pubkey(),
+        holder_onasset,
+        holder_collateral,
+        1,
+    );
+    assert_clone_error(
+        process_instructions(&mut context, &[dust], &[&holder]).await,
+        CloneError::InvalidTokenAmount,
+    );
+}
+
+#[tokio::test]
+async fn comet_settlement_seizes_registered_deposits() {
+    let mut context = start().await;
+    let mut protocol = Protocol::new(&mut context, 10.0).await;
+    let collateral_index = protocol.add_collateral(&mut context, 2.0, 100).await;
+    protocol.pools[0].committed_collateral_liquidity = 1_000_000_000;
+    protocol.pools[0].onasset_ild = 500_000_000;
+    protocol.collaterals[collateral_index as usize - 1].total_deposits = 300_000_000;
+    protocol.save(&mut context).await;
+    protocol.fund_vault(&mut context, 0, 100_000_000).await;
+    protocol
+        .fund_vault(&mut context, collateral_index, 300_000_000)
+        .await;
+    let user = Pubkey::new_unique();
+    let mut user_account = empty_user();
+    user_account.comet.collateral_amount = 100_000_000;
+    user_account.comet.positions.push(LiquidityPosition {
+        pool_index: 0,
+        committed_collateral_liquidity: 1_000_000_000,
+        collateral_ild_rebate: 0,
+        onasset_ild_rebate: 0,
+    });
+    user_account
+        .comet
+        .collateral_deposits
+        .push(CollateralDeposit {
+            collateral_index,
+            amount: 300_000_000,
+        });
+    protocol.set_user(&mut context, &user, &user_account);
+    settle_pool(&mut context, &mut protocol).await;
+
+    // The base collateral pays 1 of the 5 onasset owed, the rest stays owed while the comet
+    // holds a deposit rather than being written off.
+    settle_comet_position(&mut context, &user, None, None)
+        .await
+        .unwrap();
+    protocol.load(&mut context).await;
+    let comet = protocol.get_user(&mut context, &user).await.comet;
+    assert_eq!(comet.collateral_amount, 0);
+    assert_eq!(comet.positions[0].onasset_ild_rebate, -400_000_000);
+    assert_eq!(protocol.pools[0].settlement.collateral_reserve, 100_000_000);
+    assert_eq!(protocol.pools[0].onasset_ild, 0);
+
+    // The remaining 40 of debt take 20 of the deposit priced at 2.
+    initialize_settlement_reserve(&mut context, &protocol, collateral_index).await;
+    let reserve_address = settlement_reserve_address(&protocol, collateral_index);
+    settle_comet_position(
+        &mut context,
+        &user,
+        Some(protocol.get_collateral(collateral_index).vault),
+        Some(reserve_address),
+    )
+    .await
+    .unwrap();
+
+    protocol.load(&mut context).await;
+    let comet = protocol.get_user(&mut context, &user).await.comet;
+    assert_eq!(comet.positions[0].onasset_ild_rebate, 0);
+    assert_eq!(comet.get_collateral_amount(collateral_index), 100_000_000);
+    let reserve = get_anchor_account::<SettlementReserve>(&mut context, &reserve_address).await;
+    assert_eq!(reserve.collateral_amount, 200_000_000);
+    assert_eq!(reserve.onasset_amount, 400_000_000);
+    assert_eq!(
+        protocol.pools[0].settlement.registered_reserve_onasset,
+        400_000_000
+    );
+    assert_eq!(
+        protocol.collaterals[collateral_index as usize - 1].total_deposits,
+        100_000_000
+    );
+}
+
+#[tokio::test]
+async fn closes_registered_reserves_before_removing_the_pool() {
+    let mut context = start().await;
+    let mut protocol = Protocol::new(&mut context, 10.0).await;
+    let collateral_index = protocol.add_collateral(&mut context, 2.0, 100).await;
+    settle_pool(&mut context, &mut protocol).await;
+    initialize_settlement_reserve(&mut context, &protocol, collateral_index).await;
+    let reserve_address = settlement_reserve_address(&protocol, collateral_index);
+    set_anchor_account(
+        &mut context,
+        &reserve_address,
+        &SettlementReserve {
+            onasset_mint: protocol.onasset_mint,
+            collateral_index,
+            collateral_amount: 50_000_000,
+            onasset_amount: 100_000_000,
+        },
+    );
+    protocol
+        .fund_vault(&mut context, collateral_index, 50_000_000)
+        .await;
+    protocol.pools[0].status = Status::Deprecation;
+    protocol.pools[0].settlement.redemption_deadline = 1;
+    protocol.pools[0].settlement.registered_reserve_onasset = 100_000_000;
+    protocol.save(&mut context).await;
+
+    let remove_pool = remove_pool(&mut context, &protocol);
+    assert_clone_error(
+        process_instructions(
+            &mut context,
+            std::slice::from_ref(&remove_pool),
+            &[&protocol.admin],
+        )
+        .await,
+        CloneError::RequireAllPositionsClosed,
+    );
+
+    let collateral_mint = protocol.get_collateral(collateral_index).mint;
+    let treasury_collateral =
+        get_associated_token_address(&protocol.treasury.pubkey(), &collateral_mint);
+    let instruction = anchor_instruction(
+        clone::accounts::CloseSettlementReserve {
+            admin: protocol.admin.pubkey(),
+            clone: clone_address(),
+            pools: pools_address(),
+            collaterals: collaterals_address(),
+            settlement_reserve: reserve_address,
+            vault: protocol.get_collateral(collateral_index).vault,
+            treasury_collateral_token_account: treasury_collateral,
+            token_program: spl_token::ID,
+        },
+        clone::instruction::CloseSettlementReserve { pool_index: 0 },
+    );
+    process_instructions(
+        &mut context,
+        &[instruction, remove_pool],
+        &[&protocol.admin],
+    )
+    .await
+    .unwrap();
+
+    protocol.load(&mut context).await;
+    assert_eq!(
+        token_balance(&mut context, &treasury_collateral).await,
+        50_000_000
+    );
+    assert_eq!(protocol.pools.len(), 1);
+    assert_eq!(protocol.pools[0].settlement.registered_reserve_onasset, 0);
+    assert!(context
+        .banks_client
+        .get_account(reserve_address)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn deprecated_pools_are_redeemable_until_their_deadline() {
+    let mut context = start().await;
+    let mut protocol = Protocol::new(&mut context, 10.0).await;
+    let deadline = current_timestamp(&mut context).await + 1000;
+    let deprecate_pool = |redemption_deadline| {
+        anchor_instruction(
+            clone::accounts::DeprecatePool {
+                admin: protocol.admin.pubkey(),
+                clone: clone_address(),
+                pools: pools_address(),
+                oracles: oracles_address(),
+            },
+            clone::instruction::DeprecatePool {
+                pool_index: 0,
+                redemption_deadline,
+            },
+        )
+    };
+    let instruction = deprecate_pool(current_timestamp(&mut context).await);
+    assert_clone_error(
+        process_instructions(&mut context, &[instruction], &[&protocol.admin]).await,
+        CloneError::InvalidValueRange,
+    );
+    let instruction = deprecate_pool(deadline);
+    process_instructions(&mut context, &[instruction], &[&protocol.admin])
+        .await
+        .unwrap();
+    protocol.load(&mut context).await;
+    assert_eq!(protocol.pools[0].status, Status::Deprecation);
+    assert_eq!(protocol.pools[0].settlement.pool_price, 1_000_000_000);
+
+    // 3 onasset are outstanding and backed by 30 collateral at the snapshot price of 10.
+    protocol.pools[0].settlement.collateral_reserve = 300_000_000;
+    protocol.save(&mut context).await;
+    protocol.fund_vault(&mut context, 0, 300_000_000).await;
+    set_mint(
+        &mut context,
+        &protocol.onasset_mint,
+        &clone_address(),
+        ONASSET_SCALE,
+        300_000_000,
+    );
+    let holder = Keypair::new();
+    let holder_onasset = set_associated_token_account(
+        &mut context,
+        &protocol.onasset_mint,
+        &holder.pubkey(),
+        300_000_000,
+    );
+    let holder_collateral =
+        set_associated_token_account(&mut context, &protocol.collateral_mint, &holder.pubkey(), 0);
+    let redeem = redeem_settled_onasset(
+        &protocol,
+        &holder.pubkey(),
+        holder_onasset,
+        holder_collateral,
+        100_000_000,
+    );
+
+    process_instructions(&mut context, std::slice::from_ref(&redeem), &[&holder])
+        .await
+        .unwrap();
+    assert_eq!(
+        token_balance(&mut context, &holder_collateral).await,
+        100_000_000
+    );
+    let instruction = remove_pool(&mut context, &protocol);
+    assert_clone_error(
+        process_instructions(&mut context, &[instruction], &[&protocol.admin]).await,
+        CloneError::RedemptionPeriodActive,
+    );
+
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp = deadline + 1;
+    context.set_sysvar(&clock);
+    assert_clone_error(
+        process_instructions(&mut context, &[redeem], &[&holder]).await,
+        CloneError::RedemptionPeriodEnded,
+    );
+    // The unredeemed reserve goes to the treasury and the slot stays behind as a tombstone.
+    let instruction = remove_pool(&mut context, &protocol);
+    process_instructions(&mut context, &[instruction], &[&protocol.admin])
+        .await
+        .unwrap();
+    protocol.load(&mut context).await;
+    assert_eq!(protocol.pools.len(), 1);
+    assert_eq!(protocol.pools[0].status, Status::Deprecation);
+    assert_eq!(protocol.pools[0].settlement.collateral_reserve, 0);
+    assert_eq!(
+        token_balance(
+            &mut context,
+            &get_associated_token_address(&protocol.treasury.pubkey(), &protocol.collateral_mint)
+        )
+        .await,
+        200_000_000
+    );
+}
@@ -0,0 +1,266 @@
+mod common;
+
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use clone::instructions::SwapExpiry;
+use clone::states::*;
+use common::*;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+// Swaps `quantity` collateral for onasset, with `remaining_accounts` appended after the
+// named accounts.
+fn swap_collateral_for_onasset(
+    protocol: &Protocol,
+    user: &Pubkey,
+    quantity: u64,
+    remaining_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let mut instruction = guarded_swap_collateral_for_onasset(protocol, user, quantity, None, None);
+    instruction.accounts.extend(remaining_accounts);
+    instruction
+}
+
+fn guarded_swap_collateral_for_onasset(
+    protocol: &Protocol,
+    user: &Pubkey,
+    quantity: u64,
+    expiry: Option<SwapExpiry>,
+    max_oracle_age_slots: Option<u64>,
+) -> Instruction {
+    anchor_instruction(
+        clone::accounts::Swap {
+            user: *user,
+            clone: clone_address(),
+            pools: pools_address(),
+            oracles: oracles_address(),
+            user_collateral_token_account: get_associated_token_address(
+                user,
+                &protocol.collateral_mint,
+            ),
+            user_onasset_token_account: get_associated_token_address(user, &protocol.onasset_mint),
+            onasset_mint: protocol.onasset_mint,
+            collateral_mint: protocol.collateral_mint,
+            collateral_vault: protocol.vault,
+            treasury_onasset_token_account: get_associated_token_address(
+                &protocol.treasury.pubkey(),
+                &protocol.onasset_mint,
+            ),
+            treasury_collateral_token_account: get_associated_token_address(
+                &protocol.treasury.pubkey(),
+                &protocol.collateral_mint,
+            ),
+            token_program: spl_token::ID,
+            clone_staking: None,
+            user_staking_account: None,
+            clone_staking_program: None,
+        },
+        clone::instruction::Swap {
+            pool_index: 0,
+            quantity,
+            quantity_is_input: true,
+            quantity_is_collateral: true,
+            result_threshold: 0,
+            expiry,
+            max_oracle_age_slots,
+        },
+    )
+}
+
+async fn setup_swap(context: &mut ProgramTestContext) -> (Protocol, Keypair) {
+    let mut protocol = Protocol::new(context, 1.0).await;
+    protocol.pools[0].committed_collateral_liquidity = 10_000_000_000;
+    protocol.save(context).await;
+    let user = Keypair::new();
+    set_associated_token_account(
+        context,
+        &protocol.collateral_mint,
+        &user.pubkey(),
+        1_000_000_000,
+    );
+    set_associated_token_account(context, &protocol.onasset_mint, &user.pubkey(), 0);
+    (protocol, user)
+}
+
+#[tokio::test]
+async fn referrer_receives_its_share_of_treasury_fees() {
+    let mut context = start().await;
+    let (protocol, user) = setup_swap(&mut context).await;
+    let referrer_authority = Pubkey::new_unique();
+    let referrer = Pubkey::new_unique();
+    set_anchor_account(
+        &mut context,
+        &referrer,
+        &Referrer {
+            authority: referrer_authority,
+            fee_share_bps: 2000,
+            referred_volume: 0,
+        },
+    );
+    let referrer_token_account =
+        set_associated_token_account(&mut context, &protocol.onasset_mint, &referrer_authority, 0);
+
+    // Without oracle accounts the referral accounts are the whole of `remaining_accounts`.
+    let instruction = swap_collateral_for_onasset(
+        &protocol,
+        &user.pubkey(),
+        1_000_000_000,
+        vec![
+            AccountMeta::new(referrer, false),
+            AccountMeta::new(referrer_token_account, false),
+        ],
+    );
+    process_instructions(&mut context, &[instruction], &[&user])
+        .await
+        .unwrap();
+
+    let referral_fees = token_balance(&mut context, &referrer_token_account).await;
+    let treasury_fees = token_balance(
+        &mut context,
+        &get_associated_token_address(&protocol.treasury.pubkey(), &protocol.onasset_mint),
+    )
+    .await;
+    assert!(referral_fees > 0);
+    assert_eq!(
+        referral_fees,
+        (referral_fees + treasury_fees) * 2000 / 10000
+    );
+    let referrer_account = get_anchor_account::<Referrer>(&mut context, &referrer).await;
+    assert_eq!(referrer_account.referred_volume, 1_000_000_000);
+}
+
+#[tokio::test]
+async fn swap_without_referrer_pays_the_treasury() {
+    let mut context = start().await;
+    let (protocol, user) = setup_swap(&mut context).await;
+
+    let instruction = swap_collateral_for_onasset(&protocol, &user.pubkey(), 1_000_000_000, vec![]);
+    process_instructions(&mut context, &[instruction], &[&user])
+        .await
+        .unwrap();
+
+    assert!(
+        token_balance(
+            &mut context,
+            &get_associated_token_address(&protocol.treasury.pubkey(), &protocol.onasset_mint),
+        )
+        .await
+            > 0
+    );
+    assert_eq!(
+        token_balance(&mut context, &protocol.vault).await,
+        1_000_000_000
+    );
+}
+
+#[tokio::test]
+async fn swap_volume_cap_rejects_swaps_until_the_window_decays() {
+    let mut context = start().await;
+    let (mut protocol, user) = setup_swap(&mut context).await;
+    protocol.pools[0].swap_volume = SwapVolume {
+        window_slots: 100,
+        max_gross_volume: 1_000_000_000,
+        ..SwapVolume::default()
+    };
+    protocol.save(&mut context).await;
+    set_associated_token_account(
+        &mut context,
+        &protocol.collateral_mint,
+        &user.pubkey(),
+        2_000_000_000,
+    );
+
+    let instruction = swap_collateral_for_onasset(&protocol, &user.pubkey(), 600_000_000, vec![]);
+    process_instructions(&mut context, &[instruction], &[&user])
+        .await
+        .unwrap();
+    let instruction = swap_collateral_for_onasset(&protocol, &user.pubkey(), 500_000_000, vec![]);
+    assert_clone_error(
+        process_instructions(&mut context, &[instruction], &[&user]).await,
+        clone::error::CloneError::VolumeCapExceeded,
+    );
+
+    protocol.warp(&mut context, 100).await;
+    assert_eq!(protocol.pools[0].swap_volume.gross_volume, 600_000_000);
+    let instruction = swap_collateral_for_onasset(&protocol, &user.pubkey(), 300_000_000, vec![]);
+    process_instructions(&mut context, &[instruction], &[&user])
+        .await
+        .unwrap();
+    protocol.load(&mut context).await;
+    assert_eq!(protocol.pools[0].swap_volume.gross_volume, 300_000_000);
+}
+
+#[tokio::test]
+async fn swap_is_rejected_past_its_expiry() {
+    let mut context = start().await;
+    let (protocol, user) = setup_swap(&mut context).await;
+    let slot = current_slot(&mut context).await;
+    let timestamp = current_timestamp(&mut context).await;
+
+    for expiry in [
+        SwapExpiry::Slot { value: slot - 1 },
+        SwapExpiry::UnixTimestamp {
+            value: timestamp - 1,
+        },
+    ] {
+        let instruction = guarded_swap_collateral_for_onasset(
+            &protocol,
+            &user.pubkey(),
+            100_000_000,
+            Some(expiry),
+            None,
+        );
+        assert_clone_error(
+            process_instructions(&mut context, &[instruction], &[&user]).await,
+            clone::error::CloneError::SwapExpired,
+        );
+    }
+
+    // Both bounds are inclusive.
+    let instructions: Vec<Instruction> = vec![
+        SwapExpiry::Slot { value: slot },
+        SwapExpiry::UnixTimestamp { value: timestamp },
+    ]
+    .into_iter()
+    .map(|expiry| {
+        guarded_swap_collateral_for_onasset(
+            &protocol,
+            &user.pubkey(),
+            100_000_000,
+            Some(expiry),
+            None,
+        )
+    })
+    .collect();
+    process_instructions(&mut context, &instructions, &[&user])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn swap_is_rejected_when_an_oracle_is_older_than_allowed() {
+    let mut context = start().await;
+    let (mut protocol, user) = setup_swap(&mut context).await;
+    let slot = current_slot(&mut context).await;
+    // The pool oracle was refreshed this slot from a feed published 5 slots ago.
+    protocol.oracles[0].last_publish_slot = slot;
+    protocol.oracles[1].last_publish_slot = slot - 5;
+    protocol.save(&mut context).await;
+
+    let instruction =
+        guarded_swap_collateral_for_onasset(&protocol, &user.pubkey(), 100_000_000, None, Some(4));
+    assert_clone_error(
+        process_instructions(&mut context, &[instruction], &[&user]).await,
+        clone::error::CloneError::OracleAgeExceeded,
+    );
+
+    let instruction =
+        guarded_swap_collateral_for_onasset(&protocol, &user.pubkey(), 100_000_000, None, Some(5));
+    process_instructions(&mut context, &[instruction], &[&user])
+        .await
+        .unwrap();
+}
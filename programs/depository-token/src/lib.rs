@@ -122,7 +122,7 @@ pub mod depository_token {
     }
 }
 
-/// ON CHAIN ACCOUNTS.
+// ON CHAIN ACCOUNTS.
 
 pub const SETTINGS_SEED: &str = "settings";
 
@@ -164,7 +164,6 @@ pub struct Initialize<'info> {
 
 #[derive(Accounts)]
 #[instruction(mint_amount: u64)]
-
 pub struct MintDepositoryToken<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
@@ -200,7 +199,6 @@ pub struct MintDepositoryToken<'info> {
 
 #[derive(Accounts)]
 #[instruction(redeem_amount: u64)]
-
 pub struct RedeemDepositoryToken<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
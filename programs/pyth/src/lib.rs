@@ -1,14 +1,16 @@
 use anchor_lang::prelude::*;
 use bytemuck::{cast_slice_mut, from_bytes_mut, try_cast_slice_mut};
-use pyth_sdk_solana::state::{AccountType, PriceAccount, PriceType, MAGIC, VERSION_2};
+use pyth_sdk_solana::state::{AccountType, PriceType, SolanaPriceAccount, MAGIC, VERSION_2};
 use std::cell::RefMut;
 
 declare_id!("CgcVKPBdW6cVDGKAKDHfN5rAoSN9m9MUuiymSrdbN27k");
 
-pub fn load_price_account<'a>(price_feed: &'a AccountInfo) -> Result<RefMut<'a, PriceAccount>> {
+pub fn load_price_account<'a>(
+    price_feed: &'a AccountInfo,
+) -> Result<RefMut<'a, SolanaPriceAccount>> {
     let raw = price_feed.try_borrow_mut_data().unwrap();
     let account_data: RefMut<'a, [u8]> = RefMut::map(raw, |data| *data);
-    let state: RefMut<'a, PriceAccount> = RefMut::map(account_data, |data| {
+    let state: RefMut<'a, SolanaPriceAccount> = RefMut::map(account_data, |data| {
         from_bytes_mut(cast_slice_mut::<u8, u8>(try_cast_slice_mut(data).unwrap()))
     });
     Ok(state)
@@ -23,7 +25,8 @@ pub mod pyth {
         price_account.prev_price = price;
         price_account.agg.price = price;
         price_account.agg.conf = 0;
-        price_account.expo = expo.into();
+        price_account.agg.pub_slot = Clock::get()?.slot;
+        price_account.expo = expo;
         price_account.magic = MAGIC;
         price_account.ptype = PriceType::Price;
         price_account.atype = AccountType::Price as u32;
@@ -35,6 +38,7 @@ pub mod pyth {
         let mut price_account = load_price_account(&ctx.accounts.price_account)?;
         price_account.prev_price = price;
         price_account.agg.price = price;
+        price_account.agg.pub_slot = Clock::get()?.slot;
         Ok(())
     }
 }
@@ -26,6 +26,17 @@ export type CloneArgs = {
   treasuryAddress: web3.PublicKey
   eventCounter: beet.bignum
   nonAuthLiquidationsEnabled: boolean
+  flashLoanFeeBps: number
+  borrowLiquidatorMaxFeeBps: number
+  borrowLiquidationCloseFactorBps: number
+  borrowLiquidationProtocolFeeBps: number
+  cometCollateralIldLiquidatorMaxFeeBps: number
+  cometOnassetIldLiquidatorMaxFeeBps: number
+  liquidationAuctionSlots: beet.bignum
+  insuranceFundFeeBps: number
+  insuranceFund: beet.bignum
+  cometLiquidationTargetHealthScore: number
+  authLiquidationGraceSlots: beet.bignum
 }
 
 export const cloneDiscriminator = [20, 243, 87, 121, 2, 202, 130, 130]
@@ -47,7 +58,18 @@ export class Clone implements CloneArgs {
     readonly borrowLiquidatorFeeBps: number,
     readonly treasuryAddress: web3.PublicKey,
     readonly eventCounter: beet.bignum,
-    readonly nonAuthLiquidationsEnabled: boolean
+    readonly nonAuthLiquidationsEnabled: boolean,
+    readonly flashLoanFeeBps: number,
+    readonly borrowLiquidatorMaxFeeBps: number,
+    readonly borrowLiquidationCloseFactorBps: number,
+    readonly borrowLiquidationProtocolFeeBps: number,
+    readonly cometCollateralIldLiquidatorMaxFeeBps: number,
+    readonly cometOnassetIldLiquidatorMaxFeeBps: number,
+    readonly liquidationAuctionSlots: beet.bignum,
+    readonly insuranceFundFeeBps: number,
+    readonly insuranceFund: beet.bignum,
+    readonly cometLiquidationTargetHealthScore: number,
+    readonly authLiquidationGraceSlots: beet.bignum
   ) {}
 
   /**
@@ -64,7 +86,18 @@ export class Clone implements CloneArgs {
       args.borrowLiquidatorFeeBps,
       args.treasuryAddress,
       args.eventCounter,
-      args.nonAuthLiquidationsEnabled
+      args.nonAuthLiquidationsEnabled,
+      args.flashLoanFeeBps,
+      args.borrowLiquidatorMaxFeeBps,
+      args.borrowLiquidationCloseFactorBps,
+      args.borrowLiquidationProtocolFeeBps,
+      args.cometCollateralIldLiquidatorMaxFeeBps,
+      args.cometOnassetIldLiquidatorMaxFeeBps,
+      args.liquidationAuctionSlots,
+      args.insuranceFundFeeBps,
+      args.insuranceFund,
+      args.cometLiquidationTargetHealthScore,
+      args.authLiquidationGraceSlots
     )
   }
 
@@ -135,36 +168,38 @@ export class Clone implements CloneArgs {
 
   /**
    * Returns the byteSize of a {@link Buffer} holding the serialized data of
-   * {@link Clone}
+   * {@link Clone} for the provided args.
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    */
-  static get byteSize() {
-    return cloneBeet.byteSize
+  static byteSize(args: CloneArgs) {
+    const instance = Clone.fromArgs(args)
+    return cloneBeet.toFixedFromValue({
+      accountDiscriminator: cloneDiscriminator,
+      ...instance,
+    }).byteSize
   }
 
   /**
    * Fetches the minimum balance needed to exempt an account holding
    * {@link Clone} data from rent
    *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    * @param connection used to retrieve the rent exemption information
    */
   static async getMinimumBalanceForRentExemption(
+    args: CloneArgs,
     connection: web3.Connection,
     commitment?: web3.Commitment
   ): Promise<number> {
     return connection.getMinimumBalanceForRentExemption(
-      Clone.byteSize,
+      Clone.byteSize(args),
       commitment
     )
   }
 
-  /**
-   * Determines if the provided {@link Buffer} has the correct byte size to
-   * hold {@link Clone} data.
-   */
-  static hasCorrectByteSize(buf: Buffer, offset = 0) {
-    return buf.byteLength - offset === Clone.byteSize
-  }
-
   /**
    * Returns a readable version of {@link Clone} properties
    * and can be used to convert to JSON and/or logging
@@ -192,6 +227,49 @@ export class Clone implements CloneArgs {
         return x
       })(),
       nonAuthLiquidationsEnabled: this.nonAuthLiquidationsEnabled,
+      flashLoanFeeBps: this.flashLoanFeeBps,
+      borrowLiquidatorMaxFeeBps: this.borrowLiquidatorMaxFeeBps,
+      borrowLiquidationCloseFactorBps: this.borrowLiquidationCloseFactorBps,
+      borrowLiquidationProtocolFeeBps: this.borrowLiquidationProtocolFeeBps,
+      cometCollateralIldLiquidatorMaxFeeBps:
+        this.cometCollateralIldLiquidatorMaxFeeBps,
+      cometOnassetIldLiquidatorMaxFeeBps:
+        this.cometOnassetIldLiquidatorMaxFeeBps,
+      liquidationAuctionSlots: (() => {
+        const x = <{ toNumber: () => number }>this.liquidationAuctionSlots
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      insuranceFundFeeBps: this.insuranceFundFeeBps,
+      insuranceFund: (() => {
+        const x = <{ toNumber: () => number }>this.insuranceFund
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      cometLiquidationTargetHealthScore: this.cometLiquidationTargetHealthScore,
+      authLiquidationGraceSlots: (() => {
+        const x = <{ toNumber: () => number }>this.authLiquidationGraceSlots
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
     }
   }
 }
@@ -200,7 +278,7 @@ export class Clone implements CloneArgs {
  * @category Accounts
  * @category generated
  */
-export const cloneBeet = new beet.BeetStruct<
+export const cloneBeet = new beet.FixableBeetStruct<
   Clone,
   CloneArgs & {
     accountDiscriminator: number[] /* size: 8 */
@@ -218,6 +296,17 @@ export const cloneBeet = new beet.BeetStruct<
     ['treasuryAddress', beetSolana.publicKey],
     ['eventCounter', beet.u64],
     ['nonAuthLiquidationsEnabled', beet.bool],
+    ['flashLoanFeeBps', beet.u16],
+    ['borrowLiquidatorMaxFeeBps', beet.u16],
+    ['borrowLiquidationCloseFactorBps', beet.u16],
+    ['borrowLiquidationProtocolFeeBps', beet.u16],
+    ['cometCollateralIldLiquidatorMaxFeeBps', beet.u16],
+    ['cometOnassetIldLiquidatorMaxFeeBps', beet.u16],
+    ['liquidationAuctionSlots', beet.u64],
+    ['insuranceFundFeeBps', beet.u16],
+    ['insuranceFund', beet.u64],
+    ['cometLiquidationTargetHealthScore', beet.u8],
+    ['authLiquidationGraceSlots', beet.u64],
   ],
   Clone.fromArgs,
   'Clone'
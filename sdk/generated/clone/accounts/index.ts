@@ -1,11 +1,25 @@
 export * from './Clone'
+export * from './Collaterals'
 export * from './Oracles'
 export * from './Pools'
+export * from './Referrer'
+export * from './SettlementReserve'
 export * from './User'
 
 import { Clone } from './Clone'
 import { Pools } from './Pools'
 import { Oracles } from './Oracles'
+import { Collaterals } from './Collaterals'
+import { SettlementReserve } from './SettlementReserve'
+import { Referrer } from './Referrer'
 import { User } from './User'
 
-export const accountProviders = { Clone, Pools, Oracles, User }
+export const accountProviders = {
+  Clone,
+  Pools,
+  Oracles,
+  Collaterals,
+  SettlementReserve,
+  Referrer,
+  User,
+}
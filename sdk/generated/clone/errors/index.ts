@@ -897,6 +897,518 @@ createErrorFromNameLookup.set(
   () => new AuthAlreadyExistsError()
 )
 
+/**
+ * FailedToLoadSwitchboard: 'Failed to load switchboard'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class FailedToLoadSwitchboardError extends Error {
+  readonly code: number = 0x1796
+  readonly name: string = 'FailedToLoadSwitchboard'
+  constructor() {
+    super('Failed to load switchboard')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, FailedToLoadSwitchboardError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1796, () => new FailedToLoadSwitchboardError())
+createErrorFromNameLookup.set(
+  'FailedToLoadSwitchboard',
+  () => new FailedToLoadSwitchboardError()
+)
+
+/**
+ * VolumeCapExceeded: 'Swap volume cap exceeded'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class VolumeCapExceededError extends Error {
+  readonly code: number = 0x1797
+  readonly name: string = 'VolumeCapExceeded'
+  constructor() {
+    super('Swap volume cap exceeded')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, VolumeCapExceededError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1797, () => new VolumeCapExceededError())
+createErrorFromNameLookup.set(
+  'VolumeCapExceeded',
+  () => new VolumeCapExceededError()
+)
+
+/**
+ * AccountMigrationRequired: 'Account must be migrated to the current layout'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AccountMigrationRequiredError extends Error {
+  readonly code: number = 0x1798
+  readonly name: string = 'AccountMigrationRequired'
+  constructor() {
+    super('Account must be migrated to the current layout')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AccountMigrationRequiredError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1798, () => new AccountMigrationRequiredError())
+createErrorFromNameLookup.set(
+  'AccountMigrationRequired',
+  () => new AccountMigrationRequiredError()
+)
+
+/**
+ * AccountAlreadyMigrated: 'Account is already in the current layout'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AccountAlreadyMigratedError extends Error {
+  readonly code: number = 0x1799
+  readonly name: string = 'AccountAlreadyMigrated'
+  constructor() {
+    super('Account is already in the current layout')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AccountAlreadyMigratedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1799, () => new AccountAlreadyMigratedError())
+createErrorFromNameLookup.set(
+  'AccountAlreadyMigrated',
+  () => new AccountAlreadyMigratedError()
+)
+
+/**
+ * AccountCapacityReached: 'Account holds its maximum number of entries'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AccountCapacityReachedError extends Error {
+  readonly code: number = 0x179a
+  readonly name: string = 'AccountCapacityReached'
+  constructor() {
+    super('Account holds its maximum number of entries')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AccountCapacityReachedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179a, () => new AccountCapacityReachedError())
+createErrorFromNameLookup.set(
+  'AccountCapacityReached',
+  () => new AccountCapacityReachedError()
+)
+
+/**
+ * InvalidReferrerAccount: 'Invalid referrer account'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidReferrerAccountError extends Error {
+  readonly code: number = 0x179b
+  readonly name: string = 'InvalidReferrerAccount'
+  constructor() {
+    super('Invalid referrer account')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidReferrerAccountError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179b, () => new InvalidReferrerAccountError())
+createErrorFromNameLookup.set(
+  'InvalidReferrerAccount',
+  () => new InvalidReferrerAccountError()
+)
+
+/**
+ * FlashLoanRepayNotFound: 'Flash loan repayment not found'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class FlashLoanRepayNotFoundError extends Error {
+  readonly code: number = 0x179c
+  readonly name: string = 'FlashLoanRepayNotFound'
+  constructor() {
+    super('Flash loan repayment not found')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, FlashLoanRepayNotFoundError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179c, () => new FlashLoanRepayNotFoundError())
+createErrorFromNameLookup.set(
+  'FlashLoanRepayNotFound',
+  () => new FlashLoanRepayNotFoundError()
+)
+
+/**
+ * FlashLoanBorrowNotFound: 'Flash loan borrow not found'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class FlashLoanBorrowNotFoundError extends Error {
+  readonly code: number = 0x179d
+  readonly name: string = 'FlashLoanBorrowNotFound'
+  constructor() {
+    super('Flash loan borrow not found')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, FlashLoanBorrowNotFoundError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179d, () => new FlashLoanBorrowNotFoundError())
+createErrorFromNameLookup.set(
+  'FlashLoanBorrowNotFound',
+  () => new FlashLoanBorrowNotFoundError()
+)
+
+/**
+ * FlashLoanCpiNotAllowed: 'Flash loans cannot be invoked via CPI'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class FlashLoanCpiNotAllowedError extends Error {
+  readonly code: number = 0x179e
+  readonly name: string = 'FlashLoanCpiNotAllowed'
+  constructor() {
+    super('Flash loans cannot be invoked via CPI')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, FlashLoanCpiNotAllowedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179e, () => new FlashLoanCpiNotAllowedError())
+createErrorFromNameLookup.set(
+  'FlashLoanCpiNotAllowed',
+  () => new FlashLoanCpiNotAllowedError()
+)
+
+/**
+ * SwapExpired: 'Swap expired'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SwapExpiredError extends Error {
+  readonly code: number = 0x179f
+  readonly name: string = 'SwapExpired'
+  constructor() {
+    super('Swap expired')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SwapExpiredError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179f, () => new SwapExpiredError())
+createErrorFromNameLookup.set('SwapExpired', () => new SwapExpiredError())
+
+/**
+ * OracleAgeExceeded: 'Oracle price older than allowed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class OracleAgeExceededError extends Error {
+  readonly code: number = 0x17a0
+  readonly name: string = 'OracleAgeExceeded'
+  constructor() {
+    super('Oracle price older than allowed')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, OracleAgeExceededError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a0, () => new OracleAgeExceededError())
+createErrorFromNameLookup.set(
+  'OracleAgeExceeded',
+  () => new OracleAgeExceededError()
+)
+
+/**
+ * InvalidCollateralIndex: 'Collateral index is not registered'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidCollateralIndexError extends Error {
+  readonly code: number = 0x17a1
+  readonly name: string = 'InvalidCollateralIndex'
+  constructor() {
+    super('Collateral index is not registered')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidCollateralIndexError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a1, () => new InvalidCollateralIndexError())
+createErrorFromNameLookup.set(
+  'InvalidCollateralIndex',
+  () => new InvalidCollateralIndexError()
+)
+
+/**
+ * DepositCapExceeded: 'Collateral deposit cap exceeded'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DepositCapExceededError extends Error {
+  readonly code: number = 0x17a2
+  readonly name: string = 'DepositCapExceeded'
+  constructor() {
+    super('Collateral deposit cap exceeded')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DepositCapExceededError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a2, () => new DepositCapExceededError())
+createErrorFromNameLookup.set(
+  'DepositCapExceeded',
+  () => new DepositCapExceededError()
+)
+
+/**
+ * InvalidBorrowCollateral: 'Onasset cannot be borrowed against itself'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidBorrowCollateralError extends Error {
+  readonly code: number = 0x17a3
+  readonly name: string = 'InvalidBorrowCollateral'
+  constructor() {
+    super('Onasset cannot be borrowed against itself')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidBorrowCollateralError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a3, () => new InvalidBorrowCollateralError())
+createErrorFromNameLookup.set(
+  'InvalidBorrowCollateral',
+  () => new InvalidBorrowCollateralError()
+)
+
+/**
+ * DebtCeilingExceeded: 'Pool debt ceiling exceeded'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DebtCeilingExceededError extends Error {
+  readonly code: number = 0x17a4
+  readonly name: string = 'DebtCeilingExceeded'
+  constructor() {
+    super('Pool debt ceiling exceeded')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DebtCeilingExceededError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a4, () => new DebtCeilingExceededError())
+createErrorFromNameLookup.set(
+  'DebtCeilingExceeded',
+  () => new DebtCeilingExceededError()
+)
+
+/**
+ * LiquidityCapExceeded: 'Pool liquidity cap exceeded'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class LiquidityCapExceededError extends Error {
+  readonly code: number = 0x17a5
+  readonly name: string = 'LiquidityCapExceeded'
+  constructor() {
+    super('Pool liquidity cap exceeded')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, LiquidityCapExceededError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a5, () => new LiquidityCapExceededError())
+createErrorFromNameLookup.set(
+  'LiquidityCapExceeded',
+  () => new LiquidityCapExceededError()
+)
+
+/**
+ * FlashLiquidationUnavailable: 'Flash liquidation requires an active pool and the base collateral'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class FlashLiquidationUnavailableError extends Error {
+  readonly code: number = 0x17a6
+  readonly name: string = 'FlashLiquidationUnavailable'
+  constructor() {
+    super('Flash liquidation requires an active pool and the base collateral')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, FlashLiquidationUnavailableError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17a6,
+  () => new FlashLiquidationUnavailableError()
+)
+createErrorFromNameLookup.set(
+  'FlashLiquidationUnavailable',
+  () => new FlashLiquidationUnavailableError()
+)
+
+/**
+ * FlashLiquidationUnprofitable: 'Collateral reward does not cover the onasset bought from the pool'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class FlashLiquidationUnprofitableError extends Error {
+  readonly code: number = 0x17a7
+  readonly name: string = 'FlashLiquidationUnprofitable'
+  constructor() {
+    super('Collateral reward does not cover the onasset bought from the pool')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, FlashLiquidationUnprofitableError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17a7,
+  () => new FlashLiquidationUnprofitableError()
+)
+createErrorFromNameLookup.set(
+  'FlashLiquidationUnprofitable',
+  () => new FlashLiquidationUnprofitableError()
+)
+
+/**
+ * LiquidationGracePeriodActive: 'Only auth keepers may liquidate during the grace period'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class LiquidationGracePeriodActiveError extends Error {
+  readonly code: number = 0x17a8
+  readonly name: string = 'LiquidationGracePeriodActive'
+  constructor() {
+    super('Only auth keepers may liquidate during the grace period')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, LiquidationGracePeriodActiveError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17a8,
+  () => new LiquidationGracePeriodActiveError()
+)
+createErrorFromNameLookup.set(
+  'LiquidationGracePeriodActive',
+  () => new LiquidationGracePeriodActiveError()
+)
+
+/**
+ * SettlementUnavailable: 'Settlement requires the collateral's settlement reserve'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SettlementUnavailableError extends Error {
+  readonly code: number = 0x17a9
+  readonly name: string = 'SettlementUnavailable'
+  constructor() {
+    super("Settlement requires the collateral's settlement reserve")
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SettlementUnavailableError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a9, () => new SettlementUnavailableError())
+createErrorFromNameLookup.set(
+  'SettlementUnavailable',
+  () => new SettlementUnavailableError()
+)
+
+/**
+ * RedemptionPeriodEnded: 'Redemption period has ended'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RedemptionPeriodEndedError extends Error {
+  readonly code: number = 0x17aa
+  readonly name: string = 'RedemptionPeriodEnded'
+  constructor() {
+    super('Redemption period has ended')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RedemptionPeriodEndedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17aa, () => new RedemptionPeriodEndedError())
+createErrorFromNameLookup.set(
+  'RedemptionPeriodEnded',
+  () => new RedemptionPeriodEndedError()
+)
+
+/**
+ * RedemptionPeriodActive: 'Pool can't be removed before its redemption deadline'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RedemptionPeriodActiveError extends Error {
+  readonly code: number = 0x17ab
+  readonly name: string = 'RedemptionPeriodActive'
+  constructor() {
+    super("Pool can't be removed before its redemption deadline")
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RedemptionPeriodActiveError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ab, () => new RedemptionPeriodActiveError())
+createErrorFromNameLookup.set(
+  'RedemptionPeriodActive',
+  () => new RedemptionPeriodActiveError()
+)
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
@@ -41,6 +41,7 @@ export const addCollateralToBorrowStruct = new beet.BeetArgsStruct<
  * @property [**signer**] user
  * @property [_writable_] userAccount
  * @property [] clone
+ * @property [_writable_] collaterals
  * @property [_writable_] vault
  * @property [_writable_] userCollateralTokenAccount
  * @category Instructions
@@ -51,6 +52,7 @@ export type AddCollateralToBorrowInstructionAccounts = {
   user: web3.PublicKey
   userAccount: web3.PublicKey
   clone: web3.PublicKey
+  collaterals: web3.PublicKey
   vault: web3.PublicKey
   userCollateralTokenAccount: web3.PublicKey
   tokenProgram?: web3.PublicKey
@@ -96,6 +98,11 @@ export function createAddCollateralToBorrowInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.collaterals,
+      isWritable: true,
+      isSigner: false,
+    },
     {
       pubkey: accounts.vault,
       isWritable: true,
@@ -15,6 +15,7 @@ import * as web3 from '@solana/web3.js'
  * @category generated
  */
 export type AddCollateralToCometInstructionArgs = {
+  collateralIndex: number
   collateralAmount: beet.bignum
 }
 /**
@@ -29,6 +30,7 @@ export const addCollateralToCometStruct = new beet.BeetArgsStruct<
 >(
   [
     ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['collateralIndex', beet.u8],
     ['collateralAmount', beet.u64],
   ],
   'AddCollateralToCometInstructionArgs'
@@ -39,6 +41,7 @@ export const addCollateralToCometStruct = new beet.BeetArgsStruct<
  * @property [**signer**] user
  * @property [_writable_] userAccount
  * @property [_writable_] clone
+ * @property [_writable_] collaterals
  * @property [_writable_] vault
  * @property [_writable_] userCollateralTokenAccount
  * @category Instructions
@@ -49,6 +52,7 @@ export type AddCollateralToCometInstructionAccounts = {
   user: web3.PublicKey
   userAccount: web3.PublicKey
   clone: web3.PublicKey
+  collaterals: web3.PublicKey
   vault: web3.PublicKey
   userCollateralTokenAccount: web3.PublicKey
   tokenProgram?: web3.PublicKey
@@ -94,6 +98,11 @@ export function createAddCollateralToCometInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.collaterals,
+      isWritable: true,
+      isSigner: false,
+    },
     {
       pubkey: accounts.vault,
       isWritable: true,
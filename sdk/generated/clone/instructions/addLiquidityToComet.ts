@@ -42,6 +42,7 @@ export const addLiquidityToCometStruct = new beet.BeetArgsStruct<
  * @property [_writable_] clone
  * @property [_writable_] pools
  * @property [_writable_] oracles
+ * @property [] collaterals
  * @category Instructions
  * @category AddLiquidityToComet
  * @category generated
@@ -52,6 +53,7 @@ export type AddLiquidityToCometInstructionAccounts = {
   clone: web3.PublicKey
   pools: web3.PublicKey
   oracles: web3.PublicKey
+  collaterals: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -104,6 +106,11 @@ export function createAddLiquidityToCometInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.collaterals,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
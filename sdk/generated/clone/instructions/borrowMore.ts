@@ -41,10 +41,12 @@ export const borrowMoreStruct = new beet.BeetArgsStruct<
  * @property [**signer**] user
  * @property [_writable_] userAccount
  * @property [_writable_] clone
- * @property [] pools
+ * @property [_writable_] pools
  * @property [] oracles
+ * @property [] collaterals
  * @property [_writable_] userOnassetTokenAccount
  * @property [_writable_] onassetMint
+ * @property [_writable_] treasuryOnassetTokenAccount
  * @category Instructions
  * @category BorrowMore
  * @category generated
@@ -55,8 +57,10 @@ export type BorrowMoreInstructionAccounts = {
   clone: web3.PublicKey
   pools: web3.PublicKey
   oracles: web3.PublicKey
+  collaterals: web3.PublicKey
   userOnassetTokenAccount: web3.PublicKey
   onassetMint: web3.PublicKey
+  treasuryOnassetTokenAccount: web3.PublicKey
   tokenProgram?: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
@@ -102,7 +106,7 @@ export function createBorrowMoreInstruction(
     },
     {
       pubkey: accounts.pools,
-      isWritable: false,
+      isWritable: true,
       isSigner: false,
     },
     {
@@ -110,6 +114,11 @@ export function createBorrowMoreInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.collaterals,
+      isWritable: false,
+      isSigner: false,
+    },
     {
       pubkey: accounts.userOnassetTokenAccount,
       isWritable: true,
@@ -120,6 +129,11 @@ export function createBorrowMoreInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.treasuryOnassetTokenAccount,
+      isWritable: true,
+      isSigner: false,
+    },
     {
       pubkey: accounts.tokenProgram ?? splToken.TOKEN_PROGRAM_ID,
       isWritable: false,
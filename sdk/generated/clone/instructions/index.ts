@@ -1,29 +1,49 @@
+export * from './addCollateral'
 export * from './addCollateralToBorrow'
 export * from './addCollateralToComet'
 export * from './addLiquidityToComet'
 export * from './addPool'
 export * from './borrowMore'
+export * from './closeSettlementReserve'
 export * from './closeUserAccount'
 export * from './collectLpRewards'
 export * from './createTokenMetadata'
+export * from './deprecatePool'
+export * from './flashBorrow'
+export * from './flashRepay'
 export * from './initializeBorrowPosition'
 export * from './initializeClone'
+export * from './initializeCollaterals'
 export * from './initializeOracles'
 export * from './initializePools'
+export * from './initializeReferrer'
+export * from './initializeSettlementReserve'
 export * from './initializeUser'
 export * from './liquidateBorrowPosition'
 export * from './liquidateCometCollateralIld'
 export * from './liquidateCometOnassetIld'
+export * from './migrateClone'
+export * from './migrateOracles'
+export * from './migratePools'
+export * from './migrateUser'
 export * from './payBorrowDebt'
 export * from './payImpermanentLossDebt'
+export * from './redeemSettledOnasset'
+export * from './redeemSettlementReserve'
 export * from './removeCometPosition'
 export * from './removePool'
+export * from './settleBorrowPosition'
+export * from './settleCometPosition'
+export * from './settlePool'
 export * from './swap'
 export * from './unwrapOnasset'
 export * from './updateCloneParameters'
+export * from './updateCollateralParameters'
+export * from './updateLiquidationAuctions'
 export * from './updateOracles'
 export * from './updatePoolParameters'
 export * from './updatePrices'
+export * from './updateReferrer'
 export * from './withdrawCollateralFromBorrow'
 export * from './withdrawCollateralFromComet'
 export * from './withdrawLiquidityFromComet'
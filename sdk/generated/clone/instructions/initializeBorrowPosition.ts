@@ -18,6 +18,7 @@ export type InitializeBorrowPositionInstructionArgs = {
   poolIndex: number
   onassetAmount: beet.bignum
   collateralAmount: beet.bignum
+  collateralIndex: number
 }
 /**
  * @category Instructions
@@ -34,6 +35,7 @@ export const initializeBorrowPositionStruct = new beet.BeetArgsStruct<
     ['poolIndex', beet.u8],
     ['onassetAmount', beet.u64],
     ['collateralAmount', beet.u64],
+    ['collateralIndex', beet.u8],
   ],
   'InitializeBorrowPositionInstructionArgs'
 )
@@ -45,6 +47,7 @@ export const initializeBorrowPositionStruct = new beet.BeetArgsStruct<
  * @property [_writable_] clone
  * @property [_writable_] pools
  * @property [_writable_] oracles
+ * @property [_writable_] collaterals
  * @property [_writable_] vault
  * @property [_writable_] userCollateralTokenAccount
  * @property [_writable_] onassetMint
@@ -59,6 +62,7 @@ export type InitializeBorrowPositionInstructionAccounts = {
   clone: web3.PublicKey
   pools: web3.PublicKey
   oracles: web3.PublicKey
+  collaterals: web3.PublicKey
   vault: web3.PublicKey
   userCollateralTokenAccount: web3.PublicKey
   onassetMint: web3.PublicKey
@@ -116,6 +120,11 @@ export function createInitializeBorrowPositionInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.collaterals,
+      isWritable: true,
+      isSigner: false,
+    },
     {
       pubkey: accounts.vault,
       isWritable: true,
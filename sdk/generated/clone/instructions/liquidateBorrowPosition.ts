@@ -19,6 +19,7 @@ export type LiquidateBorrowPositionInstructionArgs = {
   user: web3.PublicKey
   borrowIndex: number
   amount: beet.bignum
+  flash: boolean
 }
 /**
  * @category Instructions
@@ -35,6 +36,7 @@ export const liquidateBorrowPositionStruct = new beet.BeetArgsStruct<
     ['user', beetSolana.publicKey],
     ['borrowIndex', beet.u8],
     ['amount', beet.u64],
+    ['flash', beet.bool],
   ],
   'LiquidateBorrowPositionInstructionArgs'
 )
@@ -45,11 +47,14 @@ export const liquidateBorrowPositionStruct = new beet.BeetArgsStruct<
  * @property [_writable_] clone
  * @property [_writable_] pools
  * @property [_writable_] oracles
+ * @property [_writable_] collaterals
  * @property [_writable_] userAccount
  * @property [_writable_] onassetMint
  * @property [_writable_] vault
  * @property [_writable_] liquidatorCollateralTokenAccount
+ * @property [_writable_] treasuryCollateralTokenAccount
  * @property [_writable_] liquidatorOnassetTokenAccount
+ * @property [_writable_] treasuryOnassetTokenAccount
  * @category Instructions
  * @category LiquidateBorrowPosition
  * @category generated
@@ -59,11 +64,14 @@ export type LiquidateBorrowPositionInstructionAccounts = {
   clone: web3.PublicKey
   pools: web3.PublicKey
   oracles: web3.PublicKey
+  collaterals: web3.PublicKey
   userAccount: web3.PublicKey
   onassetMint: web3.PublicKey
   vault: web3.PublicKey
   liquidatorCollateralTokenAccount: web3.PublicKey
+  treasuryCollateralTokenAccount: web3.PublicKey
   liquidatorOnassetTokenAccount: web3.PublicKey
+  treasuryOnassetTokenAccount: web3.PublicKey
   tokenProgram?: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
@@ -112,6 +120,11 @@ export function createLiquidateBorrowPositionInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.collaterals,
+      isWritable: true,
+      isSigner: false,
+    },
     {
       pubkey: accounts.userAccount,
       isWritable: true,
@@ -132,11 +145,21 @@ export function createLiquidateBorrowPositionInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.treasuryCollateralTokenAccount,
+      isWritable: true,
+      isSigner: false,
+    },
     {
       pubkey: accounts.liquidatorOnassetTokenAccount,
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.treasuryOnassetTokenAccount,
+      isWritable: true,
+      isSigner: false,
+    },
     {
       pubkey: accounts.tokenProgram ?? splToken.TOKEN_PROGRAM_ID,
       isWritable: false,
@@ -44,9 +44,12 @@ export const liquidateCometCollateralIldStruct = new beet.BeetArgsStruct<
  * @property [_writable_] clone
  * @property [_writable_] pools
  * @property [] oracles
+ * @property [_writable_] collaterals
  * @property [_writable_] collateralMint
  * @property [_writable_] liquidatorCollateralTokenAccount
  * @property [_writable_] vault
+ * @property [_writable_] depositVault (optional)
+ * @property [_writable_] liquidatorDepositTokenAccount (optional)
  * @category Instructions
  * @category LiquidateCometCollateralIld
  * @category generated
@@ -57,9 +60,12 @@ export type LiquidateCometCollateralIldInstructionAccounts = {
   clone: web3.PublicKey
   pools: web3.PublicKey
   oracles: web3.PublicKey
+  collaterals: web3.PublicKey
   collateralMint: web3.PublicKey
   liquidatorCollateralTokenAccount: web3.PublicKey
   vault: web3.PublicKey
+  depositVault?: web3.PublicKey
+  liquidatorDepositTokenAccount?: web3.PublicKey
   tokenProgram?: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
@@ -71,6 +77,9 @@ export const liquidateCometCollateralIldInstructionDiscriminator = [
 /**
  * Creates a _LiquidateCometCollateralIld_ instruction.
  *
+ * Optional accounts that are not provided default to the program ID since
+ * this was indicated in the IDL from which this instruction was generated.
+ *
  * @param accounts that will be accessed while the instruction is processed
  * @param args to provide as instruction data to the program
  *
@@ -114,6 +123,11 @@ export function createLiquidateCometCollateralIldInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.collaterals,
+      isWritable: true,
+      isSigner: false,
+    },
     {
       pubkey: accounts.collateralMint,
       isWritable: true,
@@ -129,6 +143,16 @@ export function createLiquidateCometCollateralIldInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.depositVault ?? programId,
+      isWritable: accounts.depositVault != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.liquidatorDepositTokenAccount ?? programId,
+      isWritable: accounts.liquidatorDepositTokenAccount != null,
+      isSigner: false,
+    },
     {
       pubkey: accounts.tokenProgram ?? splToken.TOKEN_PROGRAM_ID,
       isWritable: false,
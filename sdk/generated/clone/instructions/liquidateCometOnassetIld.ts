@@ -19,6 +19,7 @@ export type LiquidateCometOnassetIldInstructionArgs = {
   user: web3.PublicKey
   cometPositionIndex: number
   amount: beet.bignum
+  flash: boolean
 }
 /**
  * @category Instructions
@@ -35,6 +36,7 @@ export const liquidateCometOnassetIldStruct = new beet.BeetArgsStruct<
     ['user', beetSolana.publicKey],
     ['cometPositionIndex', beet.u8],
     ['amount', beet.u64],
+    ['flash', beet.bool],
   ],
   'LiquidateCometOnassetIldInstructionArgs'
 )
@@ -46,10 +48,14 @@ export const liquidateCometOnassetIldStruct = new beet.BeetArgsStruct<
  * @property [_writable_] clone
  * @property [_writable_] pools
  * @property [] oracles
+ * @property [_writable_] collaterals
  * @property [_writable_] onassetMint
  * @property [_writable_] liquidatorOnassetTokenAccount
  * @property [_writable_] liquidatorCollateralTokenAccount
+ * @property [_writable_] treasuryOnassetTokenAccount
  * @property [_writable_] vault
+ * @property [_writable_] depositVault (optional)
+ * @property [_writable_] liquidatorDepositTokenAccount (optional)
  * @category Instructions
  * @category LiquidateCometOnassetIld
  * @category generated
@@ -60,10 +66,14 @@ export type LiquidateCometOnassetIldInstructionAccounts = {
   clone: web3.PublicKey
   pools: web3.PublicKey
   oracles: web3.PublicKey
+  collaterals: web3.PublicKey
   onassetMint: web3.PublicKey
   liquidatorOnassetTokenAccount: web3.PublicKey
   liquidatorCollateralTokenAccount: web3.PublicKey
+  treasuryOnassetTokenAccount: web3.PublicKey
   vault: web3.PublicKey
+  depositVault?: web3.PublicKey
+  liquidatorDepositTokenAccount?: web3.PublicKey
   tokenProgram?: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
@@ -75,6 +85,9 @@ export const liquidateCometOnassetIldInstructionDiscriminator = [
 /**
  * Creates a _LiquidateCometOnassetIld_ instruction.
  *
+ * Optional accounts that are not provided default to the program ID since
+ * this was indicated in the IDL from which this instruction was generated.
+ *
  * @param accounts that will be accessed while the instruction is processed
  * @param args to provide as instruction data to the program
  *
@@ -117,6 +130,11 @@ export function createLiquidateCometOnassetIldInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.collaterals,
+      isWritable: true,
+      isSigner: false,
+    },
     {
       pubkey: accounts.onassetMint,
       isWritable: true,
@@ -132,11 +150,26 @@ export function createLiquidateCometOnassetIldInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.treasuryOnassetTokenAccount,
+      isWritable: true,
+      isSigner: false,
+    },
     {
       pubkey: accounts.vault,
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.depositVault ?? programId,
+      isWritable: accounts.depositVault != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.liquidatorDepositTokenAccount ?? programId,
+      isWritable: accounts.liquidatorDepositTokenAccount != null,
+      isSigner: false,
+    },
     {
       pubkey: accounts.tokenProgram ?? splToken.TOKEN_PROGRAM_ID,
       isWritable: false,
@@ -44,9 +44,10 @@ export const payBorrowDebtStruct = new beet.BeetArgsStruct<
  * @property [**signer**] payer
  * @property [_writable_] userAccount
  * @property [_writable_] clone
- * @property [] pools
+ * @property [_writable_] pools
  * @property [_writable_] payerOnassetTokenAccount
  * @property [_writable_] onassetMint
+ * @property [_writable_] treasuryOnassetTokenAccount
  * @category Instructions
  * @category PayBorrowDebt
  * @category generated
@@ -58,6 +59,7 @@ export type PayBorrowDebtInstructionAccounts = {
   pools: web3.PublicKey
   payerOnassetTokenAccount: web3.PublicKey
   onassetMint: web3.PublicKey
+  treasuryOnassetTokenAccount: web3.PublicKey
   tokenProgram?: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
@@ -103,7 +105,7 @@ export function createPayBorrowDebtInstruction(
     },
     {
       pubkey: accounts.pools,
-      isWritable: false,
+      isWritable: true,
       isSigner: false,
     },
     {
@@ -116,6 +118,11 @@ export function createPayBorrowDebtInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.treasuryOnassetTokenAccount,
+      isWritable: true,
+      isSigner: false,
+    },
     {
       pubkey: accounts.tokenProgram ?? splToken.TOKEN_PROGRAM_ID,
       isWritable: false,
@@ -42,6 +42,8 @@ export const removePoolStruct = new beet.BeetArgsStruct<
  * @property [] underlyingAssetMint
  * @property [_writable_] underlyingAssetTokenAccount
  * @property [_writable_] treasuryAssetTokenAccount
+ * @property [_writable_] vault
+ * @property [_writable_] treasuryCollateralTokenAccount
  * @category Instructions
  * @category RemovePool
  * @category generated
@@ -53,6 +55,8 @@ export type RemovePoolInstructionAccounts = {
   underlyingAssetMint: web3.PublicKey
   underlyingAssetTokenAccount: web3.PublicKey
   treasuryAssetTokenAccount: web3.PublicKey
+  vault: web3.PublicKey
+  treasuryCollateralTokenAccount: web3.PublicKey
   tokenProgram?: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
@@ -111,6 +115,16 @@ export function createRemovePoolInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.vault,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.treasuryCollateralTokenAccount,
+      isWritable: true,
+      isSigner: false,
+    },
     {
       pubkey: accounts.tokenProgram ?? splToken.TOKEN_PROGRAM_ID,
       isWritable: false,
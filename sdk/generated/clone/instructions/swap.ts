@@ -8,6 +8,7 @@
 import * as splToken from '@solana/spl-token'
 import * as beet from '@metaplex-foundation/beet'
 import * as web3 from '@solana/web3.js'
+import { SwapExpiry, swapExpiryBeet } from '../types/SwapExpiry'
 
 /**
  * @category Instructions
@@ -20,13 +21,15 @@ export type SwapInstructionArgs = {
   quantityIsInput: boolean
   quantityIsCollateral: boolean
   resultThreshold: beet.bignum
+  expiry: beet.COption<SwapExpiry>
+  maxOracleAgeSlots: beet.COption<beet.bignum>
 }
 /**
  * @category Instructions
  * @category Swap
  * @category generated
  */
-export const swapStruct = new beet.BeetArgsStruct<
+export const swapStruct = new beet.FixableBeetArgsStruct<
   SwapInstructionArgs & {
     instructionDiscriminator: number[] /* size: 8 */
   }
@@ -38,6 +41,8 @@ export const swapStruct = new beet.BeetArgsStruct<
     ['quantityIsInput', beet.bool],
     ['quantityIsCollateral', beet.bool],
     ['resultThreshold', beet.u64],
+    ['expiry', beet.coption(swapExpiryBeet)],
+    ['maxOracleAgeSlots', beet.coption(beet.u64)],
   ],
   'SwapInstructionArgs'
 )
@@ -51,7 +56,7 @@ export const swapStruct = new beet.BeetArgsStruct<
  * @property [_writable_] userCollateralTokenAccount
  * @property [_writable_] userOnassetTokenAccount
  * @property [_writable_] onassetMint
- * @property [_writable_] collateralMint
+ * @property [] collateralMint
  * @property [_writable_] collateralVault
  * @property [_writable_] treasuryOnassetTokenAccount
  * @property [_writable_] treasuryCollateralTokenAccount
@@ -147,7 +152,7 @@ export function createSwapInstruction(
     },
     {
       pubkey: accounts.collateralMint,
-      isWritable: true,
+      isWritable: false,
       isSigner: false,
     },
     {
@@ -43,8 +43,11 @@ export const withdrawCollateralFromBorrowStruct = new beet.BeetArgsStruct<
  * @property [] clone
  * @property [_writable_] pools
  * @property [_writable_] oracles
+ * @property [_writable_] collaterals
  * @property [_writable_] vault
  * @property [_writable_] userCollateralTokenAccount
+ * @property [_writable_] onassetMint
+ * @property [_writable_] treasuryOnassetTokenAccount
  * @category Instructions
  * @category WithdrawCollateralFromBorrow
  * @category generated
@@ -55,8 +58,11 @@ export type WithdrawCollateralFromBorrowInstructionAccounts = {
   clone: web3.PublicKey
   pools: web3.PublicKey
   oracles: web3.PublicKey
+  collaterals: web3.PublicKey
   vault: web3.PublicKey
   userCollateralTokenAccount: web3.PublicKey
+  onassetMint: web3.PublicKey
+  treasuryOnassetTokenAccount: web3.PublicKey
   tokenProgram?: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
@@ -111,6 +117,11 @@ export function createWithdrawCollateralFromBorrowInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.collaterals,
+      isWritable: true,
+      isSigner: false,
+    },
     {
       pubkey: accounts.vault,
       isWritable: true,
@@ -121,6 +132,16 @@ export function createWithdrawCollateralFromBorrowInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.onassetMint,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.treasuryOnassetTokenAccount,
+      isWritable: true,
+      isSigner: false,
+    },
     {
       pubkey: accounts.tokenProgram ?? splToken.TOKEN_PROGRAM_ID,
       isWritable: false,
@@ -15,6 +15,7 @@ import * as web3 from '@solana/web3.js'
  * @category generated
  */
 export type WithdrawCollateralFromCometInstructionArgs = {
+  collateralIndex: number
   collateralAmount: beet.bignum
 }
 /**
@@ -29,6 +30,7 @@ export const withdrawCollateralFromCometStruct = new beet.BeetArgsStruct<
 >(
   [
     ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['collateralIndex', beet.u8],
     ['collateralAmount', beet.u64],
   ],
   'WithdrawCollateralFromCometInstructionArgs'
@@ -41,6 +43,7 @@ export const withdrawCollateralFromCometStruct = new beet.BeetArgsStruct<
  * @property [_writable_] clone
  * @property [_writable_] pools
  * @property [_writable_] oracles
+ * @property [_writable_] collaterals
  * @property [_writable_] vault
  * @property [_writable_] userCollateralTokenAccount
  * @category Instructions
@@ -53,6 +56,7 @@ export type WithdrawCollateralFromCometInstructionAccounts = {
   clone: web3.PublicKey
   pools: web3.PublicKey
   oracles: web3.PublicKey
+  collaterals: web3.PublicKey
   vault: web3.PublicKey
   userCollateralTokenAccount: web3.PublicKey
   tokenProgram?: web3.PublicKey
@@ -109,6 +113,11 @@ export function createWithdrawCollateralFromCometInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.collaterals,
+      isWritable: true,
+      isSigner: false,
+    },
     {
       pubkey: accounts.vault,
       isWritable: true,
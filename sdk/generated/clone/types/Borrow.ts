@@ -10,6 +10,9 @@ export type Borrow = {
   poolIndex: number
   borrowedOnasset: beet.bignum
   collateralAmount: beet.bignum
+  collateralIndex: number
+  stabilityFeeIndex: beet.bignum
+  liquidationStartSlot: beet.bignum
 }
 
 /**
@@ -21,6 +24,9 @@ export const borrowBeet = new beet.BeetArgsStruct<Borrow>(
     ['poolIndex', beet.u8],
     ['borrowedOnasset', beet.u64],
     ['collateralAmount', beet.u64],
+    ['collateralIndex', beet.u8],
+    ['stabilityFeeIndex', beet.u64],
+    ['liquidationStartSlot', beet.u64],
   ],
   'Borrow'
 )
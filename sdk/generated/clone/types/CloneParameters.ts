@@ -6,8 +6,8 @@
  */
 
 import * as web3 from '@solana/web3.js'
-import * as beetSolana from '@metaplex-foundation/beet-solana'
 import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
 /**
  * This type is used to derive the {@link CloneParameters} type as well as the de/serializer.
  * However don't refer to it in your code but use the {@link CloneParameters} type instead.
@@ -26,6 +26,16 @@ export type CloneParametersRecord = {
   TreasuryAddress: { address: web3.PublicKey }
   CollateralizationRatio: { value: number }
   NonAuthLiquidationsEnabled: { value: boolean }
+  FlashLoanFee: { value: number }
+  BorrowLiquidationMaxFee: { value: number }
+  BorrowLiquidationCloseFactor: { value: number }
+  BorrowLiquidationProtocolFee: { value: number }
+  CometCollateralLiquidationMaxFee: { value: number }
+  CometOnassetLiquidationMaxFee: { value: number }
+  LiquidationAuctionSlots: { value: beet.bignum }
+  InsuranceFundFee: { value: number }
+  CometLiquidationTargetHealthScore: { value: number }
+  AuthLiquidationGraceSlots: { value: beet.bignum }
 }
 
 /**
@@ -71,6 +81,46 @@ export const isCloneParametersNonAuthLiquidationsEnabled = (
   x: CloneParameters
 ): x is CloneParameters & { __kind: 'NonAuthLiquidationsEnabled' } =>
   x.__kind === 'NonAuthLiquidationsEnabled'
+export const isCloneParametersFlashLoanFee = (
+  x: CloneParameters
+): x is CloneParameters & { __kind: 'FlashLoanFee' } =>
+  x.__kind === 'FlashLoanFee'
+export const isCloneParametersBorrowLiquidationMaxFee = (
+  x: CloneParameters
+): x is CloneParameters & { __kind: 'BorrowLiquidationMaxFee' } =>
+  x.__kind === 'BorrowLiquidationMaxFee'
+export const isCloneParametersBorrowLiquidationCloseFactor = (
+  x: CloneParameters
+): x is CloneParameters & { __kind: 'BorrowLiquidationCloseFactor' } =>
+  x.__kind === 'BorrowLiquidationCloseFactor'
+export const isCloneParametersBorrowLiquidationProtocolFee = (
+  x: CloneParameters
+): x is CloneParameters & { __kind: 'BorrowLiquidationProtocolFee' } =>
+  x.__kind === 'BorrowLiquidationProtocolFee'
+export const isCloneParametersCometCollateralLiquidationMaxFee = (
+  x: CloneParameters
+): x is CloneParameters & { __kind: 'CometCollateralLiquidationMaxFee' } =>
+  x.__kind === 'CometCollateralLiquidationMaxFee'
+export const isCloneParametersCometOnassetLiquidationMaxFee = (
+  x: CloneParameters
+): x is CloneParameters & { __kind: 'CometOnassetLiquidationMaxFee' } =>
+  x.__kind === 'CometOnassetLiquidationMaxFee'
+export const isCloneParametersLiquidationAuctionSlots = (
+  x: CloneParameters
+): x is CloneParameters & { __kind: 'LiquidationAuctionSlots' } =>
+  x.__kind === 'LiquidationAuctionSlots'
+export const isCloneParametersInsuranceFundFee = (
+  x: CloneParameters
+): x is CloneParameters & { __kind: 'InsuranceFundFee' } =>
+  x.__kind === 'InsuranceFundFee'
+export const isCloneParametersCometLiquidationTargetHealthScore = (
+  x: CloneParameters
+): x is CloneParameters & { __kind: 'CometLiquidationTargetHealthScore' } =>
+  x.__kind === 'CometLiquidationTargetHealthScore'
+export const isCloneParametersAuthLiquidationGraceSlots = (
+  x: CloneParameters
+): x is CloneParameters & { __kind: 'AuthLiquidationGraceSlots' } =>
+  x.__kind === 'AuthLiquidationGraceSlots'
 
 /**
  * @category userTypes
@@ -146,4 +196,94 @@ export const cloneParametersBeet = beet.dataEnum<CloneParametersRecord>([
       'CloneParametersRecord["NonAuthLiquidationsEnabled"]'
     ),
   ],
+
+  [
+    'FlashLoanFee',
+    new beet.BeetArgsStruct<CloneParametersRecord['FlashLoanFee']>(
+      [['value', beet.u16]],
+      'CloneParametersRecord["FlashLoanFee"]'
+    ),
+  ],
+
+  [
+    'BorrowLiquidationMaxFee',
+    new beet.BeetArgsStruct<CloneParametersRecord['BorrowLiquidationMaxFee']>(
+      [['value', beet.u16]],
+      'CloneParametersRecord["BorrowLiquidationMaxFee"]'
+    ),
+  ],
+
+  [
+    'BorrowLiquidationCloseFactor',
+    new beet.BeetArgsStruct<
+      CloneParametersRecord['BorrowLiquidationCloseFactor']
+    >(
+      [['value', beet.u16]],
+      'CloneParametersRecord["BorrowLiquidationCloseFactor"]'
+    ),
+  ],
+
+  [
+    'BorrowLiquidationProtocolFee',
+    new beet.BeetArgsStruct<
+      CloneParametersRecord['BorrowLiquidationProtocolFee']
+    >(
+      [['value', beet.u16]],
+      'CloneParametersRecord["BorrowLiquidationProtocolFee"]'
+    ),
+  ],
+
+  [
+    'CometCollateralLiquidationMaxFee',
+    new beet.BeetArgsStruct<
+      CloneParametersRecord['CometCollateralLiquidationMaxFee']
+    >(
+      [['value', beet.u16]],
+      'CloneParametersRecord["CometCollateralLiquidationMaxFee"]'
+    ),
+  ],
+
+  [
+    'CometOnassetLiquidationMaxFee',
+    new beet.BeetArgsStruct<
+      CloneParametersRecord['CometOnassetLiquidationMaxFee']
+    >(
+      [['value', beet.u16]],
+      'CloneParametersRecord["CometOnassetLiquidationMaxFee"]'
+    ),
+  ],
+
+  [
+    'LiquidationAuctionSlots',
+    new beet.BeetArgsStruct<CloneParametersRecord['LiquidationAuctionSlots']>(
+      [['value', beet.u64]],
+      'CloneParametersRecord["LiquidationAuctionSlots"]'
+    ),
+  ],
+
+  [
+    'InsuranceFundFee',
+    new beet.BeetArgsStruct<CloneParametersRecord['InsuranceFundFee']>(
+      [['value', beet.u16]],
+      'CloneParametersRecord["InsuranceFundFee"]'
+    ),
+  ],
+
+  [
+    'CometLiquidationTargetHealthScore',
+    new beet.BeetArgsStruct<
+      CloneParametersRecord['CometLiquidationTargetHealthScore']
+    >(
+      [['value', beet.u8]],
+      'CloneParametersRecord["CometLiquidationTargetHealthScore"]'
+    ),
+  ],
+
+  [
+    'AuthLiquidationGraceSlots',
+    new beet.BeetArgsStruct<CloneParametersRecord['AuthLiquidationGraceSlots']>(
+      [['value', beet.u64]],
+      'CloneParametersRecord["AuthLiquidationGraceSlots"]'
+    ),
+  ],
 ]) as beet.FixableBeet<CloneParameters, CloneParameters>
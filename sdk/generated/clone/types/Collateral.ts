@@ -8,25 +8,36 @@
 import * as web3 from '@solana/web3.js'
 import * as beet from '@metaplex-foundation/beet'
 import * as beetSolana from '@metaplex-foundation/beet-solana'
+import { CollateralType, collateralTypeBeet } from './CollateralType'
 export type Collateral = {
   oracleInfoIndex: number
   mint: web3.PublicKey
   vault: web3.PublicKey
   collateralizationRatio: number
   scale: number
+  depositCap: beet.bignum
+  collateralType: CollateralType
+  minOvercollateralRatio: number
+  maxLiquidationOvercollateralRatio: number
+  totalDeposits: beet.bignum
 }
 
 /**
  * @category userTypes
  * @category generated
  */
-export const collateralBeet = new beet.BeetArgsStruct<Collateral>(
+export const collateralBeet = new beet.FixableBeetArgsStruct<Collateral>(
   [
     ['oracleInfoIndex', beet.u8],
     ['mint', beetSolana.publicKey],
     ['vault', beetSolana.publicKey],
     ['collateralizationRatio', beet.u8],
     ['scale', beet.u8],
+    ['depositCap', beet.u64],
+    ['collateralType', collateralTypeBeet],
+    ['minOvercollateralRatio', beet.u16],
+    ['maxLiquidationOvercollateralRatio', beet.u16],
+    ['totalDeposits', beet.u64],
   ],
   'Collateral'
 )
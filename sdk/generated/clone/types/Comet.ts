@@ -7,9 +7,12 @@
 
 import * as beet from '@metaplex-foundation/beet'
 import { LiquidityPosition, liquidityPositionBeet } from './LiquidityPosition'
+import { CollateralDeposit, collateralDepositBeet } from './CollateralDeposit'
 export type Comet = {
   collateralAmount: beet.bignum
   positions: LiquidityPosition[]
+  collateralDeposits: CollateralDeposit[]
+  liquidationStartSlot: beet.bignum
 }
 
 /**
@@ -20,6 +23,8 @@ export const cometBeet = new beet.FixableBeetArgsStruct<Comet>(
   [
     ['collateralAmount', beet.u64],
     ['positions', beet.array(liquidityPositionBeet)],
+    ['collateralDeposits', beet.array(collateralDepositBeet)],
+    ['liquidationStartSlot', beet.u64],
   ],
   'Comet'
 )
@@ -18,6 +18,7 @@ export type OracleInfo = {
   status: Status
   lastUpdateSlot: beet.bignum
   rescaleFactor: number
+  lastPublishSlot: beet.bignum
 }
 
 /**
@@ -33,6 +34,7 @@ export const oracleInfoBeet = new beet.BeetArgsStruct<OracleInfo>(
     ['status', statusBeet],
     ['lastUpdateSlot', beet.u64],
     ['rescaleFactor', beet.u8],
+    ['lastPublishSlot', beet.u64],
   ],
   'OracleInfo'
 )
@@ -10,6 +10,9 @@ import * as beet from '@metaplex-foundation/beet'
 import * as beetSolana from '@metaplex-foundation/beet-solana'
 import { AssetInfo, assetInfoBeet } from './AssetInfo'
 import { Status, statusBeet } from './Status'
+import { SwapVolume, swapVolumeBeet } from './SwapVolume'
+import { StabilityFee, stabilityFeeBeet } from './StabilityFee'
+import { Settlement, settlementBeet } from './Settlement'
 export type Pool = {
   underlyingAssetTokenAccount: web3.PublicKey
   committedCollateralLiquidity: beet.bignum
@@ -19,6 +22,12 @@ export type Pool = {
   liquidityTradingFeeBps: number
   assetInfo: AssetInfo
   status: Status
+  swapVolume: SwapVolume
+  stabilityFee: StabilityFee
+  borrowedOnasset: beet.bignum
+  debtCeiling: beet.bignum
+  maxCommittedCollateralLiquidity: beet.bignum
+  settlement: Settlement
 }
 
 /**
@@ -35,6 +44,12 @@ export const poolBeet = new beet.BeetArgsStruct<Pool>(
     ['liquidityTradingFeeBps', beet.u16],
     ['assetInfo', assetInfoBeet],
     ['status', statusBeet],
+    ['swapVolume', swapVolumeBeet],
+    ['stabilityFee', stabilityFeeBeet],
+    ['borrowedOnasset', beet.u64],
+    ['debtCeiling', beet.u64],
+    ['maxCommittedCollateralLiquidity', beet.u64],
+    ['settlement', settlementBeet],
   ],
   'Pool'
 )
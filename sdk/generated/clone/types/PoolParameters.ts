@@ -25,6 +25,12 @@ export type PoolParametersRecord = {
   MaxLiquidationOvercollateralRatio: { value: number }
   IlHealthScoreCoefficient: { value: number }
   PositionHealthScoreCoefficient: { value: number }
+  VolumeWindowSlots: { value: beet.bignum }
+  MaxNetVolume: { value: beet.bignum }
+  MaxGrossVolume: { value: beet.bignum }
+  StabilityFee: { value: number }
+  DebtCeiling: { value: beet.bignum }
+  MaxCommittedCollateralLiquidity: { value: beet.bignum }
 }
 
 /**
@@ -71,6 +77,29 @@ export const isPoolParametersPositionHealthScoreCoefficient = (
   x: PoolParameters
 ): x is PoolParameters & { __kind: 'PositionHealthScoreCoefficient' } =>
   x.__kind === 'PositionHealthScoreCoefficient'
+export const isPoolParametersVolumeWindowSlots = (
+  x: PoolParameters
+): x is PoolParameters & { __kind: 'VolumeWindowSlots' } =>
+  x.__kind === 'VolumeWindowSlots'
+export const isPoolParametersMaxNetVolume = (
+  x: PoolParameters
+): x is PoolParameters & { __kind: 'MaxNetVolume' } =>
+  x.__kind === 'MaxNetVolume'
+export const isPoolParametersMaxGrossVolume = (
+  x: PoolParameters
+): x is PoolParameters & { __kind: 'MaxGrossVolume' } =>
+  x.__kind === 'MaxGrossVolume'
+export const isPoolParametersStabilityFee = (
+  x: PoolParameters
+): x is PoolParameters & { __kind: 'StabilityFee' } =>
+  x.__kind === 'StabilityFee'
+export const isPoolParametersDebtCeiling = (
+  x: PoolParameters
+): x is PoolParameters & { __kind: 'DebtCeiling' } => x.__kind === 'DebtCeiling'
+export const isPoolParametersMaxCommittedCollateralLiquidity = (
+  x: PoolParameters
+): x is PoolParameters & { __kind: 'MaxCommittedCollateralLiquidity' } =>
+  x.__kind === 'MaxCommittedCollateralLiquidity'
 
 /**
  * @category userTypes
@@ -144,4 +173,54 @@ export const poolParametersBeet = beet.dataEnum<PoolParametersRecord>([
       'PoolParametersRecord["PositionHealthScoreCoefficient"]'
     ),
   ],
+
+  [
+    'VolumeWindowSlots',
+    new beet.BeetArgsStruct<PoolParametersRecord['VolumeWindowSlots']>(
+      [['value', beet.u64]],
+      'PoolParametersRecord["VolumeWindowSlots"]'
+    ),
+  ],
+
+  [
+    'MaxNetVolume',
+    new beet.BeetArgsStruct<PoolParametersRecord['MaxNetVolume']>(
+      [['value', beet.u64]],
+      'PoolParametersRecord["MaxNetVolume"]'
+    ),
+  ],
+
+  [
+    'MaxGrossVolume',
+    new beet.BeetArgsStruct<PoolParametersRecord['MaxGrossVolume']>(
+      [['value', beet.u64]],
+      'PoolParametersRecord["MaxGrossVolume"]'
+    ),
+  ],
+
+  [
+    'StabilityFee',
+    new beet.BeetArgsStruct<PoolParametersRecord['StabilityFee']>(
+      [['value', beet.u16]],
+      'PoolParametersRecord["StabilityFee"]'
+    ),
+  ],
+
+  [
+    'DebtCeiling',
+    new beet.BeetArgsStruct<PoolParametersRecord['DebtCeiling']>(
+      [['value', beet.u64]],
+      'PoolParametersRecord["DebtCeiling"]'
+    ),
+  ],
+
+  [
+    'MaxCommittedCollateralLiquidity',
+    new beet.BeetArgsStruct<
+      PoolParametersRecord['MaxCommittedCollateralLiquidity']
+    >(
+      [['value', beet.u64]],
+      'PoolParametersRecord["MaxCommittedCollateralLiquidity"]'
+    ),
+  ],
 ]) as beet.FixableBeet<PoolParameters, PoolParameters>
@@ -2,7 +2,20 @@ export * from './AssetInfo'
 export * from './Borrow'
 export * from './CloneParameters'
 export * from './Collateral'
+export * from './CollateralDeposit'
+export * from './CollateralParameters'
+export * from './CollateralType'
 export * from './Comet'
+export * from './FlashLoanAsset'
+export * from './LegacyBorrow'
+export * from './LegacyClone'
+export * from './LegacyCollateral'
+export * from './LegacyComet'
+export * from './LegacyOracleInfo'
+export * from './LegacyOracles'
+export * from './LegacyPool'
+export * from './LegacyPools'
+export * from './LegacyUser'
 export * from './LiquidityPosition'
 export * from './MetadataArgs'
 export * from './OracleInfo'
@@ -10,5 +23,9 @@ export * from './OracleSource'
 export * from './PaymentType'
 export * from './Pool'
 export * from './PoolParameters'
+export * from './Settlement'
+export * from './StabilityFee'
 export * from './Status'
+export * from './SwapExpiry'
+export * from './SwapVolume'
 export * from './UpdateOracleParameters'
@@ -1,39 +1,96 @@
+use anchor_lang::solana_program::account_info::AccountInfo;
 use anchor_lang::{AccountDeserialize, AnchorSerialize, Discriminator};
 use anyhow::Result;
 use clone::decimal::{BPS_SCALE, CLONE_TOKEN_SCALE};
 use clone::instruction::{Swap as CloneSwapArgs, UpdatePrices};
-use clone::instructions::{CLONE_PROGRAM_SEED, ORACLES_SEED, POOLS_SEED};
+use clone::instructions::{
+    read_oracle_price, OraclePrice, SwapExpiry, CLONE_PROGRAM_SEED, ORACLES_SEED, POOLS_SEED,
+};
 use clone::states::{Clone, Oracles, Pools, Status};
 use clone::ID as CLONE_PROGRAM_ID;
+use clone_staking::states::CloneStaking;
+use clone_staking::{CLONE_STAKING_SEED, ID as CLONE_STAKING_PROGRAM_ID, USER_SEED};
 use jupiter_amm_interface::{
     AccountMap, Amm, AmmUserSetup, KeyedAccount, Quote, QuoteParams, SwapAndAccountMetas, SwapMode,
     SwapParams,
 };
-use pyth_sdk_solana::state::{load_price_account, SolanaPriceAccount};
 use rust_decimal::prelude::*;
-use solana_sdk::account::ReadableAccount;
+use solana_sdk::account::from_account;
 use solana_sdk::clock::Clock;
 use solana_sdk::instruction::{AccountMeta, Instruction};
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::sysvar::{self};
 use spl_associated_token_account::get_associated_token_address;
 use spl_token::ID as SPL_TOKEN_PROGRAM;
+use std::str::FromStr;
 use thiserror::Error;
 
-const USDC_MINT: Pubkey = Pubkey::new_from_array([
-    198u8, 250u8, 122u8, 243u8, 190u8, 219u8, 173u8, 58u8, 61u8, 101u8, 243u8, 106u8, 171u8, 201u8,
-    116u8, 49u8, 177u8, 187u8, 228u8, 194u8, 210u8, 246u8, 224u8, 228u8, 124u8, 166u8, 2u8, 3u8,
-    69u8, 47u8, 93u8, 97u8,
-]);
+pub mod pool;
+pub mod wrap;
+
+pub use pool::*;
+pub use wrap::*;
+
+pub fn get_clone_account_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[CLONE_PROGRAM_SEED.as_ref()], program_id).0
+}
+pub fn get_pools_account_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[POOLS_SEED.as_ref()], program_id).0
+}
+pub fn get_oracles_account_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[ORACLES_SEED.as_ref()], program_id).0
+}
+pub fn get_clone_staking_account_address() -> Pubkey {
+    Pubkey::find_program_address(&[CLONE_STAKING_SEED.as_ref()], &CLONE_STAKING_PROGRAM_ID).0
+}
+pub fn get_user_staking_account_address(user: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[USER_SEED.as_ref(), user.as_ref()],
+        &CLONE_STAKING_PROGRAM_ID,
+    )
+    .0
+}
 
-pub fn get_clone_account_address() -> Pubkey {
-    Pubkey::find_program_address(&[CLONE_PROGRAM_SEED.as_ref()], &CLONE_PROGRAM_ID).0
+/// Reads an optional base58 pubkey from the keyed account params, e.g. `{"program_id": "..."}`.
+pub(crate) fn get_param_pubkey(keyed_account: &KeyedAccount, name: &str) -> Result<Option<Pubkey>> {
+    match keyed_account
+        .params
+        .as_ref()
+        .and_then(|params| params.get(name))
+    {
+        Some(value) => {
+            let pubkey =
+                value
+                    .as_str()
+                    .ok_or::<CloneInterfaceError>(CloneInterfaceError::InvalidParams(
+                        String::from(name),
+                    ))?;
+            Ok(Some(Pubkey::from_str(pubkey).map_err(|_| {
+                CloneInterfaceError::InvalidParams(String::from(name))
+            })?))
+        }
+        None => Ok(None),
+    }
 }
-pub fn get_pools_account_address() -> Pubkey {
-    Pubkey::find_program_address(&[POOLS_SEED.as_ref()], &CLONE_PROGRAM_ID).0
+
+/// Deployments other than mainnet can pass their program id as `{"program_id": "..."}`.
+pub(crate) fn get_program_id(keyed_account: &KeyedAccount) -> Result<Pubkey> {
+    Ok(get_param_pubkey(keyed_account, "program_id")?.unwrap_or(CLONE_PROGRAM_ID))
 }
-pub fn get_oracles_account_address() -> Pubkey {
-    Pubkey::find_program_address(&[ORACLES_SEED.as_ref()], &CLONE_PROGRAM_ID).0
+
+/// The threshold is a bound on the non-specified side of the swap: a minimum output
+/// for ExactIn (rounded down) and a maximum input for ExactOut (rounded up).
+fn get_result_threshold(quote: &Quote, quantity_is_input: bool, slippage_bps: u64) -> Result<u64> {
+    let slippage_decimal = Decimal::new(slippage_bps.try_into()?, BPS_SCALE);
+    Ok(if quantity_is_input {
+        (Decimal::from(quote.out_amount) * (Decimal::ONE - slippage_decimal)).floor()
+    } else {
+        (Decimal::from(quote.in_amount) * (Decimal::ONE + slippage_decimal)).ceil()
+    }
+    .to_u64()
+    .ok_or::<CloneInterfaceError>(CloneInterfaceError::TypeConversionFailed(String::from(
+        "result_threshold",
+    )))?)
 }
 
 #[derive(Clone)]
@@ -41,18 +98,35 @@ pub struct CloneInterface {
     pub clone: Option<Clone>,
     pub pools: Pools,
     pub oracles: Option<Oracles>,
-    pub pyth_prices: Option<Vec<SolanaPriceAccount>>,
+    pub clone_staking: Option<CloneStaking>,
+    pub clock: Option<Clock>,
+    pub program_id: Pubkey,
     pub key: Pubkey,
 }
 
 impl CloneInterface {
+    pub(crate) fn new(program_id: Pubkey, key: Pubkey, pools: Pools) -> Self {
+        CloneInterface {
+            clone: None,
+            pools,
+            oracles: None,
+            clone_staking: None,
+            clock: None,
+            program_id,
+            key,
+        }
+    }
+
     pub fn create_update_prices_instruction(
         &self,
         oracle_indices: Option<Vec<usize>>,
     ) -> Result<Instruction> {
-        let mut account_metas = vec![AccountMeta::new(get_oracles_account_address(), false)];
+        let mut account_metas = vec![AccountMeta::new(
+            get_oracles_account_address(&self.program_id),
+            false,
+        )];
         let oracles = self.oracles.as_ref().ok_or::<CloneInterfaceError>(
-            CloneInterfaceError::PropertyNotLoaded(String::from("oracles")).into(),
+            CloneInterfaceError::PropertyNotLoaded(String::from("oracles")),
         )?;
 
         let indices_to_update = oracle_indices.unwrap_or((0usize..oracles.oracles.len()).collect());
@@ -81,6 +155,8 @@ impl CloneInterface {
         swap_mode: SwapMode,
         slippage_bps: u64,
         quote: Option<Quote>,
+        expiry: Option<SwapExpiry>,
+        max_oracle_age_slots: Option<u64>,
     ) -> Result<Instruction> {
         // Fetch quote
         let quantity_is_input = swap_mode == SwapMode::ExactIn;
@@ -99,7 +175,7 @@ impl CloneInterface {
 
         // Create args
         let clone = self.clone.as_ref().ok_or::<CloneInterfaceError>(
-            CloneInterfaceError::PropertyNotLoaded(String::from("clone")).into(),
+            CloneInterfaceError::PropertyNotLoaded(String::from("clone")),
         )?;
 
         let input_is_collateral = clone.collateral.mint.eq(&swap_params.source_mint);
@@ -116,33 +192,16 @@ impl CloneInterface {
                     classet_mint.eq(&swap_params.source_mint)
                 }
             })
-            .ok_or::<CloneInterfaceError>(
-                CloneInterfaceError::PoolNotFound(
-                    swap_params.source_mint,
-                    swap_params.destination_mint,
-                )
-                .into(),
-            )?;
+            .ok_or::<CloneInterfaceError>(CloneInterfaceError::PoolNotFound(
+                swap_params.source_mint,
+                swap_params.destination_mint,
+            ))?;
 
         // Create args
         let quantity_is_collateral = (input_is_collateral && quantity_is_input)
             || (!input_is_collateral && !quantity_is_input);
-        let slippage_decimal = Decimal::new(slippage_bps.try_into()?, BPS_SCALE);
-        let decimal_of_result = if quantity_is_collateral {
-            CLONE_TOKEN_SCALE
-        } else {
-            clone.collateral.scale.try_into()?
-        };
-
-        let mut result_threshold_decimal = if quantity_is_input {
-            Decimal::new(updated_quote.out_amount.try_into()?, decimal_of_result)
-                * (Decimal::ONE - slippage_decimal)
-        } else {
-            Decimal::new(updated_quote.in_amount.try_into()?, decimal_of_result)
-                * (Decimal::ONE + slippage_decimal)
-        };
-        result_threshold_decimal.rescale(decimal_of_result);
-        let result_threshold = result_threshold_decimal.mantissa().try_into()?;
+        let result_threshold =
+            get_result_threshold(&updated_quote, quantity_is_input, slippage_bps)?;
 
         let args = CloneSwapArgs {
             pool_index: pool_index.try_into()?,
@@ -150,12 +209,16 @@ impl CloneInterface {
             quantity_is_input,
             quantity_is_collateral,
             result_threshold,
+            expiry,
+            max_oracle_age_slots,
         };
         let mut data: Vec<u8> = CloneSwapArgs::discriminator().into_iter().collect();
         data.extend(args.try_to_vec()?.iter());
 
-        let account_metas =
-            self.get_swap_and_account_metas(swap_params)?.account_metas[1..].to_vec();
+        let account_metas = self
+            .get_swap_and_account_metas_with_mode(swap_params, swap_mode)?
+            .account_metas[1..]
+            .to_vec();
 
         Ok(Instruction {
             program_id: self.program_id(),
@@ -164,8 +227,307 @@ impl CloneInterface {
         })
     }
 
-    fn collateral_mint(&self) -> Pubkey {
-        USDC_MINT
+    pub fn get_swap_and_account_metas_with_mode(
+        &self,
+        swap_params: &SwapParams,
+        swap_mode: SwapMode,
+    ) -> Result<SwapAndAccountMetas> {
+        let clone = self.clone.as_ref().ok_or::<CloneInterfaceError>(
+            CloneInterfaceError::PropertyNotLoaded(String::from("clone")),
+        )?;
+        let oracles = self.oracles.as_ref().ok_or::<CloneInterfaceError>(
+            CloneInterfaceError::PropertyNotLoaded(String::from("oracles")),
+        )?;
+        let input_is_collateral = clone.collateral.mint.eq(&swap_params.source_mint);
+        // The program has no routed swap, onasset to onasset trades go through the collateral.
+        if !input_is_collateral && !clone.collateral.mint.eq(&swap_params.destination_mint) {
+            return Err(CloneInterfaceError::UnsupportedTradingPair(
+                swap_params.source_mint,
+                swap_params.destination_mint,
+            )
+            .into());
+        }
+        let quantity_is_input = swap_mode == SwapMode::ExactIn;
+        let quantity_is_collateral = (input_is_collateral && quantity_is_input)
+            || (!input_is_collateral && !quantity_is_input);
+        let classet_mint = if input_is_collateral {
+            swap_params.destination_mint
+        } else {
+            swap_params.source_mint
+        };
+        let (pool_index, pool) = self
+            .pools
+            .pools
+            .iter()
+            .enumerate()
+            .find(|(_, p)| {
+                let classet_mint = p.asset_info.onasset_mint;
+                if input_is_collateral {
+                    classet_mint.eq(&swap_params.destination_mint)
+                } else {
+                    classet_mint.eq(&swap_params.source_mint)
+                }
+            })
+            .ok_or::<CloneInterfaceError>(CloneInterfaceError::PoolNotFound(
+                swap_params.source_mint,
+                swap_params.destination_mint,
+            ))?;
+
+        // program:
+        let mut account_metas = vec![AccountMeta::new_readonly(self.program_id, false)];
+        // user
+        account_metas.push(AccountMeta::new(swap_params.token_transfer_authority, true));
+        // clone
+        account_metas.push(AccountMeta::new(
+            get_clone_account_address(&self.program_id),
+            false,
+        ));
+        // pools
+        account_metas.push(AccountMeta::new(
+            get_pools_account_address(&self.program_id),
+            false,
+        ));
+        // oracles
+        account_metas.push(AccountMeta::new(
+            get_oracles_account_address(&self.program_id),
+            false,
+        ));
+        // user collateral token account
+        account_metas.push(AccountMeta::new(
+            get_associated_token_address(
+                &swap_params.token_transfer_authority,
+                &clone.collateral.mint,
+            ),
+            false,
+        ));
+        // user classet token account
+        account_metas.push(AccountMeta::new(
+            get_associated_token_address(&swap_params.token_transfer_authority, &classet_mint),
+            false,
+        ));
+        // classet mint
+        account_metas.push(AccountMeta::new(classet_mint, false));
+        // collateral mint
+        account_metas.push(AccountMeta::new_readonly(clone.collateral.mint, false));
+        // collateral vault
+        account_metas.push(AccountMeta::new(clone.collateral.vault, false));
+        // treasury classet token account
+        account_metas.push(AccountMeta::new(
+            get_associated_token_address(&clone.treasury_address, &classet_mint),
+            false,
+        ));
+        // treasury collateral token account
+        account_metas.push(AccountMeta::new(
+            get_associated_token_address(&clone.treasury_address, &clone.collateral.mint),
+            false,
+        ));
+        // token program
+        account_metas.push(AccountMeta::new_readonly(SPL_TOKEN_PROGRAM, false));
+
+        // Rest of the accounts are optional
+        // clone staking, user staking account, clone staking program
+        if self.clone_staking.is_some() {
+            account_metas.push(AccountMeta::new_readonly(
+                get_clone_staking_account_address(),
+                false,
+            ));
+            account_metas.push(AccountMeta::new_readonly(
+                get_user_staking_account_address(&swap_params.token_transfer_authority),
+                false,
+            ));
+            account_metas.push(AccountMeta::new_readonly(CLONE_STAKING_PROGRAM_ID, false));
+        } else {
+            account_metas.push(AccountMeta::new_readonly(self.program_id, false));
+            account_metas.push(AccountMeta::new_readonly(self.program_id, false));
+            account_metas.push(AccountMeta::new_readonly(self.program_id, false));
+        }
+
+        // Remaining accounts, to update the oracle struct
+        account_metas.push(AccountMeta::new_readonly(
+            oracles.oracles[clone.collateral.oracle_info_index as usize].address,
+            false,
+        ));
+        account_metas.push(AccountMeta::new_readonly(
+            oracles.oracles[pool.asset_info.oracle_info_index as usize].address,
+            false,
+        ));
+
+        Ok(SwapAndAccountMetas {
+            swap: jupiter_amm_interface::Swap::Clone {
+                pool_index: pool_index.try_into()?,
+                quantity_is_input,
+                quantity_is_collateral,
+            },
+            account_metas,
+        })
+    }
+
+    /// Returns the oracle price, rejecting feeds that were not published within
+    /// `oracle_slot_threshold` slots of the loaded clock.
+    fn get_oracle_price(&self, oracle_info_index: u8) -> Result<Decimal> {
+        let oracles = self.oracles.as_ref().ok_or::<CloneInterfaceError>(
+            CloneInterfaceError::PropertyNotLoaded(String::from("oracles")),
+        )?;
+        let oracle = &oracles.oracles[oracle_info_index as usize];
+        if let Some(clock) = &self.clock {
+            if clock.slot.saturating_sub(oracle.last_publish_slot) > self.oracle_slot_threshold() {
+                return Err(CloneInterfaceError::StaleOracle(oracle.address).into());
+            }
+        }
+        Ok(oracle.get_price()?)
+    }
+
+    /// Price impact of a quote in percent, measured against the oracle price before fees.
+    pub fn quote_price_impact_pct(
+        &self,
+        quote_params: &QuoteParams,
+        quote: &Quote,
+    ) -> Result<Decimal> {
+        let clone = self.clone.as_ref().ok_or::<CloneInterfaceError>(
+            CloneInterfaceError::PropertyNotLoaded(String::from("clone")),
+        )?;
+        let input_is_collateral = clone.collateral.mint.eq(&quote_params.input_mint);
+        let classet_mint = if input_is_collateral {
+            quote_params.output_mint
+        } else {
+            quote_params.input_mint
+        };
+        let pool = self
+            .pools
+            .pools
+            .iter()
+            .find(|p| p.asset_info.onasset_mint.eq(&classet_mint))
+            .ok_or::<CloneInterfaceError>(CloneInterfaceError::PoolNotFound(
+                quote_params.input_mint,
+                quote_params.output_mint,
+            ))?;
+
+        let collateral_price = self.get_oracle_price(clone.collateral.oracle_info_index)?;
+        let classet_price = self.get_oracle_price(pool.asset_info.oracle_info_index)?;
+        let oracle_price = classet_price / collateral_price;
+
+        let collateral_scale: u32 = clone.collateral.scale.into();
+        let (collateral_amount, classet_amount) = if input_is_collateral {
+            (
+                Decimal::new(quote.in_amount.try_into()?, collateral_scale),
+                Decimal::new(
+                    (quote.out_amount + quote.fee_amount).try_into()?,
+                    CLONE_TOKEN_SCALE,
+                ),
+            )
+        } else {
+            (
+                Decimal::new(
+                    (quote.out_amount + quote.fee_amount).try_into()?,
+                    collateral_scale,
+                ),
+                Decimal::new(quote.in_amount.try_into()?, CLONE_TOKEN_SCALE),
+            )
+        };
+        if classet_amount.is_zero() {
+            return Ok(Decimal::ZERO);
+        }
+        let execution_price = collateral_amount / classet_amount;
+
+        Ok(Decimal::ONE_HUNDRED * (execution_price - oracle_price).abs() / oracle_price)
+    }
+
+    /// Loads the program accounts and prices the oracles supplied in `account_map`,
+    /// only the collateral's and the pool's oracle when an onasset mint is given.
+    pub(crate) fn update_accounts(
+        &mut self,
+        account_map: &AccountMap,
+        onasset_mint: Option<&Pubkey>,
+    ) -> Result<()> {
+        let clone_address = get_clone_account_address(&self.program_id);
+        let clone_account = account_map
+            .get(&clone_address)
+            .ok_or::<CloneInterfaceError>(CloneInterfaceError::MissingAddress(clone_address))?;
+        let mut v = clone_account.data.as_slice();
+        let clone = Clone::try_deserialize(&mut v)?;
+
+        let pools_address = get_pools_account_address(&self.program_id);
+        let pools_account = account_map
+            .get(&pools_address)
+            .ok_or::<CloneInterfaceError>(CloneInterfaceError::MissingAddress(pools_address))?;
+        let mut v = pools_account.data.as_slice();
+        self.pools = Pools::try_deserialize(&mut v)?;
+
+        let oracles_address = get_oracles_account_address(&self.program_id);
+        let oracles_account = account_map
+            .get(&oracles_address)
+            .ok_or::<CloneInterfaceError>(CloneInterfaceError::MissingAddress(oracles_address))?;
+        let mut v = oracles_account.data.as_slice();
+        let mut oracles = Oracles::try_deserialize(&mut v)?;
+
+        let priced_oracle_indices = match onasset_mint {
+            Some(onasset_mint) => {
+                let pool = self
+                    .pools
+                    .pools
+                    .iter()
+                    .find(|pool| pool.asset_info.onasset_mint.eq(onasset_mint))
+                    .ok_or::<CloneInterfaceError>(CloneInterfaceError::PoolNotFound(
+                        *onasset_mint,
+                        *onasset_mint,
+                    ))?;
+                Some([
+                    clone.collateral.oracle_info_index as usize,
+                    pool.asset_info.oracle_info_index as usize,
+                ])
+            }
+            None => None,
+        };
+
+        // Price oracles the same way the program does before a swap,
+        // oracles without a supplied account keep their stored price.
+        for (index, oracle) in oracles.oracles.iter_mut().enumerate() {
+            if priced_oracle_indices.is_some_and(|indices| !indices.contains(&index)) {
+                continue;
+            }
+            if let Some(oracle_account) = account_map.get(&oracle.address) {
+                let mut lamports = oracle_account.lamports;
+                let mut data = oracle_account.data.clone();
+                let account_info = AccountInfo::new(
+                    &oracle.address,
+                    false,
+                    false,
+                    &mut lamports,
+                    &mut data,
+                    &oracle_account.owner,
+                    oracle_account.executable,
+                    oracle_account.rent_epoch,
+                );
+                let OraclePrice {
+                    price,
+                    expo,
+                    publish_slot,
+                } = read_oracle_price(&oracle.source, &account_info)?;
+                oracle.price = price;
+                oracle.expo = expo;
+                oracle.last_publish_slot = publish_slot;
+            }
+        }
+
+        self.clone = Some(clone);
+        self.oracles = Some(oracles);
+
+        // Staking is optional, without it quotes use the pool fees.
+        let clone_staking_address = get_clone_staking_account_address();
+        if let Some(clone_staking_account) = account_map.get(&clone_staking_address) {
+            let mut v = clone_staking_account.data.as_slice();
+            self.clone_staking = Some(CloneStaking::try_deserialize(&mut v)?);
+        }
+
+        if let Some(clock_account) = account_map.get(&sysvar::clock::ID) {
+            self.clock = from_account::<Clock, _>(clock_account);
+        }
+
+        Ok(())
+    }
+
+    fn collateral_mint(&self) -> Option<Pubkey> {
+        self.clone.as_ref().map(|clone| clone.collateral.mint)
     }
 
     fn oracle_slot_threshold(&self) -> u64 {
@@ -178,28 +540,28 @@ impl Amm for CloneInterface {
         let mut v = keyed_account.account.data.as_slice();
         let pools = Pools::try_deserialize(&mut v)?;
 
-        Ok(CloneInterface {
-            clone: None,
+        Ok(CloneInterface::new(
+            get_program_id(keyed_account)?,
+            keyed_account.key,
             pools,
-            oracles: None,
-            pyth_prices: None,
-            key: keyed_account.key,
-        })
+        ))
     }
     /// A human readable label of the underlying DEX
     fn label(&self) -> String {
         String::from("Clone Protocol")
     }
     fn program_id(&self) -> Pubkey {
-        CLONE_PROGRAM_ID
+        self.program_id
     }
     /// The pool state or market state address
     fn key(&self) -> Pubkey {
-        get_pools_account_address()
+        get_pools_account_address(&self.program_id)
     }
-    /// The mints that can be traded
+    /// The mints that can be traded, each onasset only against the collateral.
+    /// Use `ClonePoolInterface` where every advertised pair has to be quotable.
     fn get_reserve_mints(&self) -> Vec<Pubkey> {
-        let mut reserve_mints = vec![self.collateral_mint()];
+        // The collateral mint is only known once the clone account is loaded.
+        let mut reserve_mints: Vec<Pubkey> = self.collateral_mint().into_iter().collect();
         self.pools
             .pools
             .iter()
@@ -209,9 +571,11 @@ impl Amm for CloneInterface {
     /// The accounts necessary to produce a quote
     fn get_accounts_to_update(&self) -> Vec<Pubkey> {
         let mut accounts = vec![
-            get_clone_account_address(),
-            get_pools_account_address(),
-            get_oracles_account_address(),
+            get_clone_account_address(&self.program_id),
+            get_pools_account_address(&self.program_id),
+            get_oracles_account_address(&self.program_id),
+            get_clone_staking_account_address(),
+            sysvar::clock::ID,
         ];
         if let Some(oracles) = &self.oracles {
             oracles
@@ -225,55 +589,20 @@ impl Amm for CloneInterface {
     /// Picks necessary accounts to update it's internal state
     /// Heavy deserialization and precomputation caching should be done in this function
     fn update(&mut self, account_map: &AccountMap) -> Result<()> {
-        let clone_address = get_clone_account_address();
-        let clone_account = account_map
-            .get(&clone_address)
-            .ok_or::<CloneInterfaceError>(CloneInterfaceError::MissingAddress(clone_address))?;
-        let mut v = clone_account.data.as_slice();
-        self.clone = Some(Clone::try_deserialize(&mut v)?);
-
-        let pools_address = get_pools_account_address();
-        let pools_account = account_map
-            .get(&pools_address)
-            .ok_or::<CloneInterfaceError>(CloneInterfaceError::MissingAddress(pools_address))?;
-        let mut v = pools_account.data.as_slice();
-        self.pools = Pools::try_deserialize(&mut v)?;
-
-        let oracles_address = get_oracles_account_address();
-        let oracles_account = account_map
-            .get(&oracles_address)
-            .ok_or::<CloneInterfaceError>(CloneInterfaceError::MissingAddress(oracles_address))?;
-        let mut v = oracles_account.data.as_slice();
-        let oracles = Oracles::try_deserialize(&mut v)?;
-
-        let mut pyth_prices = Vec::new();
-        for info in oracles.oracles.iter() {
-            if let Some(price_account) = account_map.get(&info.address) {
-                let price_account: SolanaPriceAccount = *load_price_account(price_account.data())?;
-                pyth_prices.push(price_account)
-            }
-        }
-
-        self.oracles = Some(oracles);
-        self.pyth_prices = Some(pyth_prices);
-
-        Ok(())
+        self.update_accounts(account_map, None)
     }
 
     fn quote(&self, quote_params: &QuoteParams) -> Result<Quote> {
         let clone = self.clone.as_ref().ok_or::<CloneInterfaceError>(
-            CloneInterfaceError::PropertyNotLoaded(String::from("clone")).into(),
+            CloneInterfaceError::PropertyNotLoaded(String::from("clone")),
         )?;
-        let pyth_prices = self.pyth_prices.clone().ok_or::<CloneInterfaceError>(
-            CloneInterfaceError::PropertyNotLoaded(String::from("pyth_prices")).into(),
-        )?;
-
         let collateral_mint = clone.collateral.mint;
 
         let input_is_collateral = collateral_mint.eq(&quote_params.input_mint);
 
+        // The program has no routed swap, onasset to onasset trades go through the collateral.
         if !input_is_collateral && !collateral_mint.eq(&quote_params.output_mint) {
-            return Err(CloneInterfaceError::PoolNotFound(
+            return Err(CloneInterfaceError::UnsupportedTradingPair(
                 quote_params.input_mint,
                 quote_params.output_mint,
             )
@@ -292,31 +621,17 @@ impl Amm for CloneInterface {
                     classet_mint.eq(&quote_params.input_mint)
                 }
             })
-            .ok_or::<CloneInterfaceError>(
-                CloneInterfaceError::PoolNotFound(
-                    quote_params.input_mint,
-                    quote_params.output_mint,
-                )
-                .into(),
-            )?;
+            .ok_or::<CloneInterfaceError>(CloneInterfaceError::PoolNotFound(
+                quote_params.input_mint,
+                quote_params.output_mint,
+            ))?;
 
         if pool.status != Status::Active {
             return Err(CloneInterfaceError::PoolIsNotTradeable(pool.status).into());
         }
 
-        let collateral_price_account = pyth_prices[clone.collateral.oracle_info_index as usize];
-
-        let collateral_price = Decimal::new(
-            collateral_price_account.agg.price,
-            collateral_price_account.expo.abs() as u32,
-        );
-
-        let classet_price_account = pyth_prices[pool.asset_info.oracle_info_index as usize];
-
-        let classet_price = Decimal::new(
-            classet_price_account.agg.price,
-            classet_price_account.expo.abs() as u32,
-        );
+        let collateral_price = self.get_oracle_price(clone.collateral.oracle_info_index)?;
+        let classet_price = self.get_oracle_price(pool.asset_info.oracle_info_index)?;
 
         let quantity_is_input = quote_params.swap_mode == SwapMode::ExactIn;
         let quantity_is_collateral = (input_is_collateral && quantity_is_input)
@@ -331,6 +646,43 @@ impl Amm for CloneInterface {
             },
         );
 
+        // Quotes don't know the swapper, so they're made at the pool fees. Stakers pay their
+        // tier fees when the swap executes.
+        let fee_mint = quote_params.output_mint;
+        let fee_pct = Decimal::ONE_HUNDRED
+            * (Decimal::new(pool.liquidity_trading_fee_bps.into(), BPS_SCALE)
+                + Decimal::new(pool.treasury_trading_fee_bps.into(), BPS_SCALE));
+
+        // An exact output can only be filled while it is below the JIT pool reserve. Fees are
+        // taken from the output as well, so the pool has to pay out quantity / (1 - fee).
+        if !quantity_is_input {
+            let (pool_collateral, pool_onasset) =
+                pool.calculate_jit_pool(classet_price, collateral_price, &clone.collateral)?;
+            let output_reserve = if quantity_is_collateral {
+                pool_collateral
+            } else {
+                pool_onasset
+            };
+            let net_share = Decimal::ONE - fee_pct / Decimal::ONE_HUNDRED;
+            let gross_quantity = if net_share > Decimal::ZERO {
+                quantity.checked_div(net_share)
+            } else {
+                None
+            };
+            if gross_quantity.is_none_or(|gross_quantity| gross_quantity >= output_reserve) {
+                return Ok(Quote {
+                    not_enough_liquidity: true,
+                    min_in_amount: None,
+                    min_out_amount: None,
+                    in_amount: 0,
+                    out_amount: quote_params.amount,
+                    fee_amount: 0,
+                    fee_mint,
+                    fee_pct,
+                });
+            }
+        }
+
         let swap_result = pool.calculate_swap(
             classet_price,
             collateral_price,
@@ -345,12 +697,6 @@ impl Amm for CloneInterface {
         let fee_amount: u64 = (swap_result.liquidity_fees_paid.mantissa()
             + swap_result.treasury_fees_paid.mantissa())
         .try_into()?;
-        let fee_mint = quote_params.output_mint;
-        let fee_pct = Decimal::ONE_HUNDRED
-            * Decimal::new(
-                (pool.liquidity_trading_fee_bps + pool.treasury_trading_fee_bps) as i64,
-                BPS_SCALE,
-            );
 
         let (in_amount, out_amount) = if quantity_is_input {
             (
@@ -364,8 +710,27 @@ impl Amm for CloneInterface {
             )
         };
 
+        // The program rejects swaps that would push the pool past its rolling volume caps.
+        // Collateral flowing into the pool counts as positive net volume, treasury fees
+        // paid out of the vault count against the output.
+        let collateral_volume: i64 = if input_is_collateral {
+            in_amount.try_into()?
+        } else {
+            let treasury_fees: u64 = swap_result.treasury_fees_paid.mantissa().try_into()?;
+            -i64::try_from(out_amount + treasury_fees)?
+        };
+        let slot = self
+            .clock
+            .as_ref()
+            .map_or(pool.swap_volume.last_update_slot, |clock| clock.slot);
+        let not_enough_liquidity = pool
+            .swap_volume
+            .clone()
+            .record(collateral_volume, slot)
+            .is_err();
+
         Ok(Quote {
-            not_enough_liquidity: false,
+            not_enough_liquidity,
             min_in_amount: None,
             min_out_amount: None,
             in_amount,
@@ -377,107 +742,10 @@ impl Amm for CloneInterface {
     }
 
     /// Indicates which Swap has to be performed along with all the necessary account metas
+    /// `SwapParams` does not carry the swap mode, so routes are built as ExactIn here.
+    /// Use `get_swap_and_account_metas_with_mode` for ExactOut routes.
     fn get_swap_and_account_metas(&self, swap_params: &SwapParams) -> Result<SwapAndAccountMetas> {
-        let clone = self.clone.as_ref().ok_or::<CloneInterfaceError>(
-            CloneInterfaceError::PropertyNotLoaded(String::from("clone")).into(),
-        )?;
-        let oracles = self.oracles.as_ref().ok_or::<CloneInterfaceError>(
-            CloneInterfaceError::PropertyNotLoaded(String::from("oracles")).into(),
-        )?;
-        let input_is_collateral = clone.collateral.mint.eq(&swap_params.source_mint);
-        let classet_mint = if input_is_collateral {
-            swap_params.destination_mint
-        } else {
-            swap_params.source_mint
-        };
-        let (pool_index, pool) = self
-            .pools
-            .pools
-            .iter()
-            .enumerate()
-            .find(|(_, p)| {
-                let classet_mint = p.asset_info.onasset_mint;
-                if input_is_collateral {
-                    classet_mint.eq(&swap_params.destination_mint)
-                } else {
-                    classet_mint.eq(&swap_params.source_mint)
-                }
-            })
-            .ok_or::<CloneInterfaceError>(
-                CloneInterfaceError::PoolNotFound(
-                    swap_params.source_mint,
-                    swap_params.destination_mint,
-                )
-                .into(),
-            )?;
-
-        let mut account_metas = Vec::new();
-
-        // program:
-        account_metas.push(AccountMeta::new_readonly(CLONE_PROGRAM_ID, false));
-        // user
-        account_metas.push(AccountMeta::new(swap_params.token_transfer_authority, true));
-        // clone
-        account_metas.push(AccountMeta::new(get_clone_account_address(), false));
-        // pools
-        account_metas.push(AccountMeta::new(get_pools_account_address(), false));
-        // oracles
-        account_metas.push(AccountMeta::new(get_oracles_account_address(), false));
-        // user collateral token account
-        account_metas.push(AccountMeta::new(
-            get_associated_token_address(
-                &swap_params.token_transfer_authority,
-                &clone.collateral.mint,
-            ),
-            false,
-        ));
-        // user classet token account
-        account_metas.push(AccountMeta::new(
-            get_associated_token_address(&swap_params.token_transfer_authority, &classet_mint),
-            false,
-        ));
-        // classet mint
-        account_metas.push(AccountMeta::new(classet_mint, false));
-        // collateral mint
-        account_metas.push(AccountMeta::new_readonly(clone.collateral.mint, false));
-        // collateral vault
-        account_metas.push(AccountMeta::new(clone.collateral.vault, false));
-        // treasury classet token account
-        account_metas.push(AccountMeta::new(
-            get_associated_token_address(&clone.treasury_address, &classet_mint),
-            false,
-        ));
-        // treasury collateral token account
-        account_metas.push(AccountMeta::new(
-            get_associated_token_address(&clone.treasury_address, &clone.collateral.mint),
-            false,
-        ));
-        // token program
-        account_metas.push(AccountMeta::new_readonly(SPL_TOKEN_PROGRAM, false));
-
-        // Rest of the accounts are optional
-        account_metas.push(AccountMeta::new_readonly(CLONE_PROGRAM_ID, false));
-        account_metas.push(AccountMeta::new_readonly(CLONE_PROGRAM_ID, false));
-        account_metas.push(AccountMeta::new_readonly(CLONE_PROGRAM_ID, false));
-
-        // Remaining accounts, to update the oracle struct
-        account_metas.push(AccountMeta::new_readonly(
-            oracles.oracles[clone.collateral.oracle_info_index as usize].address,
-            false,
-        ));
-        account_metas.push(AccountMeta::new_readonly(
-            oracles.oracles[pool.asset_info.oracle_info_index as usize].address,
-            false,
-        ));
-
-        Ok(SwapAndAccountMetas {
-            swap: jupiter_amm_interface::Swap::Clone {
-                pool_index: pool_index.try_into()?,
-                quantity_is_input: true,
-                quantity_is_collateral: input_is_collateral,
-            },
-            account_metas,
-        })
+        self.get_swap_and_account_metas_with_mode(swap_params, SwapMode::ExactIn)
     }
 
     /// Indicates if get_accounts_to_update might return a non constant vec
@@ -508,7 +776,7 @@ impl Amm for CloneInterface {
     }
 
     fn requires_update_for_reserve_mints(&self) -> bool {
-        false
+        true
     }
 }
 
@@ -531,4 +799,10 @@ pub enum CloneInterfaceError {
 
     #[error("Unsupported trading pair {0} -> {1}")]
     UnsupportedTradingPair(Pubkey, Pubkey),
+
+    #[error("Oracle price is stale: {0}")]
+    StaleOracle(Pubkey),
+
+    #[error("Invalid keyed account params: {0}")]
+    InvalidParams(String),
 }
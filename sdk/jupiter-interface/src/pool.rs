@@ -0,0 +1,153 @@
+use crate::{
+    get_clone_account_address, get_clone_staking_account_address, get_oracles_account_address,
+    get_pools_account_address, get_program_id, CloneInterface, CloneInterfaceError,
+};
+use anyhow::Result;
+use clone::states::{Pool, Pools};
+use jupiter_amm_interface::{
+    AccountMap, Amm, AmmUserSetup, KeyedAccount, Quote, QuoteParams, SwapAndAccountMetas,
+    SwapParams,
+};
+use solana_sdk::program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::sysvar;
+use spl_token::state::Mint;
+
+/// Trades a single pool between the collateral and one onasset. Built from the pool's
+/// onasset mint, it only prices the two oracles of its pair instead of every oracle
+/// like `CloneInterface`.
+#[derive(Clone)]
+pub struct ClonePoolInterface {
+    pub interface: CloneInterface,
+    pub onasset_mint: Pubkey,
+}
+
+impl ClonePoolInterface {
+    pub fn pool(&self) -> Result<(usize, &Pool)> {
+        self.interface
+            .pools
+            .pools
+            .iter()
+            .enumerate()
+            .find(|(_, pool)| pool.asset_info.onasset_mint.eq(&self.onasset_mint))
+            .ok_or(CloneInterfaceError::PoolNotFound(self.onasset_mint, self.onasset_mint).into())
+    }
+
+    fn check_pair(&self, input_mint: &Pubkey, output_mint: &Pubkey) -> Result<()> {
+        let reserve_mints = self.get_reserve_mints();
+        if input_mint.eq(output_mint)
+            || !reserve_mints.contains(input_mint)
+            || !reserve_mints.contains(output_mint)
+        {
+            return Err(
+                CloneInterfaceError::UnsupportedTradingPair(*input_mint, *output_mint).into(),
+            );
+        }
+        Ok(())
+    }
+}
+
+impl Amm for ClonePoolInterface {
+    fn from_keyed_account(keyed_account: &KeyedAccount) -> Result<Self> {
+        Mint::unpack(&keyed_account.account.data)?;
+
+        Ok(ClonePoolInterface {
+            interface: CloneInterface::new(
+                get_program_id(keyed_account)?,
+                keyed_account.key,
+                Pools { pools: Vec::new() },
+            ),
+            onasset_mint: keyed_account.key,
+        })
+    }
+    /// A human readable label of the underlying DEX
+    fn label(&self) -> String {
+        self.interface.label()
+    }
+    fn program_id(&self) -> Pubkey {
+        self.interface.program_id()
+    }
+    /// The onasset mint identifies the pool.
+    fn key(&self) -> Pubkey {
+        self.onasset_mint
+    }
+    /// The mints that can be traded
+    fn get_reserve_mints(&self) -> Vec<Pubkey> {
+        let mut reserve_mints: Vec<Pubkey> = self
+            .interface
+            .clone
+            .as_ref()
+            .map(|clone| clone.collateral.mint)
+            .into_iter()
+            .collect();
+        reserve_mints.push(self.onasset_mint);
+        reserve_mints
+    }
+    /// The accounts necessary to produce a quote
+    fn get_accounts_to_update(&self) -> Vec<Pubkey> {
+        let program_id = self.interface.program_id();
+        let mut accounts = vec![
+            get_clone_account_address(&program_id),
+            get_pools_account_address(&program_id),
+            get_oracles_account_address(&program_id),
+            get_clone_staking_account_address(),
+            sysvar::clock::ID,
+        ];
+        if let (Some(clone), Some(oracles), Ok((_, pool))) =
+            (&self.interface.clone, &self.interface.oracles, self.pool())
+        {
+            accounts.push(oracles.oracles[clone.collateral.oracle_info_index as usize].address);
+            accounts.push(oracles.oracles[pool.asset_info.oracle_info_index as usize].address);
+        }
+        accounts
+    }
+
+    /// Picks necessary accounts to update it's internal state
+    /// Heavy deserialization and precomputation caching should be done in this function
+    fn update(&mut self, account_map: &AccountMap) -> Result<()> {
+        self.interface
+            .update_accounts(account_map, Some(&self.onasset_mint))
+    }
+
+    fn quote(&self, quote_params: &QuoteParams) -> Result<Quote> {
+        self.check_pair(&quote_params.input_mint, &quote_params.output_mint)?;
+        self.interface.quote(quote_params)
+    }
+
+    /// Indicates which Swap has to be performed along with all the necessary account metas
+    fn get_swap_and_account_metas(&self, swap_params: &SwapParams) -> Result<SwapAndAccountMetas> {
+        self.check_pair(&swap_params.source_mint, &swap_params.destination_mint)?;
+        self.interface.get_swap_and_account_metas(swap_params)
+    }
+
+    /// Indicates if get_accounts_to_update might return a non constant vec
+    fn has_dynamic_accounts(&self) -> bool {
+        true
+    }
+
+    fn get_user_setup(&self) -> Option<AmmUserSetup> {
+        None
+    }
+
+    fn clone_amm(&self) -> Box<dyn Amm + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    /// It can only trade in one direction from its first mint to second mint, assuming it is a two mint AMM
+    fn unidirectional(&self) -> bool {
+        false
+    }
+
+    /// For testing purposes, provide a mapping of dependency programs to function
+    fn program_dependencies(&self) -> Vec<(Pubkey, String)> {
+        vec![]
+    }
+
+    fn get_accounts_len(&self) -> usize {
+        20
+    }
+
+    fn requires_update_for_reserve_mints(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{
+    get_clone_account_address, get_pools_account_address, get_program_id, CloneInterfaceError,
+};
+use anchor_lang::{AccountDeserialize, AnchorSerialize, Discriminator};
+use anyhow::Result;
+use clone::decimal::CLONE_TOKEN_SCALE;
+use clone::instruction::{UnwrapOnasset, WrapAsset};
+use clone::states::{Pool, Pools, Status};
+use jupiter_amm_interface::{AccountMap, KeyedAccount, Quote, QuoteParams, SwapMode, SwapParams};
+use rust_decimal::prelude::*;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+use spl_associated_token_account::get_associated_token_address;
+use spl_token::state::{Account as TokenAccount, Mint};
+use spl_token::ID as SPL_TOKEN_PROGRAM;
+
+/// Converts a token amount between scales, rounding up when `round_up` is set.
+fn convert_amount(amount: u64, from_scale: u32, to_scale: u32, round_up: bool) -> Result<u64> {
+    let strategy = if round_up {
+        RoundingStrategy::AwayFromZero
+    } else {
+        RoundingStrategy::ToZero
+    };
+    let mut value =
+        Decimal::new(amount.try_into()?, from_scale).round_dp_with_strategy(to_scale, strategy);
+    value.rescale(to_scale);
+    Ok(value.mantissa().try_into()?)
+}
+
+/// Quotes and builds `wrap_asset`/`unwrap_onasset` instructions, which convert between a
+/// pool's underlying asset and its onasset 1:1 without fees. Jupiter has no swap variant
+/// for them, so this is not an Amm and callers add the instruction to their transaction.
+/// Built from the pool's underlying asset token account, which identifies the pool and
+/// holds what unwraps pay out.
+#[derive(Clone)]
+pub struct CloneWrapInterface {
+    pub underlying_token_account: TokenAccount,
+    pub pool: Option<(usize, Pool)>,
+    pub underlying_decimals: Option<u8>,
+    pub program_id: Pubkey,
+    pub key: Pubkey,
+}
+
+impl CloneWrapInterface {
+    pub fn from_keyed_account(keyed_account: &KeyedAccount) -> Result<Self> {
+        Ok(CloneWrapInterface {
+            underlying_token_account: TokenAccount::unpack(&keyed_account.account.data)?,
+            pool: None,
+            underlying_decimals: None,
+            program_id: get_program_id(keyed_account)?,
+            key: keyed_account.key,
+        })
+    }
+
+    /// The underlying asset mint, followed by the onasset mint once the pool is loaded.
+    pub fn get_reserve_mints(&self) -> Vec<Pubkey> {
+        let mut reserve_mints = vec![self.underlying_token_account.mint];
+        if let Ok((_, pool)) = self.pool() {
+            reserve_mints.push(pool.asset_info.onasset_mint);
+        }
+        reserve_mints
+    }
+
+    /// The accounts necessary to produce a quote
+    pub fn get_accounts_to_update(&self) -> Vec<Pubkey> {
+        vec![
+            self.key,
+            get_pools_account_address(&self.program_id),
+            self.underlying_token_account.mint,
+        ]
+    }
+
+    pub fn update(&mut self, account_map: &AccountMap) -> Result<()> {
+        let token_account = account_map
+            .get(&self.key)
+            .ok_or::<CloneInterfaceError>(CloneInterfaceError::MissingAddress(self.key))?;
+        self.underlying_token_account = TokenAccount::unpack(&token_account.data)?;
+
+        let pools_address = get_pools_account_address(&self.program_id);
+        let pools_account = account_map
+            .get(&pools_address)
+            .ok_or::<CloneInterfaceError>(CloneInterfaceError::MissingAddress(pools_address))?;
+        let mut v = pools_account.data.as_slice();
+        self.pool = Pools::try_deserialize(&mut v)?
+            .pools
+            .into_iter()
+            .enumerate()
+            .find(|(_, pool)| pool.underlying_asset_token_account.eq(&self.key));
+        self.pool()?;
+
+        let asset_mint = self.underlying_token_account.mint;
+        let mint_account = account_map
+            .get(&asset_mint)
+            .ok_or::<CloneInterfaceError>(CloneInterfaceError::MissingAddress(asset_mint))?;
+        self.underlying_decimals = Some(Mint::unpack(&mint_account.data)?.decimals);
+
+        Ok(())
+    }
+
+    pub fn pool(&self) -> Result<(usize, &Pool)> {
+        self.pool
+            .as_ref()
+            .map(|(pool_index, pool)| (*pool_index, pool))
+            .ok_or(CloneInterfaceError::PropertyNotLoaded(String::from("pools")).into())
+    }
+
+    /// Returns whether the swap wraps the underlying asset, erroring on other pairs.
+    fn is_wrap(&self, input_mint: &Pubkey, output_mint: &Pubkey) -> Result<bool> {
+        let (_, pool) = self.pool()?;
+        let asset_mint = self.underlying_token_account.mint;
+        let onasset_mint = pool.asset_info.onasset_mint;
+        if asset_mint.eq(input_mint) && onasset_mint.eq(output_mint) {
+            Ok(true)
+        } else if onasset_mint.eq(input_mint) && asset_mint.eq(output_mint) {
+            Ok(false)
+        } else {
+            Err(CloneInterfaceError::UnsupportedTradingPair(*input_mint, *output_mint).into())
+        }
+    }
+
+    /// Mirrors the status checks of `wrap_asset` and `unwrap_onasset`.
+    fn is_tradeable(pool: &Pool, is_wrap: bool) -> bool {
+        if is_wrap {
+            pool.status != Status::Frozen && pool.status != Status::Deprecation
+        } else {
+            pool.status == Status::Active || pool.status == Status::Deprecation
+        }
+    }
+
+    pub fn quote(&self, quote_params: &QuoteParams) -> Result<Quote> {
+        let is_wrap = self.is_wrap(&quote_params.input_mint, &quote_params.output_mint)?;
+        let (_, pool) = self.pool()?;
+        if !Self::is_tradeable(pool, is_wrap) {
+            return Err(CloneInterfaceError::PoolIsNotTradeable(pool.status).into());
+        }
+
+        let underlying_decimals: u32 = self
+            .underlying_decimals
+            .ok_or::<CloneInterfaceError>(CloneInterfaceError::PropertyNotLoaded(String::from(
+                "asset_mint",
+            )))?
+            .into();
+        let (input_scale, output_scale) = if is_wrap {
+            (underlying_decimals, CLONE_TOKEN_SCALE)
+        } else {
+            (CLONE_TOKEN_SCALE, underlying_decimals)
+        };
+        let in_amount = if quote_params.swap_mode == SwapMode::ExactIn {
+            quote_params.amount
+        } else {
+            convert_amount(quote_params.amount, output_scale, input_scale, true)?
+        };
+        let out_amount = convert_amount(in_amount, input_scale, output_scale, false)?;
+
+        // Unwraps are paid out of the pool's underlying asset token account.
+        let not_enough_liquidity = !is_wrap && out_amount > self.underlying_token_account.amount;
+
+        Ok(Quote {
+            not_enough_liquidity,
+            min_in_amount: None,
+            min_out_amount: None,
+            in_amount,
+            out_amount,
+            fee_amount: 0,
+            fee_mint: quote_params.output_mint,
+            fee_pct: Decimal::ZERO,
+        })
+    }
+
+    pub fn get_account_metas(&self, swap_params: &SwapParams) -> Result<Vec<AccountMeta>> {
+        let (_, pool) = self.pool()?;
+        let asset_mint = self.underlying_token_account.mint;
+        let onasset_mint = pool.asset_info.onasset_mint;
+
+        Ok(vec![
+            // user
+            AccountMeta::new_readonly(swap_params.token_transfer_authority, true),
+            // clone
+            AccountMeta::new_readonly(get_clone_account_address(&self.program_id), false),
+            // pools
+            AccountMeta::new_readonly(get_pools_account_address(&self.program_id), false),
+            // underlying asset token account
+            AccountMeta::new(self.key, false),
+            // asset mint
+            AccountMeta::new_readonly(asset_mint, false),
+            // user asset token account
+            AccountMeta::new(
+                get_associated_token_address(&swap_params.token_transfer_authority, &asset_mint),
+                false,
+            ),
+            // onasset mint
+            AccountMeta::new(onasset_mint, false),
+            // user onasset token account
+            AccountMeta::new(
+                get_associated_token_address(&swap_params.token_transfer_authority, &onasset_mint),
+                false,
+            ),
+            // token program
+            AccountMeta::new_readonly(SPL_TOKEN_PROGRAM, false),
+        ])
+    }
+
+    /// Both instructions take the input amount, ExactOut swaps are converted using a quote.
+    pub fn create_wrap_instruction(
+        &self,
+        swap_params: &SwapParams,
+        swap_mode: SwapMode,
+    ) -> Result<Instruction> {
+        let is_wrap = self.is_wrap(&swap_params.source_mint, &swap_params.destination_mint)?;
+        let (_, pool) = self.pool()?;
+        if !Self::is_tradeable(pool, is_wrap) {
+            return Err(CloneInterfaceError::PoolIsNotTradeable(pool.status).into());
+        }
+        let amount = if swap_mode == SwapMode::ExactIn {
+            swap_params.in_amount
+        } else {
+            self.quote(&QuoteParams {
+                amount: swap_params.out_amount,
+                input_mint: swap_params.source_mint,
+                output_mint: swap_params.destination_mint,
+                swap_mode,
+            })?
+            .in_amount
+        };
+        let pool_index: u8 = self.pool()?.0.try_into()?;
+
+        let data: Vec<u8> = if is_wrap {
+            let mut data: Vec<u8> = WrapAsset::discriminator().into_iter().collect();
+            data.extend(WrapAsset { amount, pool_index }.try_to_vec()?.iter());
+            data
+        } else {
+            let mut data: Vec<u8> = UnwrapOnasset::discriminator().into_iter().collect();
+            data.extend(UnwrapOnasset { amount, pool_index }.try_to_vec()?.iter());
+            data
+        };
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts: self.get_account_metas(swap_params)?,
+            data,
+        })
+    }
+}
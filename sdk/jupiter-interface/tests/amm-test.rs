@@ -0,0 +1,567 @@
+/*
+    Tests of the Amm trait implementations against account data built in memory.
+    No program runs here, the interfaces are loaded the way Jupiter loads them, through
+    `from_keyed_account` and `update`, and then routed through the trait.
+*/
+
+use anchor_lang::AccountSerialize;
+use anchor_lang::Discriminator;
+use anyhow::Result;
+use clone::instruction::{UnwrapOnasset, WrapAsset};
+use clone::states::{
+    AssetInfo, Clone, Collateral, OracleInfo, Oracles, Pool, Pools, Status, SwapVolume,
+};
+use clone::ID;
+use clone_staking::states::{CloneStaking, Tier, MAX_TIERS};
+use jupiter_amm_interface::{
+    AccountMap, Amm, KeyedAccount, QuoteParams, Swap, SwapMode, SwapParams,
+};
+use solana_sdk::account::Account;
+use solana_sdk::program_option::COption;
+use solana_sdk::program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+use spl_token::state::{Account as TokenAccount, AccountState, Mint};
+
+extern crate jupiter_interface;
+use jupiter_interface::*;
+
+const COLLATERAL_SCALE: u8 = 7;
+const PRICE_EXPO: u8 = 8;
+
+struct Market {
+    clone: Clone,
+    pools: Pools,
+    oracles: Oracles,
+    collateral_mint: Pubkey,
+    onasset_mints: Vec<Pubkey>,
+}
+
+fn oracle(price: i64) -> OracleInfo {
+    OracleInfo {
+        address: Pubkey::new_unique(),
+        price: price * 10i64.pow(PRICE_EXPO.into()),
+        expo: PRICE_EXPO,
+        status: Status::Active,
+        ..OracleInfo::default()
+    }
+}
+
+// A collateral priced at 1 and one pool per onasset price, each with the same liquidity.
+fn market(onasset_prices: &[i64]) -> Market {
+    let collateral_mint = Pubkey::new_unique();
+    let onasset_mints: Vec<Pubkey> = onasset_prices
+        .iter()
+        .map(|_| Pubkey::new_unique())
+        .collect();
+    let clone = Clone {
+        collateral: Collateral {
+            oracle_info_index: 0,
+            mint: collateral_mint,
+            vault: Pubkey::new_unique(),
+            collateralization_ratio: 100,
+            scale: COLLATERAL_SCALE,
+            ..Collateral::default()
+        },
+        treasury_address: Pubkey::new_unique(),
+        ..Clone::default()
+    };
+    let pools = onasset_mints
+        .iter()
+        .enumerate()
+        .map(|(index, onasset_mint)| Pool {
+            underlying_asset_token_account: Pubkey::new_unique(),
+            committed_collateral_liquidity: 10_000_000_000,
+            treasury_trading_fee_bps: 50,
+            liquidity_trading_fee_bps: 50,
+            asset_info: AssetInfo {
+                onasset_mint: *onasset_mint,
+                oracle_info_index: (index + 1) as u8,
+                ..AssetInfo::default()
+            },
+            status: Status::Active,
+            ..Pool::default()
+        })
+        .collect();
+    let mut oracles = vec![oracle(1)];
+    oracles.extend(onasset_prices.iter().map(|price| oracle(*price)));
+    Market {
+        clone,
+        pools: Pools { pools },
+        oracles: Oracles { oracles },
+        collateral_mint,
+        onasset_mints,
+    }
+}
+
+fn anchor_account<T: AccountSerialize>(state: &T) -> Account {
+    let mut data = Vec::new();
+    state.try_serialize(&mut data).expect("serialize");
+    Account {
+        lamports: 1,
+        data,
+        owner: ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+fn account_map(market: &Market) -> AccountMap {
+    let mut account_map = AccountMap::new();
+    account_map.insert(
+        get_clone_account_address(&ID),
+        anchor_account(&market.clone),
+    );
+    account_map.insert(
+        get_pools_account_address(&ID),
+        anchor_account(&market.pools),
+    );
+    account_map.insert(
+        get_oracles_account_address(&ID),
+        anchor_account(&market.oracles),
+    );
+    account_map
+}
+
+fn load_clone_interface(market: &Market) -> Result<CloneInterface> {
+    let pools_address = get_pools_account_address(&ID);
+    let mut clone_interface = CloneInterface::from_keyed_account(&KeyedAccount {
+        key: pools_address,
+        account: anchor_account(&market.pools),
+        params: None,
+    })?;
+    clone_interface.update(&account_map(market))?;
+    Ok(clone_interface)
+}
+
+fn token_account(mint: Pubkey, amount: u64) -> Account {
+    let mut data = vec![0; TokenAccount::LEN];
+    TokenAccount::pack(
+        TokenAccount {
+            mint,
+            owner: Pubkey::new_unique(),
+            amount,
+            state: AccountState::Initialized,
+            ..TokenAccount::default()
+        },
+        &mut data,
+    )
+    .expect("pack");
+    Account {
+        lamports: 1,
+        data,
+        owner: spl_token::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+fn mint_account(decimals: u8) -> Account {
+    let mut data = vec![0; Mint::LEN];
+    Mint::pack(
+        Mint {
+            mint_authority: COption::None,
+            decimals,
+            is_initialized: true,
+            ..Mint::default()
+        },
+        &mut data,
+    )
+    .expect("pack");
+    Account {
+        lamports: 1,
+        data,
+        owner: spl_token::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+// Wraps the first pool of `market` over an underlying asset with 6 decimals.
+fn load_wrap_interface(
+    market: &Market,
+    underlying_amount: u64,
+) -> Result<(CloneWrapInterface, Pubkey)> {
+    let key = market.pools.pools[0].underlying_asset_token_account;
+    let asset_mint = Pubkey::new_unique();
+    let underlying_token_account = token_account(asset_mint, underlying_amount);
+    let mut wrap_interface = CloneWrapInterface::from_keyed_account(&KeyedAccount {
+        key,
+        account: underlying_token_account.clone(),
+        params: None,
+    })?;
+    let mut account_map = account_map(market);
+    account_map.insert(key, underlying_token_account);
+    account_map.insert(asset_mint, mint_account(6));
+    assert!(wrap_interface
+        .get_accounts_to_update()
+        .iter()
+        .all(|address| account_map.contains_key(address)));
+    wrap_interface.update(&account_map)?;
+    Ok((wrap_interface, asset_mint))
+}
+
+fn swap_params<'a>(
+    input_mint: Pubkey,
+    output_mint: Pubkey,
+    user: Pubkey,
+    jupiter_program_id: &'a Pubkey,
+) -> SwapParams<'a, 'a> {
+    SwapParams {
+        in_amount: 1_000_000,
+        out_amount: 1_000_000,
+        source_mint: input_mint,
+        destination_mint: output_mint,
+        source_token_account: Pubkey::new_unique(),
+        destination_token_account: Pubkey::new_unique(),
+        token_transfer_authority: user,
+        open_order_address: None,
+        quote_mint_to_referrer: None,
+        jupiter_program_id,
+        missing_dynamic_accounts_as_default: false,
+    }
+}
+
+fn clone_swap_flags(swap: Swap) -> (bool, bool) {
+    match swap {
+        Swap::Clone {
+            quantity_is_input,
+            quantity_is_collateral,
+            ..
+        } => (quantity_is_input, quantity_is_collateral),
+        _ => panic!("expected a Clone swap"),
+    }
+}
+
+#[test]
+fn routes_follow_the_swap_mode_they_are_built_with() -> Result<()> {
+    let market = market(&[20]);
+    let clone_interface = load_clone_interface(&market)?;
+    let jupiter_program_id = Pubkey::new_unique();
+    let user = Pubkey::new_unique();
+
+    for (swap_mode, input_mint, output_mint) in [
+        (
+            SwapMode::ExactIn,
+            market.collateral_mint,
+            market.onasset_mints[0],
+        ),
+        (
+            SwapMode::ExactOut,
+            market.collateral_mint,
+            market.onasset_mints[0],
+        ),
+        (
+            SwapMode::ExactIn,
+            market.onasset_mints[0],
+            market.collateral_mint,
+        ),
+        (
+            SwapMode::ExactOut,
+            market.onasset_mints[0],
+            market.collateral_mint,
+        ),
+    ] {
+        let swap_params = swap_params(input_mint, output_mint, user, &jupiter_program_id);
+
+        let input_is_collateral = input_mint == market.collateral_mint;
+
+        // The trait only gets the swap params, so it always routes ExactIn.
+        let (quantity_is_input, quantity_is_collateral) = clone_swap_flags(
+            clone_interface
+                .get_swap_and_account_metas(&swap_params)?
+                .swap,
+        );
+        assert!(quantity_is_input);
+        assert_eq!(quantity_is_collateral, input_is_collateral);
+
+        let (quantity_is_input, quantity_is_collateral) = clone_swap_flags(
+            clone_interface
+                .get_swap_and_account_metas_with_mode(&swap_params, swap_mode)?
+                .swap,
+        );
+        assert_eq!(quantity_is_input, swap_mode == SwapMode::ExactIn);
+        assert_eq!(
+            quantity_is_collateral,
+            input_is_collateral == quantity_is_input
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn swaps_pass_the_staking_account_of_the_swapper() -> Result<()> {
+    let market = market(&[20]);
+    let mut clone_interface = load_clone_interface(&market)?;
+    let jupiter_program_id = Pubkey::new_unique();
+    let user = Pubkey::new_unique();
+    let swap_params = swap_params(
+        market.collateral_mint,
+        market.onasset_mints[0],
+        user,
+        &jupiter_program_id,
+    );
+    let staking_accounts = |clone_interface: &CloneInterface| -> Result<Vec<Pubkey>> {
+        let account_metas = clone_interface
+            .get_swap_and_account_metas(&swap_params)?
+            .account_metas;
+        Ok(account_metas[13..16]
+            .iter()
+            .map(|meta| meta.pubkey)
+            .collect())
+    };
+
+    // Without a staking program the optional accounts are left out.
+    assert_eq!(staking_accounts(&clone_interface)?, vec![ID; 3]);
+
+    let mut account_map = account_map(&market);
+    account_map.insert(
+        get_clone_staking_account_address(),
+        anchor_account(&CloneStaking {
+            admin: Pubkey::new_unique(),
+            cln_token_mint: Pubkey::new_unique(),
+            cln_token_vault: Pubkey::new_unique(),
+            staking_period_slots: 0,
+            bump: 0,
+            num_tiers: 0,
+            tiers: [Tier::default(); MAX_TIERS],
+        }),
+    );
+    clone_interface.update(&account_map)?;
+    assert_eq!(
+        staking_accounts(&clone_interface)?,
+        vec![
+            get_clone_staking_account_address(),
+            get_user_staking_account_address(&user),
+            clone_staking::ID,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn exact_out_beyond_the_reserve_after_fees_is_not_enough_liquidity() -> Result<()> {
+    // The pool holds 1000 collateral and 50 onasset, and charges 1% on the output.
+    let market = market(&[20]);
+    let clone_interface = load_clone_interface(&market)?;
+    let quote_onasset_out = |amount: u64| {
+        clone_interface.quote(&QuoteParams {
+            amount,
+            input_mint: market.collateral_mint,
+            output_mint: market.onasset_mints[0],
+            swap_mode: SwapMode::ExactOut,
+        })
+    };
+
+    // 49.8 onasset is below the reserve but needs 49.8 / 0.99 > 50 before fees.
+    assert!(quote_onasset_out(4_980_000_000)?.not_enough_liquidity);
+    assert!(quote_onasset_out(5_000_000_000)?.not_enough_liquidity);
+
+    let quote = quote_onasset_out(4_900_000_000)?;
+    assert!(!quote.not_enough_liquidity);
+    assert_eq!(quote.out_amount, 4_900_000_000);
+    assert!(quote.in_amount > 0);
+
+    Ok(())
+}
+
+#[test]
+fn quotes_past_the_swap_volume_cap_are_not_enough_liquidity() -> Result<()> {
+    // 100 collateral of gross volume per window, with 60 already swapped this window.
+    let mut market = market(&[20]);
+    market.pools.pools[0].swap_volume = SwapVolume {
+        window_slots: 1_000,
+        max_gross_volume: 1_000_000_000,
+        gross_volume: 600_000_000,
+        ..SwapVolume::default()
+    };
+    let clone_interface = load_clone_interface(&market)?;
+    let quote_onasset_for = |input_mint: Pubkey, output_mint: Pubkey, amount: u64| {
+        clone_interface.quote(&QuoteParams {
+            amount,
+            input_mint,
+            output_mint,
+            swap_mode: SwapMode::ExactIn,
+        })
+    };
+
+    let (collateral_mint, onasset_mint) = (market.collateral_mint, market.onasset_mints[0]);
+    assert!(!quote_onasset_for(collateral_mint, onasset_mint, 400_000_000)?.not_enough_liquidity);
+    assert!(quote_onasset_for(collateral_mint, onasset_mint, 500_000_000)?.not_enough_liquidity);
+
+    // Selling counts the collateral paid out, 3 onasset is worth 60 collateral.
+    assert!(!quote_onasset_for(onasset_mint, collateral_mint, 100_000_000)?.not_enough_liquidity);
+    assert!(quote_onasset_for(onasset_mint, collateral_mint, 300_000_000)?.not_enough_liquidity);
+
+    Ok(())
+}
+
+#[test]
+fn wrap_instructions_convert_between_the_underlying_asset_and_the_onasset() -> Result<()> {
+    let market = market(&[20]);
+    let (wrap_interface, asset_mint) = load_wrap_interface(&market, 100_000_000)?;
+    let onasset_mint = market.onasset_mints[0];
+    let jupiter_program_id = Pubkey::new_unique();
+    let user = Pubkey::new_unique();
+
+    assert_eq!(
+        wrap_interface.get_reserve_mints(),
+        vec![asset_mint, onasset_mint]
+    );
+
+    for (input_mint, output_mint, discriminator) in [
+        (asset_mint, onasset_mint, WrapAsset::discriminator()),
+        (onasset_mint, asset_mint, UnwrapOnasset::discriminator()),
+    ] {
+        let swap_params = swap_params(input_mint, output_mint, user, &jupiter_program_id);
+        let instruction =
+            wrap_interface.create_wrap_instruction(&swap_params, SwapMode::ExactIn)?;
+        assert_eq!(instruction.program_id, ID);
+        assert_eq!(instruction.accounts[3].pubkey, wrap_interface.key);
+        assert_eq!(instruction.data[..8], discriminator);
+    }
+
+    // 1 underlying at 6 decimals is 1 onasset at 8.
+    let wrap_quote = wrap_interface.quote(&QuoteParams {
+        amount: 1_000_000,
+        input_mint: asset_mint,
+        output_mint: onasset_mint,
+        swap_mode: SwapMode::ExactIn,
+    })?;
+    assert_eq!(wrap_quote.out_amount, 100_000_000);
+
+    // Unwraps are bounded by the 100 underlying held by the pool.
+    let unwrap_onasset = |amount: u64| {
+        wrap_interface.quote(&QuoteParams {
+            amount,
+            input_mint: onasset_mint,
+            output_mint: asset_mint,
+            swap_mode: SwapMode::ExactOut,
+        })
+    };
+    assert!(!unwrap_onasset(100_000_000)?.not_enough_liquidity);
+    assert!(unwrap_onasset(100_000_001)?.not_enough_liquidity);
+
+    Ok(())
+}
+
+#[test]
+fn wrap_quotes_follow_the_program_status_rules() -> Result<()> {
+    for (status, can_wrap, can_unwrap) in [
+        (Status::Active, true, true),
+        (Status::Frozen, false, false),
+        (Status::Extraction, true, false),
+        (Status::Liquidation, true, false),
+        (Status::Deprecation, false, true),
+    ] {
+        let mut market = market(&[20]);
+        market.pools.pools[0].status = status;
+        let (wrap_interface, asset_mint) = load_wrap_interface(&market, 100_000_000)?;
+        let quote = |input_mint: Pubkey, output_mint: Pubkey| {
+            wrap_interface.quote(&QuoteParams {
+                amount: 1_000_000,
+                input_mint,
+                output_mint,
+                swap_mode: SwapMode::ExactIn,
+            })
+        };
+
+        assert_eq!(
+            quote(asset_mint, market.onasset_mints[0]).is_ok(),
+            can_wrap,
+            "wrap {:?}",
+            status
+        );
+        assert_eq!(
+            quote(market.onasset_mints[0], asset_mint).is_ok(),
+            can_unwrap,
+            "unwrap {:?}",
+            status
+        );
+    }
+
+    Ok(())
+}
+
+fn load_pool_interface(
+    market: &Market,
+    account_map: &AccountMap,
+    pool_index: usize,
+) -> Result<ClonePoolInterface> {
+    let onasset_mint = market.onasset_mints[pool_index];
+    let mut pool_interface = ClonePoolInterface::from_keyed_account(&KeyedAccount {
+        key: onasset_mint,
+        account: mint_account(8),
+        params: None,
+    })?;
+    pool_interface.update(account_map)?;
+    Ok(pool_interface)
+}
+
+#[test]
+fn pool_amms_are_keyed_on_their_onasset_mint_and_quote_like_the_market() -> Result<()> {
+    let market = market(&[20, 40]);
+    let account_map = account_map(&market);
+    let clone_interface = load_clone_interface(&market)?;
+
+    for (pool_index, onasset_mint) in market.onasset_mints.iter().enumerate() {
+        let pool_interface = load_pool_interface(&market, &account_map, pool_index)?;
+        assert_eq!(pool_interface.key(), *onasset_mint);
+        assert_eq!(pool_interface.pool()?.0, pool_index);
+        assert_eq!(
+            pool_interface.get_reserve_mints(),
+            vec![market.collateral_mint, *onasset_mint]
+        );
+
+        let quote_params = QuoteParams {
+            amount: 1_000_000_000,
+            input_mint: market.collateral_mint,
+            output_mint: *onasset_mint,
+            swap_mode: SwapMode::ExactIn,
+        };
+        assert_eq!(
+            pool_interface.quote(&quote_params)?.out_amount,
+            clone_interface.quote(&quote_params)?.out_amount
+        );
+
+        let other_onasset_mint = market.onasset_mints[1 - pool_index];
+        assert!(pool_interface
+            .quote(&QuoteParams {
+                output_mint: other_onasset_mint,
+                ..quote_params
+            })
+            .is_err());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn pool_amms_only_load_their_own_oracles() -> Result<()> {
+    let market = market(&[20, 40]);
+    let mut account_map = account_map(&market);
+    // The second pool's oracle account can't be read as a price feed.
+    account_map.insert(
+        market.oracles.oracles[2].address,
+        Account {
+            lamports: 1,
+            data: vec![0; 8],
+            owner: Pubkey::new_unique(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let pool_interface = load_pool_interface(&market, &account_map, 0)?;
+    let accounts_to_update = pool_interface.get_accounts_to_update();
+    assert!(accounts_to_update.contains(&market.oracles.oracles[0].address));
+    assert!(accounts_to_update.contains(&market.oracles.oracles[1].address));
+    assert!(!accounts_to_update.contains(&market.oracles.oracles[2].address));
+
+    assert!(load_pool_interface(&market, &account_map, 1).is_err());
+    let mut clone_interface = load_clone_interface(&market)?;
+    assert!(clone_interface.update(&account_map).is_err());
+
+    Ok(())
+}
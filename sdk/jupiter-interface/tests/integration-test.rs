@@ -7,7 +7,9 @@
 
 use anyhow::{anyhow, Result};
 use clone::{decimal::CLONE_TOKEN_SCALE, ID};
-use jupiter_amm_interface::{AccountMap, Amm, KeyedAccount, QuoteParams, SwapMode, SwapParams};
+use jupiter_amm_interface::{
+    AccountMap, Amm, KeyedAccount, QuoteParams, Swap, SwapMode, SwapParams,
+};
 use rand::prelude::*;
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_program_test::{ProgramTest, ProgramTestContext};
@@ -29,7 +31,7 @@ extern crate jupiter_interface;
 use jupiter_interface::*;
 
 async fn create_interface(rpc: &RpcClient) -> Result<CloneInterface> {
-    let pools_address = get_pools_account_address();
+    let pools_address = get_pools_account_address(&ID);
     let pools_account = rpc.get_account(&pools_address).await?;
 
     let keyed_account = KeyedAccount {
@@ -111,7 +113,7 @@ async fn set_mock_token_account(
     token_account_struct.amount = amount;
     let mut buffer = [0u8; TokenAccount::LEN];
     token_account_struct.pack_into_slice(&mut buffer);
-    let mut temp = AccountSharedData::try_from(account)?;
+    let mut temp = AccountSharedData::from(account);
     temp.set_data_from_slice(&buffer);
     context.set_account(&token_account_address, &temp);
 
@@ -119,7 +121,7 @@ async fn set_mock_token_account(
     mint_account_struct.supply -= current_token_amount;
     let mut buffer = [0u8; Mint::LEN];
     mint_account_struct.pack_into_slice(&mut buffer);
-    let mut temp = AccountSharedData::try_from(mint_account)?;
+    let mut temp = AccountSharedData::from(mint_account);
     temp.set_data_from_slice(&buffer);
     context.set_account(&token_account_struct.mint, &temp);
 
@@ -135,7 +137,7 @@ async fn swap_integration_test() -> Result<()> {
     // Setup Program test Context
     let mut program_test = ProgramTest::default();
     let local_clone_program_loaded = if env::var("BPF_OUT_DIR").is_ok() {
-        program_test.add_program(&"clone", ID, None);
+        program_test.add_program("clone", ID, None);
         true
     } else {
         false
@@ -186,7 +188,7 @@ async fn swap_integration_test() -> Result<()> {
             if let Some(account) = account {
                 accounts_map.insert(*pubkey, account.clone());
                 let account_shared_data = AccountSharedData::from(account.clone());
-                context.set_account(&pubkey, &account_shared_data)
+                context.set_account(pubkey, &account_shared_data)
             }
         });
 
@@ -203,7 +205,7 @@ async fn swap_integration_test() -> Result<()> {
         let amount_is_collateral = (input_is_collateral && swap_mode == SwapMode::ExactIn)
             || (!input_is_collateral && swap_mode == SwapMode::ExactOut);
         let scale = if amount_is_collateral {
-            clone_account.collateral.scale.try_into().unwrap()
+            clone_account.collateral.scale.into()
         } else {
             CLONE_TOKEN_SCALE
         };
@@ -247,6 +249,14 @@ async fn swap_integration_test() -> Result<()> {
         println!("QUOTE PARAMS: {:?}", quote_params);
         let quote = clone_interface.quote(&quote_params)?;
         println!("QUOTE: {:?}", quote);
+        if quote.not_enough_liquidity {
+            println!("NOT ENOUGH LIQUIDITY, SKIPPING");
+            continue;
+        }
+        println!(
+            "PRICE IMPACT: {}%",
+            clone_interface.quote_price_impact_pct(&quote_params, &quote)?
+        );
 
         let recent_blockhash = context.banks_client.get_latest_blockhash().await?;
 
@@ -263,13 +273,38 @@ async fn swap_integration_test() -> Result<()> {
                 &context.payer.pubkey(),
                 &quote_params.output_mint,
             ),
-            token_transfer_authority: context.payer.pubkey().clone(),
+            token_transfer_authority: context.payer.pubkey(),
             open_order_address: None,
             quote_mint_to_referrer: None,
             jupiter_program_id: &Pubkey::default(), // NOTE: Need to update this.
             missing_dynamic_accounts_as_default: false,
         };
 
+        // Check the route flags match the requested swap mode.
+        let input_is_collateral = quote_params.input_mint == clone_account.collateral.mint;
+        match clone_interface
+            .get_swap_and_account_metas_with_mode(&swap_params, quote_params.swap_mode)?
+            .swap
+        {
+            Swap::Clone {
+                quantity_is_input,
+                quantity_is_collateral,
+                ..
+            } => {
+                assert_eq!(
+                    quantity_is_input,
+                    quote_params.swap_mode == SwapMode::ExactIn,
+                    "quantity_is_input should follow the swap mode"
+                );
+                assert_eq!(
+                    quantity_is_collateral,
+                    input_is_collateral == quantity_is_input,
+                    "quantity_is_collateral should match the side of the specified amount"
+                );
+            }
+            _ => return Err(anyhow!("Expected a Clone swap")),
+        }
+
         // Set the exact amount required for this swap.
         set_mock_token_account(
             &mut context,
@@ -288,6 +323,8 @@ async fn swap_integration_test() -> Result<()> {
                 quote_params.swap_mode,
                 0,
                 Some(quote),
+                None,
+                None,
             )?,
         ];
 
@@ -0,0 +1,705 @@
+/*
+    Offline integration test of the clone interface for jupiter integration.
+    The test deploys clone, pyth and clone-staking into solana-program-test, seeds oracles,
+    pools, staking tiers and comet liquidity, then runs random swaps in both modes and
+    checks that every quote matches the on-chain swap result exactly.
+    It needs the programs' BPF builds, so it is ignored by default. From the repository root:
+        anchor build
+        BPF_OUT_DIR=$PWD/target/deploy cargo test -p jupiter-interface \
+            --test offline-integration-test -- --ignored
+*/
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anyhow::{anyhow, Result};
+use clone::decimal::CLONE_TOKEN_SCALE;
+use clone::instructions::{UpdateOracleParameters, COLLATERALS_SEED, USER_SEED};
+use clone::states::OracleSource;
+use clone_staking::Parameters as StakingParameters;
+use jupiter_amm_interface::{AccountMap, Amm, KeyedAccount, QuoteParams, SwapMode, SwapParams};
+use pyth_sdk_solana::state::SolanaPriceAccount;
+use rand::prelude::*;
+use solana_program_test::{ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::{Account, AccountSharedData, ReadableAccount},
+    commitment_config::CommitmentLevel,
+    instruction::Instruction,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program, sysvar,
+    transaction::Transaction,
+};
+use spl_associated_token_account::{
+    get_associated_token_address, instruction::create_associated_token_account,
+};
+use spl_token::state::{Account as TokenAccount, Mint};
+use std::env;
+use std::path::Path;
+
+extern crate jupiter_interface;
+use jupiter_interface::*;
+
+const COLLATERAL_SCALE: u8 = 7;
+const PRICE_EXPO: i32 = -8;
+const NUMBER_OF_POOLS: usize = 4;
+const NUMBER_OF_SWAPS: usize = 2048;
+const PRICE_UPDATE_INTERVAL: usize = 64;
+
+async fn process_instructions(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> Result<()> {
+    let recent_blockhash = context.banks_client.get_latest_blockhash().await?;
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend(signers);
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        recent_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction_with_commitment(transaction, CommitmentLevel::Confirmed)
+        .await?;
+    Ok(())
+}
+
+fn anchor_instruction(
+    program_id: Pubkey,
+    accounts: impl ToAccountMetas,
+    args: impl InstructionData,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: accounts.to_account_metas(None),
+        data: args.data(),
+    }
+}
+
+async fn create_mint(
+    context: &mut ProgramTestContext,
+    authority: &Pubkey,
+    decimals: u8,
+) -> Result<Pubkey> {
+    let mint = Keypair::new();
+    let rent = context.banks_client.get_rent().await?;
+    let instructions = [
+        system_instruction::create_account(
+            &context.payer.pubkey(),
+            &mint.pubkey(),
+            rent.minimum_balance(Mint::LEN),
+            Mint::LEN as u64,
+            &spl_token::ID,
+        ),
+        spl_token::instruction::initialize_mint(
+            &spl_token::ID,
+            &mint.pubkey(),
+            authority,
+            None,
+            decimals,
+        )?,
+    ];
+    process_instructions(context, &instructions, &[&mint]).await?;
+    Ok(mint.pubkey())
+}
+
+async fn create_token_account(
+    context: &mut ProgramTestContext,
+    owner: &Pubkey,
+    mint: &Pubkey,
+) -> Result<Pubkey> {
+    let instruction =
+        create_associated_token_account(&context.payer.pubkey(), owner, mint, &spl_token::ID);
+    process_instructions(context, &[instruction], &[]).await?;
+    Ok(get_associated_token_address(owner, mint))
+}
+
+async fn create_price_feed(context: &mut ProgramTestContext, price: i64) -> Result<Pubkey> {
+    let price_feed = Keypair::new();
+    let space = std::mem::size_of::<SolanaPriceAccount>();
+    let rent = context.banks_client.get_rent().await?;
+    let instructions = [
+        system_instruction::create_account(
+            &context.payer.pubkey(),
+            &price_feed.pubkey(),
+            rent.minimum_balance(space),
+            space as u64,
+            &pyth::ID,
+        ),
+        anchor_instruction(
+            pyth::ID,
+            pyth::accounts::Initialize {
+                price_account: price_feed.pubkey(),
+            },
+            pyth::instruction::Initialize {
+                price,
+                expo: PRICE_EXPO,
+            },
+        ),
+    ];
+    process_instructions(context, &instructions, &[&price_feed]).await?;
+    Ok(price_feed.pubkey())
+}
+
+async fn set_mock_token_account(
+    context: &mut ProgramTestContext,
+    token_account_address: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    // This sets the amount of the token account but also updates the mint account to properly reflect the change
+    let account = context
+        .banks_client
+        .get_account_with_commitment(token_account_address, CommitmentLevel::Confirmed)
+        .await?
+        .ok_or(anyhow!("Token account should exist"))?;
+    let mut token_account_struct = TokenAccount::unpack(account.data())?;
+    let current_token_amount = token_account_struct.amount;
+
+    let mint_account = context
+        .banks_client
+        .get_account_with_commitment(token_account_struct.mint, CommitmentLevel::Confirmed)
+        .await?
+        .ok_or(anyhow!("Token account should exist"))?;
+    let mut mint_account_struct = Mint::unpack(mint_account.data())?;
+
+    if amount == current_token_amount {
+        return Ok(());
+    }
+
+    token_account_struct.amount = amount;
+    let mut buffer = [0u8; TokenAccount::LEN];
+    token_account_struct.pack_into_slice(&mut buffer);
+    let mut temp = AccountSharedData::from(account);
+    temp.set_data_from_slice(&buffer);
+    context.set_account(&token_account_address, &temp);
+
+    mint_account_struct.supply += amount;
+    mint_account_struct.supply -= current_token_amount;
+    let mut buffer = [0u8; Mint::LEN];
+    mint_account_struct.pack_into_slice(&mut buffer);
+    let mut temp = AccountSharedData::from(mint_account);
+    temp.set_data_from_slice(&buffer);
+    context.set_account(&token_account_struct.mint, &temp);
+
+    Ok(())
+}
+
+fn read_balance_from_token_account(account: &Account) -> Result<u64> {
+    Ok(TokenAccount::unpack(account.data())?.amount)
+}
+
+async fn update_interface(
+    context: &mut ProgramTestContext,
+    clone_interface: &mut CloneInterface,
+) -> Result<()> {
+    let mut account_map = AccountMap::new();
+    for addr in clone_interface.get_accounts_to_update() {
+        if let Some(account) = context.banks_client.get_account(addr).await? {
+            account_map.insert(addr, account);
+        }
+    }
+    clone_interface.update(&account_map)
+}
+
+#[tokio::test]
+#[ignore = "needs the programs' BPF builds, see the header for how to run it"]
+async fn offline_swap_integration_test() -> Result<()> {
+    let bpf_out_dir = env::var("BPF_OUT_DIR")
+        .expect("BPF_OUT_DIR must point at the `anchor build` output, e.g. target/deploy");
+    for program in ["clone", "pyth", "clone_staking"] {
+        let program_path = Path::new(&bpf_out_dir).join(format!("{}.so", program));
+        assert!(
+            program_path.exists(),
+            "{} is missing, run `anchor build` first",
+            program_path.display()
+        );
+    }
+
+    let mut program_test = ProgramTest::default();
+    program_test.add_program("clone", clone::ID, None);
+    program_test.add_program("pyth", pyth::ID, None);
+    program_test.add_program("clone_staking", clone_staking::ID, None);
+    let mut context = program_test.start_with_context().await;
+    let mut rng = rand::thread_rng();
+
+    let payer = context.payer.pubkey();
+    let treasury_address = Keypair::new().pubkey();
+    let clone_address = get_clone_account_address(&clone::ID);
+    let pools_address = get_pools_account_address(&clone::ID);
+    let oracles_address = get_oracles_account_address(&clone::ID);
+    let collaterals_address =
+        Pubkey::find_program_address(&[COLLATERALS_SEED.as_ref()], &clone::ID).0;
+
+    // Oracles, the collateral feed is at index 0 and each pool uses the next one.
+    let mut price_feeds = vec![create_price_feed(&mut context, 100_000_000).await?];
+    for _ in 0..NUMBER_OF_POOLS {
+        let price = rng.gen_range(1..1000) * 100_000_000;
+        price_feeds.push(create_price_feed(&mut context, price).await?);
+    }
+
+    // Clone, pools and oracles accounts
+    let collateral_mint = create_mint(&mut context, &payer, COLLATERAL_SCALE).await?;
+    let collateral_vault =
+        create_token_account(&mut context, &clone_address, &collateral_mint).await?;
+    let mut instructions = vec![
+        anchor_instruction(
+            clone::ID,
+            clone::accounts::InitializeClone {
+                admin: payer,
+                clone: clone_address,
+                collateral_mint,
+                collateral_vault,
+                rent: sysvar::rent::ID,
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+            },
+            clone::instruction::InitializeClone {
+                comet_collateral_ild_liquidator_fee_bps: 500,
+                comet_onasset_ild_liquidator_fee_bps: 500,
+                borrow_liquidator_fee_bps: 500,
+                treasury_address,
+                collateral_oracle_index: 0,
+                collateralization_ratio: 95,
+            },
+        ),
+        anchor_instruction(
+            clone::ID,
+            clone::accounts::InitializePools {
+                admin: payer,
+                clone: clone_address,
+                pools: pools_address,
+                system_program: system_program::ID,
+            },
+            clone::instruction::InitializePools {},
+        ),
+        anchor_instruction(
+            clone::ID,
+            clone::accounts::InitializeOracles {
+                admin: payer,
+                clone: clone_address,
+                oracles: oracles_address,
+                system_program: system_program::ID,
+            },
+            clone::instruction::InitializeOracles {},
+        ),
+        anchor_instruction(
+            clone::ID,
+            clone::accounts::InitializeCollaterals {
+                admin: payer,
+                clone: clone_address,
+                collaterals: collaterals_address,
+                system_program: system_program::ID,
+            },
+            clone::instruction::InitializeCollaterals {},
+        ),
+        // Pools and oracles are created at their legacy size and grown before use.
+        anchor_instruction(
+            clone::ID,
+            clone::accounts::MigratePools {
+                admin: payer,
+                clone: clone_address,
+                pools: pools_address,
+                system_program: system_program::ID,
+            },
+            clone::instruction::MigratePools {},
+        ),
+        anchor_instruction(
+            clone::ID,
+            clone::accounts::MigrateOracles {
+                admin: payer,
+                clone: clone_address,
+                oracles: oracles_address,
+                system_program: system_program::ID,
+            },
+            clone::instruction::MigrateOracles {},
+        ),
+    ];
+    for price_feed in price_feeds.iter() {
+        instructions.push(anchor_instruction(
+            clone::ID,
+            clone::accounts::UpdateOracles {
+                auth: payer,
+                clone: clone_address,
+                oracles: oracles_address,
+            },
+            clone::instruction::UpdateOracles {
+                params: UpdateOracleParameters::Add {
+                    address: *price_feed,
+                    source: OracleSource::PYTH,
+                    rescale_factor: None,
+                },
+            },
+        ));
+    }
+    process_instructions(&mut context, &instructions, &[]).await?;
+
+    // Pools
+    let mut onasset_mints = Vec::new();
+    for pool_index in 0..NUMBER_OF_POOLS {
+        let onasset_mint =
+            create_mint(&mut context, &clone_address, CLONE_TOKEN_SCALE as u8).await?;
+        let onasset_token_account =
+            create_token_account(&mut context, &clone_address, &onasset_mint).await?;
+        let underlying_asset_mint = create_mint(&mut context, &payer, 9).await?;
+        let underlying_asset_token_account =
+            create_token_account(&mut context, &clone_address, &underlying_asset_mint).await?;
+        let instruction = anchor_instruction(
+            clone::ID,
+            clone::accounts::AddPool {
+                admin: payer,
+                clone: clone_address,
+                pools: pools_address,
+                onasset_mint,
+                onasset_token_account,
+                underlying_asset_mint,
+                underlying_asset_token_account,
+                system_program: system_program::ID,
+            },
+            clone::instruction::AddPool {
+                min_overcollateral_ratio: 150,
+                max_liquidation_overcollateral_ratio: 200,
+                liquidity_trading_fee_bps: rng.gen_range(0..300),
+                treasury_trading_fee_bps: rng.gen_range(0..200),
+                il_health_score_coefficient: 130,
+                position_health_score_coefficient: 110,
+                oracle_info_index: (pool_index + 1) as u8,
+            },
+        );
+        process_instructions(&mut context, &[instruction], &[]).await?;
+        create_token_account(&mut context, &treasury_address, &onasset_mint).await?;
+        create_token_account(&mut context, &payer, &onasset_mint).await?;
+        onasset_mints.push(onasset_mint);
+    }
+    create_token_account(&mut context, &treasury_address, &collateral_mint).await?;
+    let payer_collateral_token_account =
+        create_token_account(&mut context, &payer, &collateral_mint).await?;
+
+    // Staking with a fee free tier, the payer only stakes after the random swaps.
+    let cln_token_mint = create_mint(&mut context, &payer, 6).await?;
+    let clone_staking_address = get_clone_staking_account_address();
+    let cln_token_vault =
+        create_token_account(&mut context, &clone_staking_address, &cln_token_mint).await?;
+    let payer_cln_token_account =
+        create_token_account(&mut context, &payer, &cln_token_mint).await?;
+    let stake_requirement = 1_000_000_000;
+    set_mock_token_account(&mut context, payer_cln_token_account, stake_requirement).await?;
+    let instructions = [
+        anchor_instruction(
+            clone_staking::ID,
+            clone_staking::accounts::Initialize {
+                admin: payer,
+                clone_staking: clone_staking_address,
+                cln_token_mint,
+                cln_token_vault,
+                rent: sysvar::rent::ID,
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+            },
+            clone_staking::instruction::Initialize {
+                staking_period_slots: 0,
+            },
+        ),
+        anchor_instruction(
+            clone_staking::ID,
+            clone_staking::accounts::UpdateStakingParams {
+                admin: payer,
+                clone_staking: clone_staking_address,
+            },
+            clone_staking::instruction::UpdateStakingParams {
+                params: StakingParameters::Tier {
+                    num_tiers: 1,
+                    index: 0,
+                    stake_requirement,
+                    lp_trading_fee_bps: 0,
+                    treasury_trading_fee_bps: 0,
+                },
+            },
+        ),
+    ];
+    process_instructions(&mut context, &instructions, &[]).await?;
+
+    // Comet liquidity for every pool
+    let user_account_address =
+        Pubkey::find_program_address(&[USER_SEED.as_ref(), payer.as_ref()], &clone::ID).0;
+    let comet_collateral = 1_000_000 * 10u64.pow(COLLATERAL_SCALE.into());
+    set_mock_token_account(
+        &mut context,
+        payer_collateral_token_account,
+        comet_collateral,
+    )
+    .await?;
+    let mut update_prices_instruction = anchor_instruction(
+        clone::ID,
+        clone::accounts::UpdatePrices {
+            oracles: oracles_address,
+        },
+        clone::instruction::UpdatePrices {
+            oracle_indices: (0..price_feeds.len() as u8).collect(),
+        },
+    );
+    update_prices_instruction.accounts.extend(
+        price_feeds.iter().map(|price_feed| {
+            solana_sdk::instruction::AccountMeta::new_readonly(*price_feed, false)
+        }),
+    );
+    let mut instructions = vec![
+        anchor_instruction(
+            clone::ID,
+            clone::accounts::InitializeUser {
+                payer,
+                user_account: user_account_address,
+                system_program: system_program::ID,
+            },
+            clone::instruction::InitializeUser { authority: payer },
+        ),
+        anchor_instruction(
+            clone::ID,
+            clone::accounts::AddCollateralToComet {
+                user: payer,
+                user_account: user_account_address,
+                clone: clone_address,
+                collaterals: collaterals_address,
+                vault: collateral_vault,
+                user_collateral_token_account: payer_collateral_token_account,
+                token_program: spl_token::ID,
+            },
+            clone::instruction::AddCollateralToComet {
+                collateral_index: 0,
+                collateral_amount: comet_collateral,
+            },
+        ),
+        update_prices_instruction,
+    ];
+    for pool_index in 0..NUMBER_OF_POOLS {
+        instructions.push(anchor_instruction(
+            clone::ID,
+            clone::accounts::AddLiquidityToComet {
+                user: payer,
+                user_account: user_account_address,
+                clone: clone_address,
+                pools: pools_address,
+                oracles: oracles_address,
+                collaterals: collaterals_address,
+            },
+            clone::instruction::AddLiquidityToComet {
+                pool_index: pool_index as u8,
+                collateral_amount: comet_collateral / 10,
+            },
+        ));
+    }
+    process_instructions(&mut context, &instructions, &[]).await?;
+
+    // Setup clone interface from the seeded state
+    let pools_account = context
+        .banks_client
+        .get_account(pools_address)
+        .await?
+        .ok_or(anyhow!("Pools account should exist"))?;
+    let mut clone_interface = CloneInterface::from_keyed_account(&KeyedAccount {
+        key: pools_address,
+        account: pools_account,
+        params: None,
+    })?;
+    // Need to update twice, second time will get the required oracle addresses for quoting
+    for _ in 0..2 {
+        update_interface(&mut context, &mut clone_interface).await?;
+    }
+
+    let mut number_of_executed_swaps = 0;
+    for i in 0..NUMBER_OF_SWAPS {
+        if i % PRICE_UPDATE_INTERVAL == 0 {
+            // Move a random pool price by up to 10%
+            let pool_index = rng.gen_range(0..NUMBER_OF_POOLS);
+            let oracle = &clone_interface.oracles.as_ref().unwrap().oracles[pool_index + 1];
+            let price = oracle.price * rng.gen_range(90..=110) / 100;
+            let instruction = anchor_instruction(
+                pyth::ID,
+                pyth::accounts::SetPrice {
+                    price_account: price_feeds[pool_index + 1],
+                },
+                pyth::instruction::SetPrice { price },
+            );
+            process_instructions(&mut context, &[instruction], &[]).await?;
+            update_interface(&mut context, &mut clone_interface).await?;
+        }
+
+        let onasset_mint = onasset_mints[rng.gen_range(0..NUMBER_OF_POOLS)];
+        let input_is_collateral: bool = rng.gen();
+        let swap_mode = if rng.gen() {
+            SwapMode::ExactIn
+        } else {
+            SwapMode::ExactOut
+        };
+        let amount_is_collateral = (input_is_collateral && swap_mode == SwapMode::ExactIn)
+            || (!input_is_collateral && swap_mode == SwapMode::ExactOut);
+        let scale: u32 = if amount_is_collateral {
+            COLLATERAL_SCALE.into()
+        } else {
+            CLONE_TOKEN_SCALE
+        };
+        let (input_mint, output_mint) = if input_is_collateral {
+            (collateral_mint, onasset_mint)
+        } else {
+            (onasset_mint, collateral_mint)
+        };
+        let quote_params = QuoteParams {
+            amount: rng.gen_range(10u64.pow(scale - 2)..100 * 10u64.pow(scale)),
+            input_mint,
+            output_mint,
+            swap_mode,
+        };
+        let quote = clone_interface.quote(&quote_params)?;
+        if quote.not_enough_liquidity {
+            continue;
+        }
+
+        let swap_params = SwapParams {
+            in_amount: quote.in_amount,
+            out_amount: quote.out_amount,
+            source_mint: input_mint,
+            destination_mint: output_mint,
+            source_token_account: get_associated_token_address(&payer, &input_mint),
+            destination_token_account: get_associated_token_address(&payer, &output_mint),
+            token_transfer_authority: payer,
+            open_order_address: None,
+            quote_mint_to_referrer: None,
+            jupiter_program_id: &Pubkey::default(),
+            missing_dynamic_accounts_as_default: false,
+        };
+
+        // Set the exact amount required for this swap.
+        set_mock_token_account(
+            &mut context,
+            swap_params.source_token_account,
+            quote.in_amount,
+        )
+        .await?;
+        set_mock_token_account(&mut context, swap_params.destination_token_account, 0).await?;
+
+        // A swap with 0 slippage only succeeds if the quote matches execution exactly.
+        let instruction = clone_interface.create_swap_instruction(
+            &swap_params,
+            swap_mode,
+            0,
+            Some(quote),
+            None,
+            None,
+        )?;
+        process_instructions(&mut context, &[instruction], &[])
+            .await
+            .map_err(|e| anyhow!("Swap {} failed for {:?}: {}", i, quote_params, e))?;
+
+        let source_token_account = context
+            .banks_client
+            .get_account_with_commitment(
+                swap_params.source_token_account,
+                CommitmentLevel::Confirmed,
+            )
+            .await?
+            .expect("Source token account should exist");
+        assert_eq!(
+            read_balance_from_token_account(&source_token_account)?,
+            0,
+            "input token balance should be 0 after swap"
+        );
+        let destination_token_account = context
+            .banks_client
+            .get_account_with_commitment(
+                swap_params.destination_token_account,
+                CommitmentLevel::Confirmed,
+            )
+            .await?
+            .expect("Destination token account should exist");
+        assert_eq!(
+            read_balance_from_token_account(&destination_token_account)?,
+            quote.out_amount,
+            "output token balance should be equal to the quote output after swap"
+        );
+
+        update_interface(&mut context, &mut clone_interface).await?;
+        number_of_executed_swaps += 1;
+    }
+
+    assert!(
+        number_of_executed_swaps > NUMBER_OF_SWAPS / 2,
+        "most swaps should have enough liquidity"
+    );
+
+    // Once staked, the payer swaps at the fee free tier and gets more than the pool fee quote.
+    process_instructions(
+        &mut context,
+        &[anchor_instruction(
+            clone_staking::ID,
+            clone_staking::accounts::AddStake {
+                user: payer,
+                user_account: get_user_staking_account_address(&payer),
+                clone_staking: clone_staking_address,
+                cln_token_mint,
+                cln_token_vault,
+                user_cln_token_account: payer_cln_token_account,
+                rent: sysvar::rent::ID,
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+            },
+            clone_staking::instruction::AddStake {
+                amount: stake_requirement,
+            },
+        )],
+        &[],
+    )
+    .await?;
+    update_interface(&mut context, &mut clone_interface).await?;
+    let quote_params = QuoteParams {
+        amount: 10u64.pow(COLLATERAL_SCALE.into()),
+        input_mint: collateral_mint,
+        output_mint: onasset_mints[0],
+        swap_mode: SwapMode::ExactIn,
+    };
+    let quote = clone_interface.quote(&quote_params)?;
+    let swap_params = SwapParams {
+        in_amount: quote.in_amount,
+        out_amount: quote.out_amount,
+        source_mint: quote_params.input_mint,
+        destination_mint: quote_params.output_mint,
+        source_token_account: payer_collateral_token_account,
+        destination_token_account: get_associated_token_address(&payer, &quote_params.output_mint),
+        token_transfer_authority: payer,
+        open_order_address: None,
+        quote_mint_to_referrer: None,
+        jupiter_program_id: &Pubkey::default(),
+        missing_dynamic_accounts_as_default: false,
+    };
+    set_mock_token_account(
+        &mut context,
+        swap_params.source_token_account,
+        quote.in_amount,
+    )
+    .await?;
+    set_mock_token_account(&mut context, swap_params.destination_token_account, 0).await?;
+    let instruction = clone_interface.create_swap_instruction(
+        &swap_params,
+        SwapMode::ExactIn,
+        0,
+        Some(quote),
+        None,
+        None,
+    )?;
+    process_instructions(&mut context, &[instruction], &[]).await?;
+    let destination_token_account = context
+        .banks_client
+        .get_account_with_commitment(
+            swap_params.destination_token_account,
+            CommitmentLevel::Confirmed,
+        )
+        .await?
+        .expect("Destination token account should exist");
+    let staker_out_amount = read_balance_from_token_account(&destination_token_account)?;
+    assert!(staker_out_amount >= quote.out_amount);
+    if quote.fee_amount > 0 {
+        assert!(staker_out_amount > quote.out_amount);
+    }
+
+    Ok(())
+}
@@ -7,12 +7,14 @@ import {
   TransactionInstruction,
   Transaction,
   SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
 } from "@solana/web3.js";
 import {
   TOKEN_PROGRAM_ID,
   MINT_SIZE,
   createAssociatedTokenAccountInstruction,
   getAssociatedTokenAddress,
+  getAssociatedTokenAddressSync,
   createInitializeMintInstruction,
   getMinimumBalanceForRentExemptMint,
   getAccount,
@@ -43,6 +45,8 @@ import {
   createWrapAssetInstruction,
   createUnwrapOnassetInstruction,
   createInitializePoolsInstruction,
+  createMigratePoolsInstruction,
+  createMigrateOraclesInstruction,
   createInitializeOraclesInstruction,
   createUpdateOraclesInstruction,
   createAddPoolInstruction,
@@ -53,6 +57,22 @@ import {
   createLiquidateCometCollateralIldInstruction,
   createLiquidateCometOnassetIldInstruction,
   createRemoveCometPositionInstruction,
+  createInitializeReferrerInstruction,
+  createUpdateReferrerInstruction,
+  Referrer,
+  FlashLoanAsset,
+  isFlashLoanAssetOnasset,
+  createFlashBorrowInstruction,
+  createFlashRepayInstruction,
+  SwapExpiry,
+  Collateral,
+  Collaterals,
+  createInitializeCollateralsInstruction,
+  createAddCollateralInstruction,
+  UpdateCollateralParametersInstructionArgs,
+  createUpdateCollateralParametersInstruction,
+  createUpdateLiquidationAuctionsInstruction,
+  createDeprecatePoolInstruction,
 } from "../generated/clone";
 import { floorToScale } from "./utils";
 import Decimal from "decimal.js";
@@ -94,6 +114,7 @@ export class CloneClient {
   cloneAddress: PublicKey;
   poolsAddress: PublicKey;
   oraclesAddress: PublicKey;
+  collateralsAddress: PublicKey;
   programId: PublicKey;
   provider: Provider;
   opts?: ConfirmOptions;
@@ -111,6 +132,7 @@ export class CloneClient {
     this.cloneAddress = this.getCloneAddress();
     this.poolsAddress = this.getPoolsAddress();
     this.oraclesAddress = this.getOraclesAddress();
+    this.collateralsAddress = this.getCollateralsAddress();
   }
 
   /// Admin RPC methods ///
@@ -138,6 +160,10 @@ export class CloneClient {
       [Buffer.from("oracles")],
       programId
     );
+    const [collateralsAddress, _____] = PublicKey.findProgramAddressSync(
+      [Buffer.from("collaterals")],
+      programId
+    );
 
     const collateralVault = await getAssociatedTokenAddress(
       collateralMint,
@@ -182,6 +208,16 @@ export class CloneClient {
         },
         programId
       ),
+      // Pools is created at the CPI allocation limit and grown to hold every pool.
+      createMigratePoolsInstruction(
+        {
+          admin,
+          clone: cloneAddress,
+          pools: poolsAddress,
+          systemProgram: SYSTEM_PROGRAM_ID,
+        },
+        programId
+      ),
       createInitializeOraclesInstruction(
         {
           admin,
@@ -190,6 +226,24 @@ export class CloneClient {
           systemProgram: SYSTEM_PROGRAM_ID,
         },
         programId
+      ),
+      createMigrateOraclesInstruction(
+        {
+          admin,
+          clone: cloneAddress,
+          oracles: oraclesAddress,
+          systemProgram: SYSTEM_PROGRAM_ID,
+        },
+        programId
+      ),
+      createInitializeCollateralsInstruction(
+        {
+          admin,
+          clone: cloneAddress,
+          collaterals: collateralsAddress,
+          systemProgram: SYSTEM_PROGRAM_ID,
+        },
+        programId
       )
     );
   }
@@ -356,6 +410,127 @@ export class CloneClient {
     await this.provider.sendAndConfirm!(new Transaction().add(ix));
   }
 
+  public async deprecatePool(poolIndex: number, redemptionDeadline: BN) {
+    const oracles = await this.getOracles();
+    let tx = new Transaction().add(
+      this.updatePricesInstruction(oracles),
+      createDeprecatePoolInstruction(
+        {
+          admin: this.provider.publicKey!,
+          clone: this.cloneAddress,
+          pools: this.poolsAddress,
+          oracles: this.oraclesAddress,
+        },
+        { poolIndex, redemptionDeadline },
+        this.programId
+      )
+    );
+    await this.provider.sendAndConfirm!(tx);
+  }
+
+  public async addCollateral(
+    collateralMint: PublicKey,
+    oracleInfoIndex: number,
+    collateralizationRatio: number,
+    depositCap: BN,
+    minOvercollateralRatio: number = 0,
+    maxLiquidationOvercollateralRatio: number = 0,
+    depositoryConfig?: {
+      depositorySettings: PublicKey;
+      depositingTokenMint: PublicKey;
+    }
+  ) {
+    const collateralVault = await getAssociatedTokenAddress(
+      collateralMint,
+      this.cloneAddress,
+      true
+    );
+
+    let txn = new Transaction();
+    try {
+      const _collateralVault = await getAccount(
+        this.provider.connection,
+        collateralVault
+      );
+    } catch {
+      txn.add(
+        createAssociatedTokenAccountInstruction(
+          this.provider.publicKey!,
+          collateralVault,
+          this.cloneAddress,
+          collateralMint
+        )
+      );
+    }
+
+    txn.add(
+      createAddCollateralInstruction(
+        {
+          admin: this.provider.publicKey!,
+          clone: this.cloneAddress,
+          collaterals: this.collateralsAddress,
+          pools: this.poolsAddress,
+          collateralMint,
+          collateralVault,
+          depositorySettings: depositoryConfig?.depositorySettings,
+          depositingTokenMint: depositoryConfig?.depositingTokenMint,
+        },
+        {
+          oracleInfoIndex,
+          collateralizationRatio,
+          depositCap,
+          minOvercollateralRatio,
+          maxLiquidationOvercollateralRatio,
+        },
+        this.programId
+      )
+    );
+
+    await this.provider.sendAndConfirm!(txn, [], this.opts);
+  }
+
+  public async updateCollateralParameters(
+    params: UpdateCollateralParametersInstructionArgs
+  ) {
+    let ix = createUpdateCollateralParametersInstruction(
+      {
+        admin: this.provider.publicKey!,
+        clone: this.cloneAddress,
+        collaterals: this.collateralsAddress,
+      },
+      params,
+      this.programId
+    );
+    await this.provider.sendAndConfirm!(new Transaction().add(ix));
+  }
+
+  public async initializeReferrer(authority: PublicKey, feeShareBps: number) {
+    let ix = createInitializeReferrerInstruction(
+      {
+        admin: this.provider.publicKey!,
+        clone: this.cloneAddress,
+        referrer: this.getReferrerAddress(authority),
+        systemProgram: SYSTEM_PROGRAM_ID,
+      },
+      { authority, feeShareBps },
+      this.programId
+    );
+    await this.provider.sendAndConfirm!(new Transaction().add(ix));
+  }
+
+  public async updateReferrer(authority: PublicKey, feeShareBps: number) {
+    let ix = createUpdateReferrerInstruction(
+      {
+        admin: this.provider.publicKey!,
+        clone: this.cloneAddress,
+        referrer: this.getReferrerAddress(authority),
+      },
+      { feeShareBps },
+      this.programId
+    );
+    await this.provider.sendAndConfirm!(new Transaction().add(ix));
+  }
+
   /// Address and account fetching ///
 
   public getCloneAddress(): PublicKey {
@@ -382,6 +557,22 @@ export class CloneClient {
     return address;
   }
 
+  public getCollateralsAddress(): PublicKey {
+    const [address, _] = PublicKey.findProgramAddressSync(
+      [Buffer.from("collaterals")],
+      this.programId
+    );
+    return address;
+  }
+
+  public getTreasuryTokenAccountAddress(mint: PublicKey): PublicKey {
+    return getAssociatedTokenAddressSync(
+      mint,
+      this.clone.treasuryAddress,
+      true
+    );
+  }
+
   public getUserAccountAddress(authority?: PublicKey) {
     const [userPubkey, _] = PublicKey.findProgramAddressSync(
       [Buffer.from("user"), (authority ?? this.provider.publicKey!).toBuffer()],
@@ -390,6 +581,14 @@ export class CloneClient {
     return userPubkey;
   }
 
+  public getReferrerAddress(authority: PublicKey) {
+    const [address, _] = PublicKey.findProgramAddressSync(
+      [Buffer.from("referrer"), authority.toBuffer()],
+      this.programId
+    );
+    return address;
+  }
+
   public async getCloneAccount(): Promise<Clone> {
     return await Clone.fromAccountAddress(
       this.provider.connection,
@@ -411,6 +610,29 @@ export class CloneClient {
     );
   }
 
+  public async getCollaterals(): Promise<Collaterals> {
+    return await Collaterals.fromAccountAddress(
+      this.provider.connection,
+      this.collateralsAddress
+    );
+  }
+
+  // Index 0 is the base collateral, registered collaterals follow from index 1.
+  public getCollateral(
+    collateralIndex: number,
+    collaterals?: Collaterals
+  ): Collateral {
+    if (collateralIndex === 0) {
+      return this.clone.collateral;
+    }
+    if (collaterals === undefined) {
+      throw new Error(
+        "Collaterals account is required for registered collateral"
+      );
+    }
+    return collaterals.collaterals[collateralIndex - 1];
+  }
+
   public async getUserAccount(authority?: PublicKey): Promise<User> {
     return await User.fromAccountAddress(
       this.provider.connection,
@@ -418,6 +640,13 @@ export class CloneClient {
     );
   }
 
+  public async getReferrer(authority: PublicKey): Promise<Referrer> {
+    return await Referrer.fromAccountAddress(
+      this.provider.connection,
+      this.getReferrerAddress(authority)
+    );
+  }
+
   /// Instruction creation methods ///
 
   public initializeUserInstruction(
@@ -542,7 +771,9 @@ export class CloneClient {
     userOnassetTokenAccount: PublicKey,
     onassetAmount: BN,
     collateralAmount: BN,
-    poolIndex: number
+    poolIndex: number,
+    collateralIndex: number = 0,
+    collaterals?: Collaterals
   ): TransactionInstruction {
     return createInitializeBorrowPositionInstruction(
       {
@@ -551,7 +782,8 @@ export class CloneClient {
         clone: this.cloneAddress,
         pools: this.poolsAddress,
         oracles: this.oraclesAddress,
-        vault: this.clone.collateral.vault,
+        collaterals: this.collateralsAddress,
+        vault: this.getCollateral(collateralIndex, collaterals).vault,
         userCollateralTokenAccount: userCollateralTokenAccount,
         onassetMint: pools.pools[poolIndex].assetInfo.onassetMint,
         userOnassetTokenAccount: userOnassetTokenAccount,
@@ -561,6 +793,7 @@ export class CloneClient {
         poolIndex,
         onassetAmount,
         collateralAmount,
+        collateralIndex,
       },
       this.programId
     );
@@ -569,14 +802,17 @@ export class CloneClient {
   public addCollateralToBorrowInstruction(
     borrowIndex: number,
     userCollateralTokenAccount: PublicKey,
-    collateralAmount: BN
+    collateralAmount: BN,
+    collateralIndex: number = 0,
+    collaterals?: Collaterals
   ): TransactionInstruction {
     return createAddCollateralToBorrowInstruction(
       {
         user: this.provider.publicKey!,
         userAccount: this.getUserAccountAddress(),
         clone: this.cloneAddress,
-        vault: this.clone.collateral.vault,
+        collaterals: this.collateralsAddress,
+        vault: this.getCollateral(collateralIndex, collaterals).vault,
         userCollateralTokenAccount: userCollateralTokenAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
       },
@@ -589,10 +825,17 @@ export class CloneClient {
   }
 
   public withdrawCollateralFromBorrowInstruction(
+    pools: Pools,
+    userAccount: User,
     borrowIndex: number,
     userCollateralTokenAccount: PublicKey,
-    collateralAmount: BN
+    collateralAmount: BN,
+    collaterals?: Collaterals
   ): TransactionInstruction {
+    const borrowPosition = userAccount.borrows[borrowIndex];
+    const onassetMint =
+      pools.pools[Number(borrowPosition.poolIndex)].assetInfo.onassetMint;
+
     return createWithdrawCollateralFromBorrowInstruction(
       {
         user: this.provider.publicKey!,
@@ -600,8 +843,15 @@ export class CloneClient {
         clone: this.cloneAddress,
         pools: this.poolsAddress,
         oracles: this.oraclesAddress,
-        vault: this.clone.collateral.vault,
+        collaterals: this.collateralsAddress,
+        vault: this.getCollateral(
+          Number(borrowPosition.collateralIndex),
+          collaterals
+        ).vault,
         userCollateralTokenAccount: userCollateralTokenAccount,
+        onassetMint,
+        treasuryOnassetTokenAccount:
+          this.getTreasuryTokenAccountAddress(onassetMint),
         tokenProgram: TOKEN_PROGRAM_ID,
       },
       {
@@ -630,6 +880,9 @@ export class CloneClient {
         pools: this.poolsAddress,
         onassetMint: assetInfo.onassetMint,
         payerOnassetTokenAccount: userOnassetTokenAccount,
+        treasuryOnassetTokenAccount: this.getTreasuryTokenAccountAddress(
+          assetInfo.onassetMint
+        ),
         tokenProgram: TOKEN_PROGRAM_ID,
       },
       { user: this.provider.publicKey!, borrowIndex, amount: onassetAmount },
@@ -654,8 +907,12 @@ export class CloneClient {
         clone: this.cloneAddress,
         pools: this.poolsAddress,
         oracles: this.oraclesAddress,
+        collaterals: this.collateralsAddress,
         onassetMint: assetInfo.onassetMint,
         userOnassetTokenAccount: userOnassetTokenAccount,
+        treasuryOnassetTokenAccount: this.getTreasuryTokenAccountAddress(
+          assetInfo.onassetMint
+        ),
         tokenProgram: TOKEN_PROGRAM_ID,
       },
       { borrowIndex, amount: onassetAmount },
@@ -680,6 +937,14 @@ export class CloneClient {
       userStakingAccount: PublicKey;
     },
     remainingAccounts?: PublicKey[],
+    referrerConfig?: {
+      referrer: PublicKey;
+      referrerTokenAccount: PublicKey;
+    },
+    swapGuards?: {
+      expiry?: SwapExpiry;
+      maxOracleAgeSlots?: BN;
+    },
   ): TransactionInstruction {
     const { cloneStakingProgram, cloneStaking, userStakingAccount } =
       cloneStakingConfig ?? {
@@ -690,6 +955,13 @@ export class CloneClient {
     const anchorRemainingAccounts = remainingAccounts ? remainingAccounts.map(pubkey => {
       return { pubkey, isSigner: false, isWritable: false };
     }) : []
+    // The program reads the referrer accounts from the tail of the remaining accounts.
+    if (referrerConfig) {
+      anchorRemainingAccounts.push(
+        { pubkey: referrerConfig.referrer, isSigner: false, isWritable: true },
+        { pubkey: referrerConfig.referrerTokenAccount, isSigner: false, isWritable: true },
+      );
+    }
     return createSwapInstruction(
       {
         user: this.provider.publicKey!,
@@ -715,6 +987,8 @@ export class CloneClient {
         quantityIsInput,
         quantityIsCollateral,
         resultThreshold: threshold,
+        expiry: swapGuards?.expiry ?? null,
+        maxOracleAgeSlots: swapGuards?.maxOracleAgeSlots ?? null,
       },
       this.programId
     );
@@ -722,18 +996,22 @@ export class CloneClient {
 
   public addCollateralToCometInstruction(
     userCollateralTokenAccount: PublicKey,
-    collateralAmount: BN
+    collateralAmount: BN,
+    collateralIndex: number = 0,
+    collaterals?: Collaterals
   ): TransactionInstruction {
     return createAddCollateralToCometInstruction(
       {
         user: this.provider.publicKey!,
         userAccount: this.getUserAccountAddress(),
         clone: this.cloneAddress,
-        vault: this.clone.collateral.vault,
+        collaterals: this.collateralsAddress,
+        vault: this.getCollateral(collateralIndex, collaterals).vault,
         userCollateralTokenAccount: userCollateralTokenAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
       },
       {
+        collateralIndex,
         collateralAmount,
       },
       this.programId
@@ -742,7 +1020,9 @@ export class CloneClient {
 
   public withdrawCollateralFromCometInstruction(
     userCollateralTokenAccount: PublicKey,
-    collateralAmount: BN
+    collateralAmount: BN,
+    collateralIndex: number = 0,
+    collaterals?: Collaterals
   ): TransactionInstruction {
     return createWithdrawCollateralFromCometInstruction(
       {
@@ -751,11 +1031,13 @@ export class CloneClient {
         clone: this.cloneAddress,
         pools: this.poolsAddress,
         oracles: this.oraclesAddress,
-        vault: this.clone.collateral.vault,
+        collaterals: this.collateralsAddress,
+        vault: this.getCollateral(collateralIndex, collaterals).vault,
         userCollateralTokenAccount: userCollateralTokenAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
       },
       {
+        collateralIndex,
         collateralAmount,
       },
       this.programId
@@ -773,6 +1055,7 @@ export class CloneClient {
         clone: this.cloneAddress,
         pools: this.poolsAddress,
         oracles: this.oraclesAddress,
+        collaterals: this.collateralsAddress,
       },
       { poolIndex, collateralAmount },
       this.programId
@@ -832,6 +1115,22 @@ export class CloneClient {
     );
   }
 
+  public updateLiquidationAuctionsInstruction(
+    user: PublicKey
+  ): TransactionInstruction {
+    return createUpdateLiquidationAuctionsInstruction(
+      {
+        clone: this.cloneAddress,
+        pools: this.poolsAddress,
+        oracles: this.oraclesAddress,
+        collaterals: this.collateralsAddress,
+        userAccount: this.getUserAccountAddress(user),
+      },
+      { user },
+      this.programId
+    );
+  }
+
   public liquidateCometCollateralILDInstruction(
     liquidateeAddress: PublicKey,
     cometPositionIndex: number,
@@ -844,6 +1143,7 @@ export class CloneClient {
         clone: this.cloneAddress,
         pools: this.poolsAddress,
         oracles: this.oraclesAddress,
+        collaterals: this.collateralsAddress,
         collateralMint: this.clone.collateral.mint,
         liquidatorCollateralTokenAccount,
         vault: this.clone.collateral.vault,
@@ -864,7 +1164,8 @@ export class CloneClient {
     cometPositionIndex: number,
     liquidatorCollateralTokenAccount: PublicKey,
     liquidatorOnassetTokenAccount: PublicKey,
-    amount: BN
+    amount: BN,
+    flash: boolean = false
   ): TransactionInstruction {
     const cometPosition =
       liquidateeUserAccount.comet.positions[cometPositionIndex];
@@ -877,9 +1178,13 @@ export class CloneClient {
         clone: this.cloneAddress,
         pools: this.poolsAddress,
         oracles: this.oraclesAddress,
+        collaterals: this.collateralsAddress,
         onassetMint: pool.assetInfo.onassetMint,
         liquidatorOnassetTokenAccount,
         liquidatorCollateralTokenAccount,
+        treasuryOnassetTokenAccount: this.getTreasuryTokenAccountAddress(
+          pool.assetInfo.onassetMint
+        ),
         vault: this.clone.collateral.vault,
         tokenProgram: TOKEN_PROGRAM_ID,
       },
@@ -887,6 +1192,7 @@ export class CloneClient {
         user: liquidateeAddress,
         cometPositionIndex,
         amount,
+        flash,
       },
       this.programId
     );
@@ -899,10 +1205,16 @@ export class CloneClient {
     borrowIndex: number,
     amount: BN,
     liquidatorCollateralTokenAccount: PublicKey,
-    liquidatorOnassetTokenAccount: PublicKey
+    liquidatorOnassetTokenAccount: PublicKey,
+    collaterals?: Collaterals,
+    flash: boolean = false
   ): TransactionInstruction {
     const borrowPosition = liquidateeUserAccount.borrows[borrowIndex];
     const pool = pools.pools[Number(borrowPosition.poolIndex)];
+    const collateral = this.getCollateral(
+      Number(borrowPosition.collateralIndex),
+      collaterals
+    );
 
     return createLiquidateBorrowPositionInstruction(
       {
@@ -911,16 +1223,24 @@ export class CloneClient {
         userAccount: this.getUserAccountAddress(liquidateeAddress),
         pools: this.poolsAddress,
         oracles: this.oraclesAddress,
+        collaterals: this.collateralsAddress,
         onassetMint: pool.assetInfo.onassetMint,
-        vault: this.clone.collateral.vault,
+        vault: collateral.vault,
         liquidatorCollateralTokenAccount: liquidatorCollateralTokenAccount,
+        treasuryCollateralTokenAccount: this.getTreasuryTokenAccountAddress(
+          collateral.mint
+        ),
         liquidatorOnassetTokenAccount: liquidatorOnassetTokenAccount,
+        treasuryOnassetTokenAccount: this.getTreasuryTokenAccountAddress(
+          pool.assetInfo.onassetMint
+        ),
         tokenProgram: TOKEN_PROGRAM_ID,
       },
       {
         user: liquidateeAddress,
         borrowIndex,
         amount,
+        flash,
       },
       this.programId
     );
@@ -954,6 +1274,60 @@ export class CloneClient {
     );
   }
 
+  public flashBorrowInstruction(
+    pools: Pools,
+    asset: FlashLoanAsset,
+    amount: BN,
+    userTokenAccount: PublicKey
+  ): TransactionInstruction {
+    return createFlashBorrowInstruction(
+      {
+        user: this.provider.publicKey!,
+        clone: this.cloneAddress,
+        pools: this.poolsAddress,
+        onassetMint: isFlashLoanAssetOnasset(asset)
+          ? pools.pools[asset.poolIndex].assetInfo.onassetMint
+          : undefined,
+        collateralVault: isFlashLoanAssetOnasset(asset)
+          ? undefined
+          : this.clone.collateral.vault,
+        userTokenAccount,
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      },
+      { asset, amount },
+      this.programId
+    );
+  }
+
+  public flashRepayInstruction(
+    pools: Pools,
+    asset: FlashLoanAsset,
+    amount: BN,
+    userTokenAccount: PublicKey,
+    treasuryTokenAccount: PublicKey
+  ): TransactionInstruction {
+    return createFlashRepayInstruction(
+      {
+        user: this.provider.publicKey!,
+        clone: this.cloneAddress,
+        pools: this.poolsAddress,
+        onassetMint: isFlashLoanAssetOnasset(asset)
+          ? pools.pools[asset.poolIndex].assetInfo.onassetMint
+          : undefined,
+        collateralVault: isFlashLoanAssetOnasset(asset)
+          ? undefined
+          : this.clone.collateral.vault,
+        userTokenAccount,
+        treasuryTokenAccount,
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      },
+      { asset, amount },
+      this.programId
+    );
+  }
+
   public removeCometPositionInstruction(cometPositionIndex: number): TransactionInstruction {
     return createRemoveCometPositionInstruction(
       {
@@ -85,6 +85,24 @@ export const getPoolLiquidity = (
   };
 };
 
+// Onasset that can still be borrowed from the pool before its debt ceiling,
+// undefined when the pool is uncapped.
+export const getRemainingDebtCapacity = (pool: Pool) => {
+  if (Number(pool.debtCeiling) === 0) return undefined;
+  return Math.max(Number(pool.debtCeiling) - Number(pool.borrowedOnasset), 0);
+};
+
+// Collateral liquidity that can still be committed to the pool,
+// undefined when the pool is uncapped.
+export const getRemainingLiquidityCapacity = (pool: Pool) => {
+  if (Number(pool.maxCommittedCollateralLiquidity) === 0) return undefined;
+  return Math.max(
+    Number(pool.maxCommittedCollateralLiquidity) -
+      Number(pool.committedCollateralLiquidity),
+    0
+  );
+};
+
 export const calculateOutputFromInputFromParams = (
   poolCollateral: number,
   poolOnasset: number,
@@ -27,6 +27,7 @@ import {
   calculateSwapExecution,
   sleep,
   getOrCreateAssociatedTokenAccount,
+  getRemainingDebtCapacity,
 } from "../sdk/src/utils";
 import { getHealthScore, getILD } from "../sdk/src/healthscore";
 import {
@@ -35,6 +36,7 @@ import {
   OracleSource,
   PaymentType,
   Status,
+  FlashLoanAsset,
   createCreateTokenMetadataInstruction,
   CreateTokenMetadataInstructionAccounts,
   CreateTokenMetadataInstructionArgs,
@@ -470,6 +472,110 @@ describe("tests", async () => {
 
     let pools = await cloneClient.getPools();
     assert.equal(pools.pools.length, 1);
+
+    // Swaps, borrow repayments and liquidations pay fees into the treasury.
+    let pool = pools.pools[0];
+    const treasuryOnassetAssociatedTokenAddress =
+      await getAssociatedTokenAddress(
+        pool.assetInfo.onassetMint,
+        treasuryAddress.publicKey,
+        false,
+        TOKEN_PROGRAM_ID,
+        ASSOCIATED_TOKEN_PROGRAM_ID
+      );
+    const treasuryCollateralAssociatedTokenAddress =
+      await getAssociatedTokenAddress(
+        cloneClient.clone.collateral.mint,
+        treasuryAddress.publicKey,
+        false,
+        TOKEN_PROGRAM_ID,
+        ASSOCIATED_TOKEN_PROGRAM_ID
+      );
+    await cloneClient.provider.sendAndConfirm!(
+      new Transaction()
+        .add(
+          await createAssociatedTokenAccountInstruction(
+            cloneClient.provider.publicKey!,
+            treasuryOnassetAssociatedTokenAddress,
+            treasuryAddress.publicKey,
+            pool.assetInfo.onassetMint,
+            TOKEN_PROGRAM_ID,
+            ASSOCIATED_TOKEN_PROGRAM_ID
+          )
+        )
+        .add(
+          await createAssociatedTokenAccountInstruction(
+            cloneClient.provider.publicKey!,
+            treasuryCollateralAssociatedTokenAddress,
+            treasuryAddress.publicKey,
+            cloneClient.clone.collateral.mint,
+            TOKEN_PROGRAM_ID,
+            ASSOCIATED_TOKEN_PROGRAM_ID
+          )
+        )
+    );
+
+    treasuryOnassetTokenAccount = await getAccount(
+      cloneClient.provider.connection,
+      treasuryOnassetAssociatedTokenAddress,
+      "recent"
+    );
+    treasuryCollateralTokenAccount = await getAccount(
+      cloneClient.provider.connection,
+      treasuryCollateralAssociatedTokenAddress,
+      "recent"
+    );
+  });
+
+  it("collateral registered!", async () => {
+    const collateralMint = await createTokenMint(provider, {
+      scale: COLLATERAL_SCALE,
+    });
+    await cloneClient.addCollateral(
+      collateralMint,
+      0,
+      90,
+      toScale(1_000_000, COLLATERAL_SCALE),
+      150,
+      200
+    );
+
+    let collaterals = await cloneClient.getCollaterals();
+    assert.equal(collaterals.collaterals.length, 1);
+    let collateral = cloneClient.getCollateral(1, collaterals);
+    assert.equal(collateral.mint.toString(), collateralMint.toString());
+    assert.equal(collateral.collateralizationRatio, 90);
+    assert.equal(collateral.scale, COLLATERAL_SCALE);
+    assert.equal(collateral.collateralType.__kind, "Token");
+    assert.equal(collateral.minOvercollateralRatio, 150);
+    assert.equal(collateral.maxLiquidationOvercollateralRatio, 200);
+
+    await cloneClient.updateCollateralParameters({
+      index: 1,
+      params: {
+        __kind: "DepositCap",
+        value: toScale(2_000_000, COLLATERAL_SCALE),
+      },
+    });
+    collaterals = await cloneClient.getCollaterals();
+    assert.equal(
+      fromScale(collaterals.collaterals[0].depositCap, COLLATERAL_SCALE),
+      2_000_000
+    );
+  });
+
+  it("referrer initialized!", async () => {
+    const authority = anchor.web3.Keypair.generate().publicKey;
+    await cloneClient.initializeReferrer(authority, 2500);
+
+    let referrer = await cloneClient.getReferrer(authority);
+    assert.equal(referrer.authority.toString(), authority.toString());
+    assert.equal(referrer.feeShareBps, 2500);
+    assert.equal(Number(referrer.referredVolume), 0);
+
+    await cloneClient.updateReferrer(authority, 1000);
+    referrer = await cloneClient.getReferrer(authority);
+    assert.equal(referrer.feeShareBps, 1000);
   });
 
   it("user initialized!", async () => {
@@ -633,6 +739,8 @@ describe("tests", async () => {
 
     const withdrawCollateralFromBorrowIx =
       cloneClient.withdrawCollateralFromBorrowInstruction(
+        pools,
+        userAccount,
         borrowIndex,
         mockUSDCTokenAccountInfo.address,
         new BN(borrowPosition.collateralAmount)
@@ -765,6 +873,53 @@ describe("tests", async () => {
     );
   });
 
+  it("debt ceiling enforced!", async () => {
+    let pools = await cloneClient.getPools();
+    const userAccount = await cloneClient.getUserAccount();
+    const oracles = await cloneClient.getOracles();
+    const pool = pools.pools[0];
+
+    await cloneClient.updatePoolParameters({
+      index: 0,
+      params: {
+        __kind: "DebtCeiling",
+        value: new BN(pool.borrowedOnasset),
+      },
+    });
+    pools = await cloneClient.getPools();
+    assert.equal(getRemainingDebtCapacity(pools.pools[0]), 0);
+
+    let failedBorrow = false;
+    try {
+      await provider.sendAndConfirm(
+        new Transaction()
+          .add(cloneClient.updatePricesInstruction(oracles))
+          .add(
+            cloneClient.borrowMoreInstruction(
+              pools,
+              userAccount,
+              onassetTokenAccountInfo.address,
+              toCloneScale(0.01),
+              0
+            )
+          )
+      );
+    } catch (error) {
+      failedBorrow = true;
+    }
+    assert.isTrue(failedBorrow, "borrow past the debt ceiling should fail");
+
+    await cloneClient.updatePoolParameters({
+      index: 0,
+      params: {
+        __kind: "DebtCeiling",
+        value: new BN(0),
+      },
+    });
+    pools = await cloneClient.getPools();
+    assert.isUndefined(getRemainingDebtCapacity(pools.pools[0]));
+  });
+
   it("borrow position liquidation", async () => {
     let pools = await cloneClient.getPools();
     let oracles = await cloneClient.getOracles();
@@ -833,6 +988,22 @@ describe("tests", async () => {
       },
     });
 
+    // Start the liquidation auction of the now unhealthy position.
+    await cloneClient.provider.sendAndConfirm!(
+      new Transaction().add(
+        updatePricesIx,
+        cloneClient.updateLiquidationAuctionsInstruction(
+          cloneClient.provider.publicKey!
+        )
+      )
+    );
+    userAccount = await cloneClient.getUserAccount();
+    assert.isAbove(
+      Number(userAccount.borrows[positionIndex].liquidationStartSlot),
+      0,
+      "liquidation auction should have started"
+    );
+
     await cloneClient.provider.sendAndConfirm!(tx);
     userAccount = await cloneClient.getUserAccount();
     userborrowPositions = userAccount.borrows;
@@ -1179,56 +1350,6 @@ describe("tests", async () => {
     );
     let startingOnassetBalance = fromCloneScale(onassetTokenAccountInfo.amount);
 
-    const treasuryOnassetAssociatedTokenAddress =
-      await getAssociatedTokenAddress(
-        pool.assetInfo.onassetMint,
-        treasuryAddress.publicKey,
-        false,
-        TOKEN_PROGRAM_ID,
-        ASSOCIATED_TOKEN_PROGRAM_ID
-      );
-    const treasuryCollateralAssociatedTokenAddress =
-      await getAssociatedTokenAddress(
-        cloneClient.clone.collateral.mint,
-        treasuryAddress.publicKey,
-        false,
-        TOKEN_PROGRAM_ID,
-        ASSOCIATED_TOKEN_PROGRAM_ID
-      );
-    await cloneClient.provider.sendAndConfirm!(
-      new Transaction()
-        .add(
-          await createAssociatedTokenAccountInstruction(
-            cloneClient.provider.publicKey!,
-            treasuryOnassetAssociatedTokenAddress,
-            treasuryAddress.publicKey,
-            pool.assetInfo.onassetMint,
-            TOKEN_PROGRAM_ID,
-            ASSOCIATED_TOKEN_PROGRAM_ID
-          )
-        )
-        .add(
-          await createAssociatedTokenAccountInstruction(
-            cloneClient.provider.publicKey!,
-            treasuryCollateralAssociatedTokenAddress,
-            treasuryAddress.publicKey,
-            cloneClient.clone.collateral.mint,
-            TOKEN_PROGRAM_ID,
-            ASSOCIATED_TOKEN_PROGRAM_ID
-          )
-        )
-    );
-
-    treasuryOnassetTokenAccount = await getAccount(
-      cloneClient.provider.connection,
-      treasuryOnassetAssociatedTokenAddress,
-      "recent"
-    );
-    treasuryCollateralTokenAccount = await getAccount(
-      cloneClient.provider.connection,
-      treasuryCollateralAssociatedTokenAddress,
-      "recent"
-    );
     let updatePriceIx = cloneClient.updatePricesInstruction(oracles);
     const amountToBuy = 100;
     let executionEst = calculateSwapExecution(
@@ -1310,7 +1431,8 @@ describe("tests", async () => {
       fromScale(oracle.price, oracle.expo),
       cloneClient.clone.collateral
     );
-    // Buy via specified onasset for output
+    // Buy via specified onasset for output, guarded by an expiry and oracle age.
+    const currentSlot = await provider.connection.getSlot("recent");
     let convertIx = cloneClient.swapInstruction(
       poolIndex,
       toScale(collateralToConvert, COLLATERAL_SCALE),
@@ -1321,9 +1443,49 @@ describe("tests", async () => {
       collateralTokenAccountInfo.address,
       onassetTokenAccountInfo.address,
       treasuryCollateralTokenAccount.address,
-      treasuryOnassetTokenAccount.address
+      treasuryOnassetTokenAccount.address,
+      undefined,
+      undefined,
+      undefined,
+      {
+        expiry: { __kind: "Slot", value: new BN(currentSlot + 150) },
+        maxOracleAgeSlots: new BN(100_000),
+      }
     );
 
+    // An expired swap is rejected.
+    let failedSimulation = false;
+    try {
+      await provider.simulate(
+        new Transaction().add(updatePriceIx).add(
+          cloneClient.swapInstruction(
+            poolIndex,
+            toScale(collateralToConvert, COLLATERAL_SCALE),
+            true,
+            true,
+            toCloneScale(executionEst.result * 0.995),
+            pool.assetInfo.onassetMint,
+            collateralTokenAccountInfo.address,
+            onassetTokenAccountInfo.address,
+            treasuryCollateralTokenAccount.address,
+            treasuryOnassetTokenAccount.address,
+            undefined,
+            undefined,
+            undefined,
+            {
+              expiry: {
+                __kind: "UnixTimestamp",
+                value: new BN(Math.floor(Date.now() / 1000) - 60),
+              },
+            }
+          )
+        )
+      );
+    } catch (error) {
+      failedSimulation = true;
+    }
+    assert.isTrue(failedSimulation, "expired swap should have failed");
+
     await provider.sendAndConfirm(
       new Transaction().add(updatePriceIx).add(convertIx)
     );
@@ -1495,6 +1657,101 @@ describe("tests", async () => {
     );
   });
 
+  it("collateral flash borrowed and repaid!", async () => {
+    const flashLoanFeeBps = 10;
+    await cloneClient.updateCloneParameters({
+      params: {
+        __kind: "FlashLoanFee",
+        value: flashLoanFeeBps,
+      },
+    });
+
+    const pools = await cloneClient.getPools();
+    collateralTokenAccountInfo = await getOrCreateAssociatedTokenAccount(
+      cloneClient.provider,
+      cloneClient.clone.collateral.mint
+    );
+    const startingCollateralBalance = fromScale(
+      collateralTokenAccountInfo.amount,
+      COLLATERAL_SCALE
+    );
+    const startingTreasuryBalance = fromScale(
+      (
+        await getAccount(
+          provider.connection,
+          treasuryCollateralTokenAccount.address,
+          "recent"
+        )
+      ).amount,
+      COLLATERAL_SCALE
+    );
+
+    const amount = 1000;
+    const asset: FlashLoanAsset = { __kind: "Collateral" };
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        cloneClient.flashBorrowInstruction(
+          pools,
+          asset,
+          toScale(amount, COLLATERAL_SCALE),
+          collateralTokenAccountInfo.address
+        ),
+        cloneClient.flashRepayInstruction(
+          pools,
+          asset,
+          toScale(amount, COLLATERAL_SCALE),
+          collateralTokenAccountInfo.address,
+          treasuryCollateralTokenAccount.address
+        )
+      )
+    );
+
+    const fee = (amount * flashLoanFeeBps) / 10000;
+    collateralTokenAccountInfo = await getOrCreateAssociatedTokenAccount(
+      cloneClient.provider,
+      cloneClient.clone.collateral.mint
+    );
+    assert.closeTo(
+      fromScale(collateralTokenAccountInfo.amount, COLLATERAL_SCALE),
+      startingCollateralBalance - fee,
+      1e-7,
+      "check user collateral balance"
+    );
+    assert.closeTo(
+      fromScale(
+        (
+          await getAccount(
+            provider.connection,
+            treasuryCollateralTokenAccount.address,
+            "recent"
+          )
+        ).amount,
+        COLLATERAL_SCALE
+      ),
+      startingTreasuryBalance + fee,
+      1e-7,
+      "check treasury collateral balance"
+    );
+
+    // A borrow without a matching repayment is rejected.
+    let failedSimulation = false;
+    try {
+      await provider.simulate(
+        new Transaction().add(
+          cloneClient.flashBorrowInstruction(
+            pools,
+            asset,
+            toScale(amount, COLLATERAL_SCALE),
+            collateralTokenAccountInfo.address
+          )
+        )
+      );
+    } catch (error) {
+      failedSimulation = true;
+    }
+    assert.isTrue(failedSimulation, "simulation should have failed");
+  });
+
   it("withdraw all staked CLN", async () => {
     let userStakingAccount = await CloneStaking.User.fromAccountAddress(
       provider.connection,
@@ -2157,14 +2414,22 @@ describe("tests", async () => {
         cloneClient.clone.treasuryAddress
       );
 
-    // change status to active
-    await cloneClient.updatePoolParameters({
-      index: poolIndex,
-      params: {
-        __kind: "Status",
-        value: Status.Deprecation,
-      },
-    });
+    // Deprecate the pool and wait out its redemption period.
+    const getChainTime = async () => {
+      const slot = await provider.connection.getSlot("recent");
+      return (await provider.connection.getBlockTime(slot))!;
+    };
+    const redemptionDeadline = (await getChainTime()) + 2;
+    await cloneClient.deprecatePool(poolIndex, new BN(redemptionDeadline));
+    pools = await cloneClient.getPools();
+    assert.equal(pools.pools[poolIndex].status, Status.Deprecation);
+    assert.equal(
+      Number(pools.pools[poolIndex].settlement.redemptionDeadline),
+      redemptionDeadline
+    );
+    while ((await getChainTime()) <= redemptionDeadline) {
+      await sleep(500);
+    }
 
     let createTreasuryIx = await createAssociatedTokenAccountInstruction(
       provider.publicKey!,
@@ -2180,6 +2445,8 @@ describe("tests", async () => {
         underlyingAssetMint: underlyingAssetTokenAccount.mint,
         underlyingAssetTokenAccount: underlyingAssetTokenAddress,
         treasuryAssetTokenAccount: treasuryUnderlyingAssociatedTokenAddress,
+        vault: cloneClient.clone.collateral.vault,
+        treasuryCollateralTokenAccount: treasuryCollateralTokenAccount.address,
       } as RemovePoolInstructionAccounts,
       {
         poolIndex,
@@ -2193,9 +2460,14 @@ describe("tests", async () => {
     let updatedPools = await cloneClient.getPools();
 
     assert.equal(
-      pools.pools.length - 1,
+      pools.pools.length,
       updatedPools.pools.length,
-      "check pool length"
+      "removed pools stay as tombstones"
+    );
+    assert.equal(
+      Number(updatedPools.pools[poolIndex].settlement.collateralReserve),
+      0,
+      "check the settlement reserve was swept"
     );
 
     underlyingAssetTokenAccount = await getAccount(